@@ -7,6 +7,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use owl2_rs::{
     api::{load_ontology, Reasoner},
     owl2_profile::{check_profile_compliance, OwlProfile},
+    reasoner::{ConsistencyResult, TableauReasoner},
 };
 
 /// Creates a moderately complex ontology for benchmarking
@@ -69,7 +70,81 @@ fn bench_consistency_check(c: &mut Criterion) {
     c.bench_function("consistency_check", |b| {
         b.iter(|| {
             let mut reasoner = Reasoner::new(ontology.clone());
-            let _is_consistent = reasoner.is_consistent();
+            let _is_consistent = reasoner.is_consistent().unwrap();
+        })
+    });
+}
+
+/// Creates an ontology in which many classes share the same existential restriction
+fn create_shared_existential_ontology(size: usize) -> String {
+    let mut ontology = String::from("Ontology(<http://example.com/shared>\n");
+    for i in 0..size {
+        ontology.push_str(&format!(
+            "  SubClassOf(Class(<http://example.com/A{i}>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/C>)))\n"
+        ));
+        ontology.push_str(&format!(
+            "  ClassAssertion(Class(<http://example.com/A{i}>) NamedIndividual(<http://example.com/a{i}>))\n"
+        ));
+    }
+    ontology.push(')');
+    ontology
+}
+
+/// Benchmark for consistency checking when existential successors can be shared
+fn bench_shared_existential_consistency(c: &mut Criterion) {
+    let ontology_str = create_shared_existential_ontology(100);
+    let ontology = load_ontology(&ontology_str).expect("Failed to parse ontology");
+
+    c.bench_function("shared_existential_consistency", |b| {
+        b.iter(|| {
+            let mut reasoner = Reasoner::new(ontology.clone());
+            let _is_consistent = reasoner.is_consistent().unwrap();
+        })
+    });
+}
+
+/// Creates an ontology whose edges are each asserted on a property, an equivalent
+/// property and the inverse of both
+fn create_inverse_heavy_ontology(size: usize) -> String {
+    let mut ontology = String::from(
+        "Ontology(<http://example.com/inverse>
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasChild>) ObjectProperty(<http://example.com/hasParent>))
+  EquivalentObjectProperties(ObjectProperty(<http://example.com/hasChild>) ObjectProperty(<http://example.com/hasKid>))
+  SymmetricObjectProperty(ObjectProperty(<http://example.com/knows>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>))\n",
+    );
+    for i in 0..size {
+        let (parent, child) = (format!("<http://example.com/p{i}>"), format!("<http://example.com/c{i}>"));
+        for (property, source, target) in [
+            ("ObjectProperty(<http://example.com/hasChild>)", &parent, &child),
+            ("ObjectProperty(<http://example.com/hasKid>)", &parent, &child),
+            ("ObjectProperty(<http://example.com/hasParent>)", &child, &parent),
+            ("ObjectInverseOf(ObjectProperty(<http://example.com/hasKid>))", &child, &parent),
+            ("ObjectProperty(<http://example.com/knows>)", &parent, &child),
+            ("ObjectInverseOf(ObjectProperty(<http://example.com/knows>))", &child, &parent),
+        ] {
+            ontology.push_str(&format!(
+                "  ObjectPropertyAssertion({property} NamedIndividual({source}) NamedIndividual({target}))\n"
+            ));
+        }
+    }
+    ontology.push(')');
+    ontology
+}
+
+/// Benchmark for consistency checking when most asserted edges are equivalent or
+/// mirrored; the completion graph stores each edge once
+fn bench_inverse_heavy_consistency(c: &mut Criterion) {
+    let size = 100;
+    let ontology = load_ontology(&create_inverse_heavy_ontology(size)).expect("Failed to parse ontology");
+
+    if let ConsistencyResult::Consistent { model_summary } = TableauReasoner::new(ontology.clone()).check_consistency() {
+        println!("inverse_heavy_consistency: {} asserted edges stored as {}", size * 6, model_summary.edges);
+    }
+    c.bench_function("inverse_heavy_consistency", |b| {
+        b.iter(|| {
+            let mut reasoner = TableauReasoner::new(ontology.clone());
+            let _is_consistent = reasoner.is_consistent().unwrap();
         })
     });
 }
@@ -114,7 +189,7 @@ fn bench_class_expression_processing(c: &mut Criterion) {
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_parse_ontology, bench_consistency_check, bench_rl_profile_check, bench_el_profile_check, bench_class_expression_processing
+    targets = bench_parse_ontology, bench_consistency_check, bench_shared_existential_consistency, bench_inverse_heavy_consistency, bench_rl_profile_check, bench_el_profile_check, bench_class_expression_processing
 }
 
 criterion_main!(benches);
\ No newline at end of file
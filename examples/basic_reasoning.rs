@@ -2,7 +2,7 @@
 //!
 //! This example demonstrates the basic functionality of the owl2_rs library.
 
-use owl2_rs::{parser::OWLParser, reasoner::TableauReasoner};
+use owl2_rs::{api::Reasoner, parser::OWLParser, reasoner::TableauReasoner};
 use std::time::Instant;
 
 fn main() {
@@ -29,6 +29,7 @@ fn main() {
     
     let start = Instant::now();
     let ontology = OWLParser::parse_ontology(ontology_str).expect("Failed to parse ontology");
+    let summary_ontology = ontology.clone();
     let parse_duration = start.elapsed();
     
     println!("  Parsed ontology with {} axioms in {:?}", ontology.axioms.len(), parse_duration);
@@ -37,7 +38,7 @@ fn main() {
     println!("\n2. Checking consistency:");
     let start = Instant::now();
     let mut reasoner = TableauReasoner::new(ontology);
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     let consistency_duration = start.elapsed();
     
     println!("  Ontology is consistent: {} (checked in {:?})", is_consistent, consistency_duration);
@@ -45,7 +46,7 @@ fn main() {
     // Example 3: Classify the ontology
     println!("\n3. Computing class hierarchy:");
     let start = Instant::now();
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     let classification_duration = start.elapsed();
     
     println!("  Computed class hierarchy in {:?}", classification_duration);
@@ -55,7 +56,7 @@ fn main() {
     // Example 4: Realize individuals
     println!("\n4. Realizing individuals:");
     let start = Instant::now();
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     let realization_duration = start.elapsed();
     
     println!("  Realized individuals in {:?}", realization_duration);
@@ -83,5 +84,11 @@ fn main() {
     println!("  Is john an instance of Student? {}", is_john_student);
     println!("  Is john an instance of Person? {}", is_john_person);
     
+    // Example 6: Reasoning summary
+    println!("\n6. Reasoning summary:");
+    Reasoner::new(summary_ontology)
+        .write_summary(&mut std::io::stdout())
+        .expect("Failed to write summary");
+    
     println!("\nExample completed successfully!");
 }
\ No newline at end of file
@@ -17,18 +17,18 @@ fn main() {
     let mut reasoner = Reasoner::new(ontology);
     
     // Check consistency
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     println!("Ontology is consistent: {}", is_consistent);
     assert!(is_consistent, "Ontology should be consistent");
     
     // Classify the ontology
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     println!("Classification completed");
     println!("Found {} superclasses", hierarchy.superclasses.len());
     println!("Found {} subclasses", hierarchy.subclasses.len());
     
     // Realize individuals
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     println!("Realization completed");
     println!("Found types for {} individuals", individual_types.len());
     
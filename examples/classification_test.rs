@@ -1 +1 @@
-fn main() { println!("Simple Classification Test"); println!("========================="); let ontology = owl2_rs::Ontology::default(); println!("Created ontology with {} axioms", ontology.axioms.len()); let mut reasoner = owl2_rs::reasoner::TableauReasoner::new(ontology); let hierarchy = reasoner.classify(); println!("Classification completed"); println!("Found {} superclasses", hierarchy.superclasses.len()); println!("Found {} subclasses", hierarchy.subclasses.len()); println!("\nTest completed successfully!"); }
+fn main() { println!("Simple Classification Test"); println!("========================="); let ontology = owl2_rs::Ontology::default(); println!("Created ontology with {} axioms", ontology.axioms.len()); let mut reasoner = owl2_rs::reasoner::TableauReasoner::new(ontology); let hierarchy = reasoner.classify().unwrap(); println!("Classification completed"); println!("Found {} superclasses", hierarchy.superclasses.len()); println!("Found {} subclasses", hierarchy.subclasses.len()); println!("\nTest completed successfully!"); }
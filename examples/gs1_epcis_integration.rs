@@ -27,7 +27,7 @@ fn test_gs1_ontology() {
     
     // Parse the ontology
     let start = Instant::now();
-    let ontology = load_ontology_from_file(&path).expect("Failed to load GS1 ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load GS1 ontology");
     let parse_duration = start.elapsed();
     
     println!("  Parsed GS1 ontology with {} axioms in {:?}", ontology.axioms.len(), parse_duration);
@@ -35,14 +35,14 @@ fn test_gs1_ontology() {
     // Check consistency
     let start = Instant::now();
     let mut reasoner = Reasoner::new(ontology);
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     let consistency_duration = start.elapsed();
     
     println!("  GS1 ontology is consistent: {} (checked in {:?})", is_consistent, consistency_duration);
     
     // Classify the ontology
     let start = Instant::now();
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     let classification_duration = start.elapsed();
     
     println!("  Computed class hierarchy in {:?}", classification_duration);
@@ -51,7 +51,7 @@ fn test_gs1_ontology() {
     
     // Realize individuals
     let start = Instant::now();
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     let realization_duration = start.elapsed();
     
     println!("  Realized individuals in {:?}", realization_duration);
@@ -63,7 +63,7 @@ fn test_epcis_ontology() {
     
     // Parse the ontology
     let start = Instant::now();
-    let ontology = load_ontology_from_file(&path).expect("Failed to load EPCIS ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load EPCIS ontology");
     let parse_duration = start.elapsed();
     
     println!("  Parsed EPCIS ontology with {} axioms in {:?}", ontology.axioms.len(), parse_duration);
@@ -71,14 +71,14 @@ fn test_epcis_ontology() {
     // Check consistency
     let start = Instant::now();
     let mut reasoner = Reasoner::new(ontology);
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     let consistency_duration = start.elapsed();
     
     println!("  EPCIS ontology is consistent: {} (checked in {:?})", is_consistent, consistency_duration);
     
     // Classify the ontology
     let start = Instant::now();
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     let classification_duration = start.elapsed();
     
     println!("  Computed class hierarchy in {:?}", classification_duration);
@@ -87,7 +87,7 @@ fn test_epcis_ontology() {
     
     // Realize individuals
     let start = Instant::now();
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     let realization_duration = start.elapsed();
     
     println!("  Realized individuals in {:?}", realization_duration);
@@ -3,7 +3,7 @@
 //! This example demonstrates a real-world use case of traceability in a UHT milk supply chain
 //! using EPCIS concepts and the owl2_rs library.
 
-use owl2_rs::api::{load_ontology_from_file, Reasoner};
+use owl2_rs::api::{load_ontology_from_file, realization_to_csv, Reasoner};
 use std::path::Path;
 use std::time::Instant;
 
@@ -16,7 +16,7 @@ fn main() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
     
     let start = Instant::now();
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     let load_duration = start.elapsed();
     
     println!("  Loaded ontology with {} axioms in {:?}", ontology.axioms.len(), load_duration);
@@ -25,7 +25,7 @@ fn main() {
     println!("\n2. Checking ontology consistency:");
     let start = Instant::now();
     let mut reasoner = Reasoner::new(ontology);
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     let consistency_duration = start.elapsed();
     
     println!("  Ontology is consistent: {} (checked in {:?})", is_consistent, consistency_duration);
@@ -33,7 +33,7 @@ fn main() {
     // Classify the ontology
     println!("\n3. Computing class hierarchy:");
     let start = Instant::now();
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     let classification_duration = start.elapsed();
     
     println!("  Computed class hierarchy in {:?}", classification_duration);
@@ -43,15 +43,16 @@ fn main() {
     // Realize individuals
     println!("\n4. Realizing individuals:");
     let start = Instant::now();
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     let realization_duration = start.elapsed();
     
     println!("  Realized individuals in {:?}", realization_duration);
     println!("  Found types for {} individuals", individual_types.len());
     
-    // Demonstrate traceability queries
-    println!("\n5. Performing traceability queries:");
-    demonstrate_traceability_queries(&individual_types);
+    // Export the realization results for spreadsheet analysis
+    println!("\n5. Exporting realization results as CSV:");
+    let mut stdout = std::io::stdout().lock();
+    realization_to_csv(&individual_types, &mut stdout).expect("Failed to write realization results");
     
     // Show supply chain relationships
     println!("\n6. Verifying supply chain relationships:");
@@ -61,75 +62,6 @@ fn main() {
     println!("This demonstrates how owl2_rs can be used for real-world supply chain traceability.");
 }
 
-fn demonstrate_traceability_queries(individual_types: &std::collections::HashMap<owl2_rs::Individual, owl2_rs::reasoner::IndividualTypes>) {
-    // Define the classes we want to query for
-    let uht_milk_product_class = owl2_rs::Class(owl2_rs::IRI("http://epcis.example.com/UHTMilkProduct".to_string()));
-    let business_location_class = owl2_rs::Class(owl2_rs::IRI("http://epcis.org/ontology/BusinessLocation".to_string()));
-    let business_step_class = owl2_rs::Class(owl2_rs::IRI("http://epcis.org/ontology/BusinessStep".to_string()));
-    let disposition_class = owl2_rs::Class(owl2_rs::IRI("http://epcis.org/ontology/Disposition".to_string()));
-    
-    // Count instances of each class
-    let mut uht_milk_products = Vec::new();
-    let mut business_locations = Vec::new();
-    let mut business_steps = Vec::new();
-    let mut dispositions = Vec::new();
-    
-    for (individual, types) in individual_types.iter() {
-        if types.all.contains(&uht_milk_product_class) {
-            uht_milk_products.push(individual);
-        }
-        if types.all.contains(&business_location_class) {
-            business_locations.push(individual);
-        }
-        if types.all.contains(&business_step_class) {
-            business_steps.push(individual);
-        }
-        if types.all.contains(&disposition_class) {
-            dispositions.push(individual);
-        }
-    }
-    
-    println!("  UHT Milk Products ({}):", uht_milk_products.len());
-    for product in uht_milk_products.iter().take(3) {
-        if let owl2_rs::Individual::Named(iri) = product {
-            println!("    - {}", iri.0);
-        }
-    }
-    if uht_milk_products.len() > 3 {
-        println!("    ... and {} more", uht_milk_products.len() - 3);
-    }
-    
-    println!("  Business Locations ({}):", business_locations.len());
-    for location in business_locations.iter().take(3) {
-        if let owl2_rs::Individual::Named(iri) = location {
-            println!("    - {}", iri.0);
-        }
-    }
-    if business_locations.len() > 3 {
-        println!("    ... and {} more", business_locations.len() - 3);
-    }
-    
-    println!("  Business Steps ({}):", business_steps.len());
-    for step in business_steps.iter().take(3) {
-        if let owl2_rs::Individual::Named(iri) = step {
-            println!("    - {}", iri.0);
-        }
-    }
-    if business_steps.len() > 3 {
-        println!("    ... and {} more", business_steps.len() - 3);
-    }
-    
-    println!("  Dispositions ({}):", dispositions.len());
-    for disposition in dispositions.iter().take(3) {
-        if let owl2_rs::Individual::Named(iri) = disposition {
-            println!("    - {}", iri.0);
-        }
-    }
-    if dispositions.len() > 3 {
-        println!("    ... and {} more", dispositions.len() - 3);
-    }
-}
-
 fn show_supply_chain_relationships(_hierarchy: &owl2_rs::reasoner::ClassHierarchy) {
     // In a more complete implementation, we would query the hierarchy for specific relationships
     println!("  Class hierarchy relationships have been computed");
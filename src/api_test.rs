@@ -22,6 +22,6 @@ mod tests {
         let ontology = load_ontology(ontology_str).unwrap();
         let mut reasoner = Reasoner::new(ontology);
         
-        assert!(reasoner.is_consistent());
+        assert!(reasoner.is_consistent().unwrap());
     }
 }
\ No newline at end of file
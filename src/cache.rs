@@ -3,10 +3,8 @@
 //! This module provides caching mechanisms to avoid recomputing results for 
 //! the same queries in the OWL 2 reasoner.
 
-use crate::{Ontology, reasoner::{ClassHierarchy, IndividualTypes, Individual}};
+use crate::{Ontology, Individual, reasoner::{ClassHierarchy, IndividualTypes}};
 use std::collections::HashMap;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
 
 /// Configuration for the reasoner caching mechanisms
 #[derive(Debug, Clone)]
@@ -52,9 +50,7 @@ impl ReasonerCache {
     
     /// Computes a hash for an ontology to use as a cache key
     fn compute_ontology_hash(ontology: &Ontology) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        ontology.hash(&mut hasher);
-        hasher.finish()
+        ontology.canonical_hash()
     }
     
     /// Gets a cached consistency result
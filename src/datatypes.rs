@@ -0,0 +1,593 @@
+//! # Data Range Support for OWL 2
+//!
+//! This module decides whether a literal belongs to a data range, which the
+//! reasoner uses to check data property assertions against declared ranges, and
+//! whether two literals denote the same data value.
+
+use crate::{DataRange, Datatype, Literal};
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS_LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+const OWL_REAL: &str = "http://www.w3.org/2002/07/owl#real";
+const OWL_RATIONAL: &str = "http://www.w3.org/2002/07/owl#rational";
+
+/// Whether a literal belongs to a data range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Membership {
+    /// The literal is a member of the range.
+    Member,
+    /// The literal is not a member of the range.
+    NonMember,
+    /// Membership cannot be decided, e.g. because the range uses a custom datatype.
+    Unknown,
+}
+
+impl Membership {
+    fn from_bool(member: bool) -> Self {
+        if member { Membership::Member } else { Membership::NonMember }
+    }
+
+    /// Membership in the complement of the range.
+    fn complement(self) -> Self {
+        match self {
+            Membership::Member => Membership::NonMember,
+            Membership::NonMember => Membership::Member,
+            Membership::Unknown => Membership::Unknown,
+        }
+    }
+
+    /// Membership in the intersection of ranges: a member of all of them.
+    fn all(memberships: impl IntoIterator<Item = Membership>) -> Self {
+        memberships.into_iter().fold(Membership::Member, |all, membership| match (all, membership) {
+            (Membership::NonMember, _) | (_, Membership::NonMember) => Membership::NonMember,
+            (Membership::Unknown, _) | (_, Membership::Unknown) => Membership::Unknown,
+            _ => Membership::Member,
+        })
+    }
+
+    /// Membership in the union of ranges: a member of any of them.
+    fn any(memberships: impl IntoIterator<Item = Membership>) -> Self {
+        memberships.into_iter().fold(Membership::NonMember, |any, membership| match (any, membership) {
+            (Membership::Member, _) | (_, Membership::Member) => Membership::Member,
+            (Membership::Unknown, _) | (_, Membership::Unknown) => Membership::Unknown,
+            _ => Membership::NonMember,
+        })
+    }
+}
+
+/// Checks whether `literal` is a member of `range`.
+///
+/// Membership is decided by value. A literal of the `xsd:decimal` family belongs to
+/// a numeric datatype when its value does, so `"22"^^xsd:integer` is an
+/// `xsd:nonNegativeInteger` and `"5"^^xsd:decimal` an `xsd:integer`, but `"5.5"` and
+/// out-of-range values such as `"300"^^xsd:integer` for `xsd:byte` are not. Other
+/// literals belong to a datatype when they are typed with it or one of its
+/// subdatatypes (see [`is_subdatatype_of`]). Every literal belongs to
+/// `rdfs:Literal`. The value spaces of datatypes outside the XSD, RDF and OWL
+/// vocabularies (custom datatypes) are unknown, so membership in them is `Unknown`
+/// unless the literal is typed with the datatype itself. Boolean combinations are
+/// evaluated recursively, `DataOneOf` compares literals by value (see
+/// [`literals_value_equal`]), and `DatatypeRestriction` checks the base datatype
+/// followed by each facet. Facets that cannot be evaluated here (such as
+/// `xsd:pattern`) are `Unknown` as well.
+///
+/// # Arguments
+///
+/// * `literal` - The literal to test
+/// * `range` - The data range to test against
+///
+/// # Returns
+///
+/// `Member` if the literal is in the range, `NonMember` if it is not, and `Unknown`
+/// if that cannot be decided.
+///
+/// # Examples
+///
+/// ```rust
+/// use owl2_rs::{DataRange, Datatype, IRI, Literal};
+/// use owl2_rs::datatypes::{literal_in_range, Membership};
+///
+/// let integer = Datatype(IRI("http://www.w3.org/2001/XMLSchema#integer".to_string()));
+/// let five = Literal { value: "5".to_string(), datatype: integer.clone(), lang: None };
+///
+/// assert_eq!(literal_in_range(&five, &DataRange::Datatype(integer.clone())), Membership::Member);
+/// assert_eq!(literal_in_range(&five, &DataRange::DataComplementOf(Box::new(DataRange::Datatype(integer)))), Membership::NonMember);
+/// ```
+pub fn literal_in_range(literal: &Literal, range: &DataRange) -> Membership {
+    match range {
+        DataRange::Datatype(datatype) => datatype_membership(literal, datatype),
+        DataRange::DataIntersectionOf(ranges) => Membership::all(ranges.iter().map(|range| literal_in_range(literal, range))),
+        DataRange::DataUnionOf(ranges) => Membership::any(ranges.iter().map(|range| literal_in_range(literal, range))),
+        DataRange::DataComplementOf(inner) => literal_in_range(literal, inner).complement(),
+        DataRange::DataOneOf(literals) => {
+            if literals.iter().any(|member| literals_value_equal(literal, member)) {
+                Membership::Member
+            } else if is_known_datatype(&literal.datatype) && literals.iter().all(|member| is_known_datatype(&member.datatype)) {
+                Membership::NonMember
+            } else {
+                Membership::Unknown
+            }
+        }
+        DataRange::DatatypeRestriction { datatype, restrictions } => Membership::all(
+            std::iter::once(datatype_membership(literal, datatype))
+                .chain(restrictions.iter().map(|(facet, value)| facet_holds(literal, &facet.0, value))),
+        ),
+    }
+}
+
+/// Checks whether `literal` is a member of the value space of `datatype`.
+fn datatype_membership(literal: &Literal, datatype: &Datatype) -> Membership {
+    if datatype.0.0 == RDFS_LITERAL {
+        Membership::Member
+    } else if !is_known_datatype(datatype) || !is_known_datatype(&literal.datatype) {
+        if literal.datatype == *datatype { Membership::Member } else { Membership::Unknown }
+    } else if let Some(membership) = decimal_membership(literal, datatype) {
+        membership
+    } else {
+        Membership::from_bool(is_subdatatype_of(&literal.datatype, datatype))
+    }
+}
+
+/// The integer datatypes derived from `xsd:integer` with their inclusive lower and
+/// upper bounds; `None` leaves a side unbounded.
+const INTEGER_BOUNDS: &[(&str, Option<i128>, Option<i128>)] = &[
+    ("integer", None, None),
+    ("nonNegativeInteger", Some(0), None),
+    ("positiveInteger", Some(1), None),
+    ("nonPositiveInteger", None, Some(0)),
+    ("negativeInteger", None, Some(-1)),
+    ("long", Some(i64::MIN as i128), Some(i64::MAX as i128)),
+    ("int", Some(i32::MIN as i128), Some(i32::MAX as i128)),
+    ("short", Some(i16::MIN as i128), Some(i16::MAX as i128)),
+    ("byte", Some(i8::MIN as i128), Some(i8::MAX as i128)),
+    ("unsignedLong", Some(0), Some(u64::MAX as i128)),
+    ("unsignedInt", Some(0), Some(u32::MAX as i128)),
+    ("unsignedShort", Some(0), Some(u16::MAX as i128)),
+    ("unsignedByte", Some(0), Some(u8::MAX as i128)),
+];
+
+/// Decides membership of a literal of the `xsd:decimal` family in `xsd:decimal`,
+/// `owl:rational`, `owl:real` or one of the integer datatypes by its value. Returns
+/// `None` for other literals and datatypes.
+fn decimal_membership(literal: &Literal, datatype: &Datatype) -> Option<Membership> {
+    let literal_type = literal.datatype.0.0.strip_prefix(XSD)?;
+    if !DECIMAL_DATATYPES.contains(&literal_type) {
+        return None;
+    }
+    let iri = datatype.0.0.as_str();
+    let bounds = INTEGER_BOUNDS.iter().find(|(name, _, _)| iri.strip_prefix(XSD) == Some(*name));
+    if bounds.is_none() && !matches!(iri.strip_prefix(XSD), Some("decimal")) && iri != OWL_RATIONAL && iri != OWL_REAL {
+        return None;
+    }
+
+    let Some(value) = canonical_decimal(&literal.value) else {
+        return Some(Membership::NonMember);
+    };
+    let Some((_, lower, upper)) = bounds else {
+        return Some(Membership::Member);
+    };
+    if value.contains('.') {
+        return Some(Membership::NonMember);
+    }
+    // Values too large for i128 lie beyond every finite bound
+    let negative = value.starts_with('-');
+    let parsed = value.parse::<i128>().ok();
+    let above_lower = lower.is_none_or(|lower| parsed.map_or(!negative, |value| value >= lower));
+    let below_upper = upper.is_none_or(|upper| parsed.map_or(negative, |value| value <= upper));
+    Some(Membership::from_bool(above_lower && below_upper))
+}
+
+/// The built-in datatype hierarchy as pairs of a datatype and its direct
+/// superdatatype, following the value space inclusions of XSD and OWL 2. `xsd:float`
+/// and `xsd:double` have value spaces of their own and do not appear.
+const DATATYPE_PARENTS: &[(&str, &str)] = &[
+    ("http://www.w3.org/2002/07/owl#rational", OWL_REAL),
+    ("http://www.w3.org/2001/XMLSchema#decimal", OWL_RATIONAL),
+    ("http://www.w3.org/2001/XMLSchema#integer", "http://www.w3.org/2001/XMLSchema#decimal"),
+    ("http://www.w3.org/2001/XMLSchema#nonNegativeInteger", "http://www.w3.org/2001/XMLSchema#integer"),
+    ("http://www.w3.org/2001/XMLSchema#positiveInteger", "http://www.w3.org/2001/XMLSchema#nonNegativeInteger"),
+    ("http://www.w3.org/2001/XMLSchema#unsignedLong", "http://www.w3.org/2001/XMLSchema#nonNegativeInteger"),
+    ("http://www.w3.org/2001/XMLSchema#unsignedInt", "http://www.w3.org/2001/XMLSchema#unsignedLong"),
+    ("http://www.w3.org/2001/XMLSchema#unsignedShort", "http://www.w3.org/2001/XMLSchema#unsignedInt"),
+    ("http://www.w3.org/2001/XMLSchema#unsignedByte", "http://www.w3.org/2001/XMLSchema#unsignedShort"),
+    ("http://www.w3.org/2001/XMLSchema#nonPositiveInteger", "http://www.w3.org/2001/XMLSchema#integer"),
+    ("http://www.w3.org/2001/XMLSchema#negativeInteger", "http://www.w3.org/2001/XMLSchema#nonPositiveInteger"),
+    ("http://www.w3.org/2001/XMLSchema#long", "http://www.w3.org/2001/XMLSchema#integer"),
+    ("http://www.w3.org/2001/XMLSchema#int", "http://www.w3.org/2001/XMLSchema#long"),
+    ("http://www.w3.org/2001/XMLSchema#short", "http://www.w3.org/2001/XMLSchema#int"),
+    ("http://www.w3.org/2001/XMLSchema#byte", "http://www.w3.org/2001/XMLSchema#short"),
+    ("http://www.w3.org/2001/XMLSchema#string", "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral"),
+    ("http://www.w3.org/2001/XMLSchema#normalizedString", "http://www.w3.org/2001/XMLSchema#string"),
+    ("http://www.w3.org/2001/XMLSchema#token", "http://www.w3.org/2001/XMLSchema#normalizedString"),
+    ("http://www.w3.org/2001/XMLSchema#language", "http://www.w3.org/2001/XMLSchema#token"),
+    ("http://www.w3.org/2001/XMLSchema#Name", "http://www.w3.org/2001/XMLSchema#token"),
+    ("http://www.w3.org/2001/XMLSchema#NCName", "http://www.w3.org/2001/XMLSchema#Name"),
+    ("http://www.w3.org/2001/XMLSchema#NMTOKEN", "http://www.w3.org/2001/XMLSchema#token"),
+    ("http://www.w3.org/2001/XMLSchema#dateTimeStamp", "http://www.w3.org/2001/XMLSchema#dateTime"),
+];
+
+/// Checks whether every value of `sub` is also a value of `sup` according to the
+/// built-in XSD and OWL 2 datatype hierarchy, e.g. `xsd:int ⊑ xsd:integer ⊑ xsd:decimal
+/// ⊑ owl:rational ⊑ owl:real`. Every datatype is a subdatatype of itself and of
+/// `rdfs:Literal`. Custom datatypes are only subdatatypes of themselves and of
+/// `rdfs:Literal`.
+///
+/// This relates datatypes, not values: a single literal may belong to a datatype that
+/// is not a superdatatype of its own, as `"5"^^xsd:integer` belongs to `xsd:int`
+/// (see [`literal_in_range`]).
+///
+/// # Arguments
+///
+/// * `sub` - The candidate subdatatype
+/// * `sup` - The candidate superdatatype
+///
+/// # Returns
+///
+/// `true` if `sub` is a subdatatype of `sup`, `false` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use owl2_rs::{Datatype, IRI};
+/// use owl2_rs::datatypes::is_subdatatype_of;
+///
+/// let xsd = |name: &str| Datatype(IRI(format!("http://www.w3.org/2001/XMLSchema#{}", name)));
+///
+/// assert!(is_subdatatype_of(&xsd("integer"), &xsd("decimal")));
+/// assert!(!is_subdatatype_of(&xsd("decimal"), &xsd("integer")));
+/// assert!(!is_subdatatype_of(&xsd("integer"), &xsd("double")));
+/// ```
+pub fn is_subdatatype_of(sub: &Datatype, sup: &Datatype) -> bool {
+    if sup.0.0 == RDFS_LITERAL {
+        return true;
+    }
+    let mut current = sub.0.0.as_str();
+    loop {
+        if current == sup.0.0 {
+            return true;
+        }
+        match DATATYPE_PARENTS.iter().find(|(datatype, _)| *datatype == current) {
+            Some((_, parent)) => current = parent,
+            None => return false,
+        }
+    }
+}
+
+/// Checks whether `range` contains no literals at all, whatever literal is tested.
+///
+/// The empty data range is the complement of `rdfs:Literal` (or of another range
+/// covering every literal), an empty `DataOneOf`, an intersection with an empty
+/// member, or a union whose members are all empty.
+///
+/// # Arguments
+///
+/// * `range` - The data range to test
+///
+/// # Returns
+///
+/// `true` if no literal can be a member of the range, `false` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use owl2_rs::{DataRange, Datatype, IRI};
+/// use owl2_rs::datatypes::is_empty_data_range;
+///
+/// let literal = DataRange::Datatype(Datatype(IRI("http://www.w3.org/2000/01/rdf-schema#Literal".to_string())));
+///
+/// assert!(is_empty_data_range(&DataRange::DataComplementOf(Box::new(literal.clone()))));
+/// assert!(!is_empty_data_range(&literal));
+/// ```
+pub fn is_empty_data_range(range: &DataRange) -> bool {
+    match range {
+        DataRange::DataComplementOf(inner) => is_full_data_range(inner),
+        DataRange::DataOneOf(literals) => literals.is_empty(),
+        DataRange::DataIntersectionOf(ranges) => ranges.iter().any(is_empty_data_range),
+        DataRange::DataUnionOf(ranges) => ranges.iter().all(is_empty_data_range),
+        DataRange::Datatype(_) | DataRange::DatatypeRestriction { .. } => false,
+    }
+}
+
+/// Checks whether `range` contains every literal.
+fn is_full_data_range(range: &DataRange) -> bool {
+    match range {
+        DataRange::Datatype(datatype) => datatype.0.0 == RDFS_LITERAL,
+        DataRange::DataComplementOf(inner) => is_empty_data_range(inner),
+        DataRange::DataIntersectionOf(ranges) => ranges.iter().all(is_full_data_range),
+        DataRange::DataUnionOf(ranges) => ranges.iter().any(is_full_data_range),
+        DataRange::DataOneOf(_) | DataRange::DatatypeRestriction { .. } => false,
+    }
+}
+
+/// The XSD datatypes whose value spaces are subsets of the `xsd:decimal` value space.
+const DECIMAL_DATATYPES: &[&str] = &[
+    "decimal", "integer", "nonNegativeInteger", "positiveInteger", "nonPositiveInteger", "negativeInteger",
+    "long", "int", "short", "byte", "unsignedLong", "unsignedInt", "unsignedShort", "unsignedByte",
+];
+
+/// Checks whether two literals denote the same data value.
+///
+/// Literals of the `xsd:decimal` family (`xsd:decimal`, `xsd:integer` and the integer
+/// types derived from it) share one value space, so `"1"^^xsd:integer` equals
+/// `"1.0"^^xsd:decimal`. `xsd:float` and `xsd:double` values are compared numerically
+/// within their own datatype; their value spaces are disjoint from the decimals and
+/// from each other. All other literals are equal only if they are identical.
+///
+/// # Arguments
+///
+/// * `a` - The first literal
+/// * `b` - The second literal
+///
+/// # Returns
+///
+/// `true` if both literals denote the same value, `false` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use owl2_rs::{Datatype, IRI, Literal};
+/// use owl2_rs::datatypes::literals_value_equal;
+///
+/// let xsd = |name: &str| Datatype(IRI(format!("http://www.w3.org/2001/XMLSchema#{}", name)));
+/// let one = Literal { value: "1".to_string(), datatype: xsd("integer"), lang: None };
+/// let one_point_zero = Literal { value: "1.0".to_string(), datatype: xsd("decimal"), lang: None };
+///
+/// assert!(literals_value_equal(&one, &one_point_zero));
+/// assert_ne!(one, one_point_zero);
+/// ```
+pub fn literals_value_equal(a: &Literal, b: &Literal) -> bool {
+    if a == b {
+        return true;
+    }
+    let (Some(a_type), Some(b_type)) = (a.datatype.0.0.strip_prefix(XSD), b.datatype.0.0.strip_prefix(XSD)) else {
+        return false;
+    };
+    if DECIMAL_DATATYPES.contains(&a_type) && DECIMAL_DATATYPES.contains(&b_type) {
+        return matches!((canonical_decimal(&a.value), canonical_decimal(&b.value)), (Some(x), Some(y)) if x == y);
+    }
+    if a_type == b_type && matches!(a_type, "float" | "double") {
+        return matches!((a.value.trim().parse::<f64>(), b.value.trim().parse::<f64>()), (Ok(x), Ok(y)) if x == y);
+    }
+    false
+}
+
+/// Returns the canonical form of a decimal lexical value (no leading or trailing
+/// zeros, no `+` sign, no sign on zero), or `None` if it is not a valid decimal.
+fn canonical_decimal(lexical: &str) -> Option<String> {
+    let lexical = lexical.trim();
+    let (negative, unsigned) = match lexical.as_bytes().first() {
+        Some(b'-') => (true, &lexical[1..]),
+        Some(b'+') => (false, &lexical[1..]),
+        _ => (false, lexical),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+    let mut canonical = String::new();
+    if negative && !(integer.is_empty() && fraction.is_empty()) {
+        canonical.push('-');
+    }
+    canonical.push_str(if integer.is_empty() { "0" } else { integer });
+    if !fraction.is_empty() {
+        canonical.push('.');
+        canonical.push_str(fraction);
+    }
+    Some(canonical)
+}
+
+/// Checks whether the value space of `datatype` is known: it is `rdfs:Literal`, an XSD
+/// datatype, an RDF datatype, `owl:real` or `owl:rational`.
+fn is_known_datatype(datatype: &Datatype) -> bool {
+    let iri = datatype.0.0.as_str();
+    iri == RDFS_LITERAL || iri == OWL_REAL || iri == OWL_RATIONAL || iri.starts_with(XSD) || iri.starts_with(RDF)
+}
+
+/// Checks a single facet restriction against a literal.
+fn facet_holds(literal: &Literal, facet: &str, bound: &Literal) -> Membership {
+    let Some(name) = facet.strip_prefix(XSD) else {
+        return Membership::Unknown;
+    };
+    let holds = match name {
+        "minInclusive" | "minExclusive" | "maxInclusive" | "maxExclusive" => {
+            let (Ok(value), Ok(bound)) = (literal.value.trim().parse::<f64>(), bound.value.trim().parse::<f64>()) else {
+                return Membership::NonMember;
+            };
+            match name {
+                "minInclusive" => value >= bound,
+                "minExclusive" => value > bound,
+                "maxInclusive" => value <= bound,
+                _ => value < bound,
+            }
+        }
+        "length" | "minLength" | "maxLength" => {
+            let Ok(bound) = bound.value.trim().parse::<usize>() else {
+                return Membership::NonMember;
+            };
+            let length = literal.value.chars().count();
+            match name {
+                "length" => length == bound,
+                "minLength" => length >= bound,
+                _ => length <= bound,
+            }
+        }
+        _ => return Membership::Unknown,
+    };
+    Membership::from_bool(holds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Membership::*;
+    use crate::IRI;
+
+    fn xsd(name: &str) -> Datatype {
+        Datatype(IRI(format!("{}{}", XSD, name)))
+    }
+
+    fn typed(value: &str, datatype: &str) -> Literal {
+        Literal { value: value.to_string(), datatype: xsd(datatype), lang: None }
+    }
+
+    fn facet(name: &str, value: Literal) -> (IRI, Literal) {
+        (IRI(format!("{}{}", XSD, name)), value)
+    }
+
+    #[test]
+    fn test_datatype_membership() {
+        let five = typed("5", "integer");
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(xsd("integer"))), Member);
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(xsd("string"))), NonMember);
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(Datatype(IRI(RDFS_LITERAL.to_string())))), Member);
+    }
+
+    #[test]
+    fn test_datatype_hierarchy_membership() {
+        let five = typed("5", "integer");
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(xsd("decimal"))), Member);
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(Datatype(IRI(OWL_REAL.to_string())))), Member);
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(xsd("string"))), NonMember);
+        assert_eq!(literal_in_range(&five, &DataRange::Datatype(xsd("double"))), NonMember);
+        assert_eq!(literal_in_range(&typed("5.5", "decimal"), &DataRange::Datatype(xsd("integer"))), NonMember);
+        assert_eq!(literal_in_range(&typed("7", "unsignedByte"), &DataRange::Datatype(xsd("nonNegativeInteger"))), Member);
+        assert_eq!(literal_in_range(&typed("en", "language"), &DataRange::Datatype(xsd("string"))), Member);
+
+        // A super-datatype with facets accepts values of its subdatatypes that satisfy them
+        let small_decimal = DataRange::DatatypeRestriction {
+            datatype: xsd("decimal"),
+            restrictions: vec![facet("maxInclusive", typed("10", "integer"))],
+        };
+        assert_eq!(literal_in_range(&five, &small_decimal), Member);
+        assert_eq!(literal_in_range(&typed("50", "integer"), &small_decimal), NonMember);
+    }
+
+    #[test]
+    fn test_numeric_membership_by_value() {
+        assert_eq!(literal_in_range(&typed("22", "integer"), &DataRange::Datatype(xsd("nonNegativeInteger"))), Member);
+        assert_eq!(literal_in_range(&typed("5", "decimal"), &DataRange::Datatype(xsd("integer"))), Member);
+        assert_eq!(literal_in_range(&typed("5.0", "decimal"), &DataRange::Datatype(xsd("integer"))), Member);
+        assert_eq!(literal_in_range(&typed("5", "integer"), &DataRange::Datatype(xsd("int"))), Member);
+        assert_eq!(literal_in_range(&typed("255", "integer"), &DataRange::Datatype(xsd("unsignedByte"))), Member);
+
+        assert_eq!(literal_in_range(&typed("-1", "integer"), &DataRange::Datatype(xsd("nonNegativeInteger"))), NonMember);
+        assert_eq!(literal_in_range(&typed("0", "integer"), &DataRange::Datatype(xsd("positiveInteger"))), NonMember);
+        assert_eq!(literal_in_range(&typed("3000000000", "integer"), &DataRange::Datatype(xsd("int"))), NonMember);
+        assert_eq!(literal_in_range(&typed("300", "integer"), &DataRange::Datatype(xsd("byte"))), NonMember);
+        assert_eq!(literal_in_range(&typed("5.5", "decimal"), &DataRange::Datatype(xsd("integer"))), NonMember);
+        assert_eq!(literal_in_range(&typed("five", "integer"), &DataRange::Datatype(xsd("integer"))), NonMember);
+        let huge = "1".repeat(50);
+        assert_eq!(literal_in_range(&typed(&huge, "integer"), &DataRange::Datatype(xsd("positiveInteger"))), Member);
+        assert_eq!(literal_in_range(&typed(&huge, "integer"), &DataRange::Datatype(xsd("unsignedLong"))), NonMember);
+    }
+
+    #[test]
+    fn test_unknown_datatype_membership() {
+        let sku = DataRange::Datatype(Datatype(IRI("http://example.com/datatypes#SKU".to_string())));
+        assert_eq!(literal_in_range(&typed("SKU-1", "string"), &sku), Unknown);
+        assert_eq!(literal_in_range(&typed("5", "integer"), &sku), Unknown);
+        assert_eq!(literal_in_range(&typed("SKU-1", "string"), &DataRange::DataComplementOf(Box::new(sku.clone()))), Unknown);
+        let own = Literal { value: "SKU-1".to_string(), datatype: Datatype(IRI("http://example.com/datatypes#SKU".to_string())), lang: None };
+        assert_eq!(literal_in_range(&own, &sku), Member);
+
+        // Facets still restrict an unknown base datatype
+        let short_sku = DataRange::DatatypeRestriction {
+            datatype: Datatype(IRI("http://example.com/datatypes#SKU".to_string())),
+            restrictions: vec![facet("maxLength", typed("3", "integer"))],
+        };
+        assert_eq!(literal_in_range(&typed("SKU-1", "string"), &short_sku), NonMember);
+    }
+
+    #[test]
+    fn test_numeric_value_equality() {
+        assert!(literals_value_equal(&typed("1", "integer"), &typed("1.0", "decimal")));
+        assert!(literals_value_equal(&typed("+007", "int"), &typed("7.000", "decimal")));
+        assert!(literals_value_equal(&typed("-0", "integer"), &typed("0.0", "decimal")));
+        assert!(literals_value_equal(&typed("-2.50", "decimal"), &typed("-2.5", "decimal")));
+        assert!(literals_value_equal(&typed("1E0", "double"), &typed("1.0", "double")));
+
+        assert!(!literals_value_equal(&typed("1", "integer"), &typed("1.5", "decimal")));
+        assert!(!literals_value_equal(&typed("1", "integer"), &typed("-1", "integer")));
+        assert!(!literals_value_equal(&typed("1", "integer"), &typed("1.0", "double")));
+        assert!(!literals_value_equal(&typed("1", "float"), &typed("1", "double")));
+        assert!(!literals_value_equal(&typed("1", "integer"), &typed("1", "string")));
+        assert!(!literals_value_equal(&typed("one", "integer"), &typed("1", "integer")));
+    }
+
+    #[test]
+    fn test_intersection_membership() {
+        let five = typed("5", "integer");
+        let both = DataRange::DataIntersectionOf(vec![
+            DataRange::Datatype(xsd("integer")),
+            DataRange::DataComplementOf(Box::new(DataRange::Datatype(xsd("string")))),
+        ]);
+        assert_eq!(literal_in_range(&five, &both), Member);
+
+        let contradictory = DataRange::DataIntersectionOf(vec![DataRange::Datatype(xsd("integer")), DataRange::Datatype(xsd("string"))]);
+        assert_eq!(literal_in_range(&five, &contradictory), NonMember);
+    }
+
+    #[test]
+    fn test_union_membership() {
+        let either = DataRange::DataUnionOf(vec![DataRange::Datatype(xsd("integer")), DataRange::Datatype(xsd("string"))]);
+        assert_eq!(literal_in_range(&typed("5", "integer"), &either), Member);
+        assert_eq!(literal_in_range(&typed("five", "string"), &either), Member);
+        assert_eq!(literal_in_range(&typed("true", "boolean"), &either), NonMember);
+    }
+
+    #[test]
+    fn test_complement_membership() {
+        let not_integer = DataRange::DataComplementOf(Box::new(DataRange::Datatype(xsd("integer"))));
+        assert_eq!(literal_in_range(&typed("5", "integer"), &not_integer), NonMember);
+        assert_eq!(literal_in_range(&typed("five", "string"), &not_integer), Member);
+    }
+
+    #[test]
+    fn test_one_of_membership() {
+        let colours = DataRange::DataOneOf(vec![typed("red", "string"), typed("green", "string")]);
+        assert_eq!(literal_in_range(&typed("red", "string"), &colours), Member);
+        assert_eq!(literal_in_range(&typed("blue", "string"), &colours), NonMember);
+
+        // Members are compared by value, not by lexical form
+        let sizes = DataRange::DataOneOf(vec![typed("1", "integer"), typed("2", "integer")]);
+        assert_eq!(literal_in_range(&typed("2.0", "decimal"), &sizes), Member);
+        assert_eq!(literal_in_range(&typed("3", "integer"), &sizes), NonMember);
+    }
+
+    #[test]
+    fn test_restriction_membership() {
+        let adult_age = DataRange::DatatypeRestriction {
+            datatype: xsd("integer"),
+            restrictions: vec![facet("minInclusive", typed("18", "integer")), facet("maxExclusive", typed("130", "integer"))],
+        };
+        assert_eq!(literal_in_range(&typed("18", "integer"), &adult_age), Member);
+        assert_eq!(literal_in_range(&typed("17", "integer"), &adult_age), NonMember);
+        assert_eq!(literal_in_range(&typed("130", "integer"), &adult_age), NonMember);
+        assert_eq!(literal_in_range(&typed("20", "string"), &adult_age), NonMember);
+
+        let short_code = DataRange::DatatypeRestriction {
+            datatype: xsd("string"),
+            restrictions: vec![facet("maxLength", typed("3", "integer"))],
+        };
+        assert_eq!(literal_in_range(&typed("abc", "string"), &short_code), Member);
+        assert_eq!(literal_in_range(&typed("abcd", "string"), &short_code), NonMember);
+    }
+
+    #[test]
+    fn test_empty_data_range() {
+        let literal = DataRange::Datatype(Datatype(IRI(RDFS_LITERAL.to_string())));
+        let empty = DataRange::DataComplementOf(Box::new(literal.clone()));
+
+        assert!(is_empty_data_range(&empty));
+        assert!(is_empty_data_range(&DataRange::DataOneOf(Vec::new())));
+        assert!(is_empty_data_range(&DataRange::DataIntersectionOf(vec![DataRange::Datatype(xsd("integer")), empty.clone()])));
+        assert!(!is_empty_data_range(&DataRange::DataUnionOf(vec![DataRange::Datatype(xsd("integer")), empty.clone()])));
+        assert!(!is_empty_data_range(&DataRange::DataComplementOf(Box::new(DataRange::Datatype(xsd("integer"))))));
+        assert!(!is_empty_data_range(&DataRange::DataComplementOf(Box::new(empty))));
+    }
+}
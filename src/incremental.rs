@@ -14,10 +14,10 @@
 //! use owl2_rs::incremental::{IncrementalReasoner, ReasoningResults};
 //! 
 //! let mut reasoner = IncrementalReasoner::new(ontology);
-//! let results = reasoner.reason_incremental();
+//! let results = reasoner.reason_incremental().unwrap();
 //! ```
 
-use crate::{Ontology, Individual, reasoner::{TableauReasoner, ClassHierarchy, IndividualTypes}};
+use crate::{Ontology, Individual, reasoner::{TableauReasoner, ClassHierarchy, ConsistencyUnknown, IndividualTypes}};
 use std::collections::HashMap;
 
 /// Results from a reasoning operation that can be reused for incremental reasoning.
@@ -71,8 +71,9 @@ impl IncrementalReasoner {
     /// 
     /// # Returns
     /// 
-    /// The results of the reasoning operation.
-    pub fn reason_incremental(&mut self) -> ReasoningResults {
+    /// The results of the reasoning operation, or an error if consistency checking
+    /// gave up.
+    pub fn reason_incremental(&mut self) -> Result<ReasoningResults, ConsistencyUnknown> {
         // Check if we can do incremental reasoning
         if self.can_do_incremental_reasoning() {
             self.perform_incremental_reasoning()
@@ -118,18 +119,18 @@ impl IncrementalReasoner {
     /// # Returns
     /// 
     /// The results of the incremental reasoning operation.
-    fn perform_incremental_reasoning(&mut self) -> ReasoningResults {
+    fn perform_incremental_reasoning(&mut self) -> Result<ReasoningResults, ConsistencyUnknown> {
         // For now, we'll just do full reasoning but in a real implementation
         // we would use the previous results to optimize the computation
         
-        let is_consistent = self.tableau_reasoner.is_consistent();
+        let is_consistent = self.tableau_reasoner.is_consistent()?;
         let class_hierarchy = if is_consistent {
-            self.tableau_reasoner.classify()
+            self.tableau_reasoner.classify()?
         } else {
             ClassHierarchy::new()
         };
         let individual_types = if is_consistent {
-            self.tableau_reasoner.realize()
+            self.tableau_reasoner.realize()?
         } else {
             HashMap::new()
         };
@@ -142,7 +143,7 @@ impl IncrementalReasoner {
         };
         
         self.previous_results = Some(results.clone());
-        results
+        Ok(results)
     }
     
     /// Performs full reasoning.
@@ -152,15 +153,15 @@ impl IncrementalReasoner {
     /// # Returns
     /// 
     /// The results of the full reasoning operation.
-    fn perform_full_reasoning(&mut self) -> ReasoningResults {
-        let is_consistent = self.tableau_reasoner.is_consistent();
+    fn perform_full_reasoning(&mut self) -> Result<ReasoningResults, ConsistencyUnknown> {
+        let is_consistent = self.tableau_reasoner.is_consistent()?;
         let class_hierarchy = if is_consistent {
-            self.tableau_reasoner.classify()
+            self.tableau_reasoner.classify()?
         } else {
             ClassHierarchy::new()
         };
         let individual_types = if is_consistent {
-            self.tableau_reasoner.realize()
+            self.tableau_reasoner.realize()?
         } else {
             HashMap::new()
         };
@@ -173,7 +174,7 @@ impl IncrementalReasoner {
         };
         
         self.previous_results = Some(results.clone());
-        results
+        Ok(results)
     }
     
     /// Clears the previous reasoning results.
@@ -201,7 +202,7 @@ mod tests {
     fn test_reasoning_with_empty_ontology() {
         let ontology = Ontology::default();
         let mut reasoner = IncrementalReasoner::new(ontology);
-        let results = reasoner.reason_incremental();
+        let results = reasoner.reason_incremental().unwrap();
         
         // Empty ontology should be consistent
         assert!(results.is_consistent);
@@ -20,12 +20,22 @@
 //! - Instance checking
 //! - OWL 2 profile compliance checking (EL, QL, RL)
 //!
+//! ## Feature Flags
+//!
+//! - `serde` - Derives `Serialize` and `Deserialize` for the ontology model types
+//!   ([`IRI`], [`ClassExpression`], [`Axiom`], [`Ontology`] and the rest). IRIs and
+//!   the other newtypes are written as their inner value. Enums are adjacently
+//!   tagged: a variant is an object whose `type` is the variant name and whose
+//!   `value` holds its fields, e.g.
+//!   `{"type":"Class","value":"http://example.com/Person"}`.
+//!
 //! ## Modules
 //!
 //! - [`api`] - The main public API for the library
 //! - [`parser`] - The OWL 2 parser implementation
 //! - [`reasoner`] - The tableau-based reasoner implementation
 //! - [`owl2_profile`] - OWL 2 profile compliance checking
+//! - [`datatypes`] - Data range membership for literals
 //!
 //! ## Basic Usage
 //!
@@ -41,7 +51,7 @@
 //!
 //! // Create a reasoner and check consistency
 //! let mut reasoner = Reasoner::new(ontology);
-//! let is_consistent = reasoner.is_consistent();
+//! let is_consistent = reasoner.is_consistent().unwrap();
 //! ```
 //!
 //! ## Documentation
@@ -61,21 +71,21 @@
 //! - `classification_test.rs` - Classification testing
 //! - `comprehensive_reasoning.rs` - Comprehensive reasoning test
 
+use std::collections::{HashMap, HashSet};
+
 pub mod parser;
 pub mod reasoner;
 pub mod api;
 pub mod test_runner;
-<<<<<<< HEAD
 pub mod owl2_profile;
 pub mod rdf;
 pub mod cache;
 pub mod sparql;
+pub mod incremental;
+pub mod datatypes;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
-=======
-pub mod incremental;
->>>>>>> feature/integrate-phase1-incremental-reasoning
 
 /// An Internationalized Resource Identifier (IRI).
 ///
@@ -91,8 +101,124 @@ pub mod incremental;
 /// let iri = IRI("http://example.com/MyClass".to_string());
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IRI(pub String);
 
+impl IRI {
+    /// Returns the syntax-based normal form of this IRI (RFC 3986, section 6.2.2).
+    ///
+    /// The scheme and host are lowercased, percent-encoded unreserved characters
+    /// (letters, digits, `-`, `.`, `_` and `~`) are decoded, and the hex digits of the
+    /// remaining percent-encodings are uppercased. Two IRIs that differ only in these
+    /// respects identify the same resource and have the same normal form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use owl2_rs::IRI;
+    ///
+    /// let encoded = IRI("HTTP://Example.COM/%7Ejohn%2fdoe".to_string());
+    /// assert_eq!(encoded.normalized(), IRI("http://example.com/~john%2Fdoe".to_string()));
+    /// ```
+    pub fn normalized(&self) -> IRI {
+        let iri = self.0.as_str();
+        let (scheme, rest) = match iri.split_once(':') {
+            Some((scheme, rest))
+                if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) =>
+            {
+                (Some(scheme.to_ascii_lowercase()), rest)
+            }
+            _ => (None, iri),
+        };
+
+        let mut rest = normalize_percent_encoding(rest);
+        if let Some(after_slashes) = rest.strip_prefix("//") {
+            let authority_end = after_slashes.find(['/', '?', '#']).unwrap_or(after_slashes.len());
+            let authority = &after_slashes[..authority_end];
+            let host_start = authority.rfind('@').map_or(0, |at| at + 1);
+            let normalized_authority = format!("{}{}", &authority[..host_start], authority[host_start..].to_ascii_lowercase());
+            rest = format!("//{}{}", normalized_authority, &after_slashes[authority_end..]);
+        }
+
+        match scheme {
+            Some(scheme) => IRI(format!("{}:{}", scheme, rest)),
+            None => IRI(rest),
+        }
+    }
+}
+
+/// Decides which IRIs denote the same thing, by mapping each IRI to a canonical form.
+///
+/// Deployments differ in which IRIs they consider equal, for example treating `http`
+/// and `https` as the same scheme or ignoring trailing slashes. A normalizer can be
+/// passed to [`OWLParser::parse_ontology_with_normalizer`](parser::OWLParser::parse_ontology_with_normalizer)
+/// and to the reasoner through
+/// [`ReasonerConfig::iri_normalizer`](reasoner::ReasonerConfig::iri_normalizer); both
+/// rewrite every IRI of the ontology with it (see [`Ontology::normalize_iris`]). The
+/// syntax-based normalization of [`IRI::normalized`] is still applied by the reasoner
+/// on top.
+///
+/// Any `Fn(&IRI) -> IRI` closure is a normalizer.
+///
+/// # Examples
+///
+/// ```rust
+/// use owl2_rs::{IriNormalizer, IRI};
+///
+/// let same_scheme = |iri: &IRI| IRI(iri.0.replacen("https://", "http://", 1));
+/// assert_eq!(same_scheme.normalize(&IRI("https://example.com/a".to_string())), IRI("http://example.com/a".to_string()));
+/// ```
+pub trait IriNormalizer: Send + Sync {
+    /// Returns the canonical form of `iri`. The default implementation returns it
+    /// unchanged.
+    fn normalize(&self, iri: &IRI) -> IRI {
+        iri.clone()
+    }
+}
+
+impl<F: Fn(&IRI) -> IRI + Send + Sync> IriNormalizer for F {
+    fn normalize(&self, iri: &IRI) -> IRI {
+        self(iri)
+    }
+}
+
+/// The normalizer that leaves every IRI as it is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultNormalizer;
+
+impl IriNormalizer for DefaultNormalizer {}
+
+/// Decodes percent-encoded unreserved characters and uppercases the hex digits of
+/// every other percent-encoding.
+fn normalize_percent_encoding(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut normalized = String::with_capacity(text.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && index + 2 < bytes.len()
+            && bytes[index + 1].is_ascii_hexdigit()
+            && bytes[index + 2].is_ascii_hexdigit()
+        {
+            let hex = &text[index + 1..index + 3];
+            let decoded = u8::from_str_radix(hex, 16).unwrap();
+            if decoded.is_ascii_alphanumeric() || matches!(decoded, b'-' | b'.' | b'_' | b'~') {
+                normalized.push(decoded as char);
+            } else {
+                normalized.push('%');
+                normalized.push_str(&hex.to_ascii_uppercase());
+            }
+            index += 3;
+        } else {
+            let c = text[index..].chars().next().unwrap();
+            normalized.push(c);
+            index += c.len_utf8();
+        }
+    }
+    normalized
+}
+
 /// A node identifier for anonymous individuals.
 ///
 /// Node IDs are used to identify anonymous individuals in OWL 2 ontologies.
@@ -106,6 +232,7 @@ pub struct IRI(pub String);
 /// let node_id = NodeID("_:b1".to_string());
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeID(pub String);
 
 /// A class in an OWL 2 ontology.
@@ -120,8 +247,37 @@ pub struct NodeID(pub String);
 /// let class = Class(IRI("http://example.com/Student".to_string()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Class(pub IRI);
 
+impl Class {
+    /// The IRI of `owl:Thing`, the class of all individuals.
+    pub const THING_IRI: &'static str = "http://www.w3.org/2002/07/owl#Thing";
+
+    /// Returns `owl:Thing`.
+    pub fn thing() -> Self {
+        Class(IRI(Self::THING_IRI.to_string()))
+    }
+
+    /// Returns true if this is `owl:Thing`.
+    pub fn is_thing(&self) -> bool {
+        self.0.0 == Self::THING_IRI
+    }
+
+    /// The IRI of `owl:Nothing`, the class with no individuals.
+    pub const NOTHING_IRI: &'static str = "http://www.w3.org/2002/07/owl#Nothing";
+
+    /// Returns `owl:Nothing`.
+    pub fn nothing() -> Self {
+        Class(IRI(Self::NOTHING_IRI.to_string()))
+    }
+
+    /// Returns true if this is `owl:Nothing`.
+    pub fn is_nothing(&self) -> bool {
+        self.0.0 == Self::NOTHING_IRI
+    }
+}
+
 /// A datatype in an OWL 2 ontology.
 ///
 /// Datatypes are used to represent sets of data values such as integers, strings, etc.
@@ -135,6 +291,7 @@ pub struct Class(pub IRI);
 /// let integer_datatype = Datatype(IRI("http://www.w3.org/2001/XMLSchema#integer".to_string()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Datatype(pub IRI);
 
 /// An object property in an OWL 2 ontology.
@@ -150,6 +307,7 @@ pub struct Datatype(pub IRI);
 /// let has_part = ObjectProperty(IRI("http://example.com/hasPart".to_string()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectProperty(pub IRI);
 
 /// A data property in an OWL 2 ontology.
@@ -165,8 +323,37 @@ pub struct ObjectProperty(pub IRI);
 /// let has_age = DataProperty(IRI("http://example.com/hasAge".to_string()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataProperty(pub IRI);
 
+impl DataProperty {
+    /// The IRI of `owl:topDataProperty`, which relates every individual to every literal.
+    pub const TOP_IRI: &'static str = "http://www.w3.org/2002/07/owl#topDataProperty";
+
+    /// Returns `owl:topDataProperty`.
+    pub fn top() -> Self {
+        DataProperty(IRI(Self::TOP_IRI.to_string()))
+    }
+
+    /// Returns true if this is `owl:topDataProperty`.
+    pub fn is_top(&self) -> bool {
+        self.0.0 == Self::TOP_IRI
+    }
+
+    /// The IRI of `owl:bottomDataProperty`, which relates no individual to any literal.
+    pub const BOTTOM_IRI: &'static str = "http://www.w3.org/2002/07/owl#bottomDataProperty";
+
+    /// Returns `owl:bottomDataProperty`.
+    pub fn bottom() -> Self {
+        DataProperty(IRI(Self::BOTTOM_IRI.to_string()))
+    }
+
+    /// Returns true if this is `owl:bottomDataProperty`.
+    pub fn is_bottom(&self) -> bool {
+        self.0.0 == Self::BOTTOM_IRI
+    }
+}
+
 /// Represents the basic building blocks of an ontology.
 ///
 /// Entities are the fundamental components used to construct OWL 2 ontologies.
@@ -180,6 +367,8 @@ pub struct DataProperty(pub IRI);
 /// * `AnnotationProperty(IRI)` - An annotation property entity.
 /// * `NamedIndividual(IRI)` - A named individual entity.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Entity {
     Class(Class),
     Datatype(Datatype),
@@ -208,13 +397,29 @@ pub enum Entity {
 /// let anonymous_individual = Individual::Anonymous(NodeID("_:b1".to_string()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Individual {
     Named(IRI),
     Anonymous(NodeID),
 }
 
+impl Individual {
+    /// Returns this individual with its IRI in normal form (see [`IRI::normalized`]).
+    /// Anonymous individuals are returned unchanged.
+    pub fn normalized(&self) -> Individual {
+        match self {
+            Individual::Named(iri) => Individual::Named(iri.normalized()),
+            Individual::Anonymous(_) => self.clone(),
+        }
+    }
+}
+
 /// Represents a literal value, which can have a datatype or a language tag.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// As in RDF 1.1, a literal with a language tag has the datatype `rdf:langString`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Literal {
     pub value: String,
     pub datatype: Datatype,
@@ -222,7 +427,13 @@ pub struct Literal {
 }
 
 /// A ClassExpression is a class or a boolean combination of classes.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Class expressions are totally ordered: first by variant, in declaration order, then
+/// structurally by their contents. This is the canonical order used to sort operands
+/// (see [`ClassExpression::canonical`]) and the concepts of completion graph nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum ClassExpression {
     Class(Class),
     ObjectIntersectionOf(Vec<ClassExpression>),
@@ -257,10 +468,171 @@ pub enum ClassExpression {
         property: ObjectPropertyExpression,
         filler: Option<Box<ClassExpression>>,
     },
+    DataSomeValuesFrom {
+        property: DataProperty,
+        filler: DataRange,
+    },
+    DataAllValuesFrom {
+        property: DataProperty,
+        filler: DataRange,
+    },
+    DataHasValue {
+        property: DataProperty,
+        value: Literal,
+    },
+    DataMinCardinality {
+        min: u32,
+        property: DataProperty,
+        filler: Option<DataRange>,
+    },
+    DataMaxCardinality {
+        max: u32,
+        property: DataProperty,
+        filler: Option<DataRange>,
+    },
+    DataExactCardinality {
+        cardinality: u32,
+        property: DataProperty,
+        filler: Option<DataRange>,
+    },
+}
+
+impl ClassExpression {
+    /// Builds `ObjectIntersectionOf` from the given operands.
+    ///
+    /// # Examples
+    ///
+    /// Building `A ⊓ ∃R.B`:
+    ///
+    /// ```rust
+    /// use owl2_rs::{Class, ClassExpression, IRI, ObjectProperty, ObjectPropertyExpression};
+    ///
+    /// let a = ClassExpression::Class(Class(IRI("http://example.com/A".to_string())));
+    /// let b = ClassExpression::Class(Class(IRI("http://example.com/B".to_string())));
+    /// let r = ObjectPropertyExpression::ObjectProperty(ObjectProperty(IRI("http://example.com/R".to_string())));
+    ///
+    /// let expr = ClassExpression::intersection([a.clone(), ClassExpression::some(r.clone(), b.clone())]);
+    ///
+    /// assert_eq!(expr, ClassExpression::ObjectIntersectionOf(vec![
+    ///     a,
+    ///     ClassExpression::ObjectSomeValuesFrom { property: r, filler: Box::new(b) },
+    /// ]));
+    /// ```
+    pub fn intersection(operands: impl IntoIterator<Item = ClassExpression>) -> Self {
+        ClassExpression::ObjectIntersectionOf(operands.into_iter().collect())
+    }
+
+    /// Builds `ObjectUnionOf` from the given operands.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use owl2_rs::{Class, ClassExpression, IRI};
+    ///
+    /// let a = ClassExpression::Class(Class(IRI("http://example.com/A".to_string())));
+    /// let b = ClassExpression::Class(Class(IRI("http://example.com/B".to_string())));
+    ///
+    /// let expr = ClassExpression::union([a.clone(), b.clone()]);
+    /// assert_eq!(expr, ClassExpression::ObjectUnionOf(vec![a, b]));
+    /// ```
+    pub fn union(operands: impl IntoIterator<Item = ClassExpression>) -> Self {
+        ClassExpression::ObjectUnionOf(operands.into_iter().collect())
+    }
+
+    /// Builds `ObjectComplementOf(inner)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use owl2_rs::{Class, ClassExpression, IRI};
+    ///
+    /// let a = ClassExpression::Class(Class(IRI("http://example.com/A".to_string())));
+    /// let not_a = ClassExpression::complement(a.clone());
+    /// assert_eq!(not_a, ClassExpression::ObjectComplementOf(Box::new(a)));
+    /// ```
+    pub fn complement(inner: ClassExpression) -> Self {
+        ClassExpression::ObjectComplementOf(Box::new(inner))
+    }
+
+    /// Builds the existential restriction `ObjectSomeValuesFrom(property filler)`.
+    pub fn some(property: ObjectPropertyExpression, filler: ClassExpression) -> Self {
+        ClassExpression::ObjectSomeValuesFrom { property, filler: Box::new(filler) }
+    }
+
+    /// Builds the universal restriction `ObjectAllValuesFrom(property filler)`.
+    pub fn all(property: ObjectPropertyExpression, filler: ClassExpression) -> Self {
+        ClassExpression::ObjectAllValuesFrom { property, filler: Box::new(filler) }
+    }
+
+    /// Returns the canonical form of this expression: the operands of every
+    /// intersection, union and enumeration, at any depth, are sorted and deduplicated.
+    ///
+    /// Two expressions that differ only in operand order have the same canonical form.
+    ///
+    /// ```rust
+    /// use owl2_rs::{Class, ClassExpression, IRI};
+    ///
+    /// let a = ClassExpression::Class(Class(IRI("http://example.com/A".to_string())));
+    /// let b = ClassExpression::Class(Class(IRI("http://example.com/B".to_string())));
+    /// assert_eq!(
+    ///     ClassExpression::intersection([b.clone(), a.clone()]).canonical(),
+    ///     ClassExpression::intersection([a, b]).canonical(),
+    /// );
+    /// ```
+    pub fn canonical(&self) -> ClassExpression {
+        let sorted = |operands: &[ClassExpression]| {
+            let mut operands: Vec<ClassExpression> = operands.iter().map(ClassExpression::canonical).collect();
+            operands.sort();
+            operands.dedup();
+            operands
+        };
+        let boxed = |filler: &ClassExpression| Box::new(filler.canonical());
+        match self {
+            ClassExpression::Class(_) | ClassExpression::ObjectHasValue { .. } | ClassExpression::ObjectHasSelf(_) => self.clone(),
+            ClassExpression::ObjectIntersectionOf(operands) => ClassExpression::ObjectIntersectionOf(sorted(operands)),
+            ClassExpression::ObjectUnionOf(operands) => ClassExpression::ObjectUnionOf(sorted(operands)),
+            ClassExpression::ObjectComplementOf(inner) => ClassExpression::ObjectComplementOf(boxed(inner)),
+            ClassExpression::ObjectOneOf(individuals) => {
+                let mut individuals = individuals.clone();
+                individuals.sort();
+                individuals.dedup();
+                ClassExpression::ObjectOneOf(individuals)
+            }
+            ClassExpression::ObjectSomeValuesFrom { property, filler } => {
+                ClassExpression::ObjectSomeValuesFrom { property: property.clone(), filler: boxed(filler) }
+            }
+            ClassExpression::ObjectAllValuesFrom { property, filler } => {
+                ClassExpression::ObjectAllValuesFrom { property: property.clone(), filler: boxed(filler) }
+            }
+            ClassExpression::ObjectMinCardinality { min, property, filler } => ClassExpression::ObjectMinCardinality {
+                min: *min,
+                property: property.clone(),
+                filler: filler.as_deref().map(boxed),
+            },
+            ClassExpression::ObjectMaxCardinality { max, property, filler } => ClassExpression::ObjectMaxCardinality {
+                max: *max,
+                property: property.clone(),
+                filler: filler.as_deref().map(boxed),
+            },
+            ClassExpression::ObjectExactCardinality { cardinality, property, filler } => ClassExpression::ObjectExactCardinality {
+                cardinality: *cardinality,
+                property: property.clone(),
+                filler: filler.as_deref().map(boxed),
+            },
+            ClassExpression::DataSomeValuesFrom { .. }
+            | ClassExpression::DataAllValuesFrom { .. }
+            | ClassExpression::DataHasValue { .. }
+            | ClassExpression::DataMinCardinality { .. }
+            | ClassExpression::DataMaxCardinality { .. }
+            | ClassExpression::DataExactCardinality { .. } => self.clone(),
+        }
+    }
 }
 
 /// An ObjectPropertyExpression is an object property or an inverse of an object property.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum ObjectPropertyExpression {
     ObjectProperty(ObjectProperty),
     InverseObjectProperty(ObjectProperty),
@@ -269,6 +641,8 @@ pub enum ObjectPropertyExpression {
 
 /// Axioms about classes.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum ClassAxiom {
     SubClassOf {
         sub_class: ClassExpression,
@@ -288,6 +662,8 @@ pub enum ClassAxiom {
 
 /// Axioms about object properties.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum ObjectPropertyAxiom {
     SubObjectPropertyOf {
         sub_property: ObjectPropertyExpression,
@@ -321,7 +697,9 @@ pub enum ObjectPropertyAxiom {
 }
 
 /// Represents a data range in OWL 2.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum DataRange {
     Datatype(Datatype),
     DataIntersectionOf(Vec<DataRange>),
@@ -336,6 +714,8 @@ pub enum DataRange {
 
 /// Axioms about data properties.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum DataPropertyAxiom {
     SubDataPropertyOf {
         sub_property: DataProperty,
@@ -360,6 +740,8 @@ pub enum DataPropertyAxiom {
 
 /// Assertions about individuals.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Assertion {
     SameIndividual {
         individuals: Vec<Individual>,
@@ -398,17 +780,55 @@ pub enum Assertion {
     },
 }
 
+/// The value of an annotation: an IRI, an anonymous individual or a literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum AnnotationValue {
+    IRI(IRI),
+    AnonymousIndividual(NodeID),
+    Literal(Literal),
+}
+
+/// An annotation on the ontology itself, such as its title or version info.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Annotation {
+    pub property: IRI,
+    pub value: AnnotationValue,
+}
+
+/// Axioms about annotations.
+///
+/// Annotations carry no logical meaning; the reasoner ignores them unless it is
+/// configured with handlers that translate them into logical axioms.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum AnnotationAxiom {
+    AnnotationAssertion {
+        property: IRI,
+        subject: IRI,
+        value: AnnotationValue,
+    },
+}
+
 /// A general axiom type that encompasses all specific axiom types.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Axiom {
     Class(ClassAxiom),
     ObjectProperty(ObjectPropertyAxiom),
     DataProperty(DataPropertyAxiom),
     Assertion(Assertion),
+    Annotation(AnnotationAxiom),
+    Declaration(Entity),
 }
 
 /// Tracks changes made to an ontology for incremental reasoning.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeTracker {
     /// The revision number of the ontology.
     pub revision: u64,
@@ -418,9 +838,9 @@ pub struct ChangeTracker {
     pub removed_axioms: Vec<Axiom>,
 }
 
-<<<<<<< HEAD
 /// Represents an explanation for an entailment.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Explanation {
     /// The entailment being explained
     pub entailment: String,
@@ -430,19 +850,22 @@ pub struct Explanation {
     pub description: String,
 }
 
-/// Represents an explanation for an entailment.
-#[derive(Debug, Clone)]
-pub struct Explanation {
-    /// The entailment being explained
-    pub entailment: String,
-    /// The axioms that justify the entailment
-    pub justifications: Vec<Axiom>,
-    /// A human-readable explanation
-    pub description: String,
+/// The characteristics declared for an object property.
+///
+/// Each flag is set when the ontology contains the corresponding characteristic axiom
+/// for the property. See [`Ontology::object_property_characteristics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PropertyCharacteristics {
+    pub functional: bool,
+    pub inverse_functional: bool,
+    pub reflexive: bool,
+    pub irreflexive: bool,
+    pub symmetric: bool,
+    pub asymmetric: bool,
+    pub transitive: bool,
 }
 
-=======
->>>>>>> feature/integrate-phase1-incremental-reasoning
 /// Represents a complete OWL 2 ontology.
 ///
 /// An ontology consists of a set of axioms that describe the relationships
@@ -451,9 +874,12 @@ pub struct Explanation {
 /// # Fields
 ///
 /// * `direct_imports` - IRIs of ontologies that are directly imported by this ontology.
+/// * `annotations` - Annotations on the ontology itself, in document order.
 /// * `axioms` - The axioms that make up this ontology.
-<<<<<<< HEAD
 /// * `change_tracker` - Tracks changes for incremental reasoning.
+/// * `axiom_sources` - For each axiom (by index), the IRI of the imported ontology it was
+///   merged from, or `None` if it was asserted in this ontology. Remove axioms with
+///   [`Ontology::remove_axiom`] so the two stay aligned.
 ///
 /// # Examples
 ///
@@ -462,173 +888,1115 @@ pub struct Explanation {
 ///
 /// let ontology = Ontology::default();
 /// ```
-#[derive(Debug, Clone)]
-=======
 #[derive(Debug, Clone, Default)]
->>>>>>> feature/integrate-phase1-incremental-reasoning
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ontology {
+    /// The IRI naming the ontology, if its header gives one.
+    pub ontology_iri: Option<IRI>,
+    /// The IRI of this version of the ontology; only present with an ontology IRI.
+    pub version_iri: Option<IRI>,
     pub direct_imports: Vec<IRI>,
+    pub annotations: Vec<Annotation>,
     pub axioms: Vec<Axiom>,
     pub change_tracker: ChangeTracker,
-<<<<<<< HEAD
+    pub axiom_sources: Vec<Option<IRI>>,
+    /// The prefixes declared by the document the ontology was parsed from, if any,
+    /// kept so they can be written back out.
+    pub prefixes: Option<parser::PrefixMap>,
 }
 
-impl Default for Ontology {
-    fn default() -> Self {
-        Ontology {
-            direct_imports: Vec::new(),
-            axioms: Vec::new(),
-            change_tracker: ChangeTracker::default(),
-        }
+impl Ontology {
+    /// Returns the value of the first ontology annotation on `property`, if any.
+    pub fn annotation(&self, property: &IRI) -> Option<&AnnotationValue> {
+        self.annotations.iter().find(|annotation| &annotation.property == property).map(|annotation| &annotation.value)
     }
-=======
->>>>>>> feature/integrate-phase1-incremental-reasoning
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_iri_creation() {
-        let iri = IRI("http://example.com/class".to_string());
-        assert_eq!(iri.0, "http://example.com/class");
+    /// Returns the IRI of the imported ontology the axiom at `index` came from.
+    ///
+    /// Returns `None` for axioms asserted directly in this ontology. Axioms pushed
+    /// without a matching entry in `axiom_sources` are treated as local.
+    pub fn axiom_source(&self, index: usize) -> Option<&IRI> {
+        self.axiom_sources.get(index).and_then(|source| source.as_ref())
     }
 
-    #[test]
-    fn test_entity_creation() {
-        let class_entity = Entity::Class(Class(IRI("http://example.com/class".to_string())));
-        assert!(matches!(class_entity, Entity::Class(_)));
-
-        let datatype_entity = Entity::Datatype(Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())));
-        assert!(matches!(datatype_entity, Entity::Datatype(_)));
+    /// Removes and returns the axiom at `index`, keeping `axiom_sources` aligned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_axiom(&mut self, index: usize) -> Axiom {
+        if index < self.axiom_sources.len() {
+            self.axiom_sources.remove(index);
+        }
+        self.axioms.remove(index)
+    }
 
-        let object_property_entity = Entity::ObjectProperty(ObjectProperty(IRI("http://example.com/hasPart".to_string())));
-        assert!(matches!(object_property_entity, Entity::ObjectProperty(_)));
+    /// Merges the axioms of an imported ontology into this one, recording `source`
+    /// as their provenance.
+    ///
+    /// Axioms that the imported ontology itself merged from further imports keep their
+    /// original provenance. Axioms already present are not duplicated.
+    pub fn merge_import(&mut self, source: IRI, imported: Ontology) {
+        self.axiom_sources.resize(self.axioms.len(), None);
+        let mut present: HashSet<Axiom> = self.axioms.iter().cloned().collect();
+        for (index, axiom) in imported.axioms.iter().enumerate() {
+            if !present.insert(axiom.clone()) {
+                continue;
+            }
+            let provenance = imported.axiom_source(index).cloned().unwrap_or_else(|| source.clone());
+            self.axioms.push(axiom.clone());
+            self.axiom_sources.push(Some(provenance));
+        }
+    }
 
-        let data_property_entity = Entity::DataProperty(DataProperty(IRI("http://example.com/hasAge".to_string())));
-        assert!(matches!(data_property_entity, Entity::DataProperty(_)));
+    /// Collects the asserted domains of each object property.
+    ///
+    /// A range asserted on `ObjectInverseOf(P)` is reported as a domain of `P`.
+    /// Property chains have no domain of their own and are skipped.
+    pub fn property_domains(&self) -> HashMap<ObjectProperty, Vec<ClassExpression>> {
+        self.object_property_restrictions(true)
+    }
 
-        let annotation_property_entity = Entity::AnnotationProperty(IRI("http://example.com/comment".to_string()));
-        assert!(matches!(annotation_property_entity, Entity::AnnotationProperty(_)));
+    /// Collects the asserted ranges of each object property.
+    ///
+    /// A domain asserted on `ObjectInverseOf(P)` is reported as a range of `P`.
+    /// Property chains are skipped.
+    pub fn property_ranges(&self) -> HashMap<ObjectProperty, Vec<ClassExpression>> {
+        self.object_property_restrictions(false)
+    }
 
-        let named_individual_entity = Entity::NamedIndividual(IRI("http://example.com/individual1".to_string()));
-        assert!(matches!(named_individual_entity, Entity::NamedIndividual(_)));
+    /// Collects the asserted domains of each data property.
+    pub fn data_property_domains(&self) -> HashMap<DataProperty, Vec<ClassExpression>> {
+        let mut domains: HashMap<DataProperty, Vec<ClassExpression>> = HashMap::new();
+        for axiom in &self.axioms {
+            if let Axiom::DataProperty(DataPropertyAxiom::DataPropertyDomain { property, domain }) = axiom {
+                domains.entry(property.clone()).or_default().push(domain.clone());
+            }
+        }
+        domains
     }
 
-    #[test]
-    fn test_class_creation() {
-        let class = Class(IRI("http://example.com/MyClass".to_string()));
-        assert_eq!(class.0 .0, "http://example.com/MyClass");
+    /// Collects the asserted ranges of each data property.
+    pub fn data_property_ranges(&self) -> HashMap<DataProperty, Vec<DataRange>> {
+        let mut ranges: HashMap<DataProperty, Vec<DataRange>> = HashMap::new();
+        for axiom in &self.axioms {
+            if let Axiom::DataProperty(DataPropertyAxiom::DataPropertyRange { property, range }) = axiom {
+                ranges.entry(property.clone()).or_default().push(range.clone());
+            }
+        }
+        ranges
     }
 
-    #[test]
-    fn test_datatype_creation() {
-        let datatype = Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string()));
-        assert_eq!(datatype.0 .0, "http://www.w3.org/2001/XMLSchema#string");
+    /// Summarizes the told characteristics of each object property.
+    ///
+    /// Only properties with at least one characteristic axiom appear in the map. A
+    /// characteristic declared on `ObjectInverseOf(P)` is recorded for `P`, with
+    /// functional and inverse functional swapped. Property chains are skipped.
+    pub fn object_property_characteristics(&self) -> HashMap<ObjectProperty, PropertyCharacteristics> {
+        let mut result: HashMap<ObjectProperty, PropertyCharacteristics> = HashMap::new();
+        for axiom in &self.axioms {
+            let Axiom::ObjectProperty(axiom) = axiom else {
+                continue;
+            };
+            let property = match axiom {
+                ObjectPropertyAxiom::FunctionalObjectProperty { property }
+                | ObjectPropertyAxiom::InverseFunctionalObjectProperty { property }
+                | ObjectPropertyAxiom::ReflexiveObjectProperty { property }
+                | ObjectPropertyAxiom::IrreflexiveObjectProperty { property }
+                | ObjectPropertyAxiom::SymmetricObjectProperty { property }
+                | ObjectPropertyAxiom::AsymmetricObjectProperty { property }
+                | ObjectPropertyAxiom::TransitiveObjectProperty { property } => property,
+                _ => continue,
+            };
+            let (named, inverted) = match property {
+                ObjectPropertyExpression::ObjectProperty(p) => (p, false),
+                ObjectPropertyExpression::InverseObjectProperty(p) => (p, true),
+                ObjectPropertyExpression::ObjectPropertyChain(_) => continue,
+            };
+            let characteristics = result.entry(named.clone()).or_default();
+            match axiom {
+                ObjectPropertyAxiom::FunctionalObjectProperty { .. } if inverted => characteristics.inverse_functional = true,
+                ObjectPropertyAxiom::FunctionalObjectProperty { .. } => characteristics.functional = true,
+                ObjectPropertyAxiom::InverseFunctionalObjectProperty { .. } if inverted => characteristics.functional = true,
+                ObjectPropertyAxiom::InverseFunctionalObjectProperty { .. } => characteristics.inverse_functional = true,
+                ObjectPropertyAxiom::ReflexiveObjectProperty { .. } => characteristics.reflexive = true,
+                ObjectPropertyAxiom::IrreflexiveObjectProperty { .. } => characteristics.irreflexive = true,
+                ObjectPropertyAxiom::SymmetricObjectProperty { .. } => characteristics.symmetric = true,
+                ObjectPropertyAxiom::AsymmetricObjectProperty { .. } => characteristics.asymmetric = true,
+                _ => characteristics.transitive = true,
+            }
+        }
+        result
     }
 
-    #[test]
-    fn test_object_property_creation() {
-        let object_property = ObjectProperty(IRI("http://example.com/hasPart".to_string()));
-        assert_eq!(object_property.0 .0, "http://example.com/hasPart");
+    /// Computes a hash of the ontology's logical content that does not depend on the
+    /// order of imports or axioms.
+    ///
+    /// The class expressions in each axiom are put in canonical form (operands of
+    /// intersections and unions, and of class axioms such as `EquivalentClasses`, are
+    /// sorted). Each axiom is then rendered in Functional-Style Syntax, the rendered
+    /// axioms and the import IRIs are sorted and deduplicated, and the result is hashed with 64-bit
+    /// FNV-1a. The hash is stable across runs, platforms and compiler versions, so it
+    /// can be used as a content address. Axiom provenance and the change tracker do not
+    /// take part.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use owl2_rs::api::load_ontology;
+    ///
+    /// let a = load_ontology(r#"Ontology(<http://example.com/o>
+    ///   SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+    ///   SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+    /// )"#).unwrap();
+    /// let b = load_ontology(r#"Ontology(<http://example.com/o>
+    ///   SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+    ///   SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+    /// )"#).unwrap();
+    ///
+    /// assert_eq!(a.canonical_hash(), b.canonical_hash());
+    /// ```
+    pub fn canonical_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut imports: Vec<String> = self.direct_imports.iter().map(|iri| format!("Import(<{}>)", iri.0)).collect();
+        imports.sort();
+        imports.dedup();
+        let mut axioms: Vec<String> = self.axioms.iter().map(|axiom| parser::fss_axiom(&canonical_axiom(axiom))).collect();
+        axioms.sort();
+        axioms.dedup();
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for line in imports.iter().chain(&axioms) {
+            for byte in line.bytes().chain(std::iter::once(b'\n')) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
     }
 
-    #[test]
-    fn test_data_property_creation() {
-        let data_property = DataProperty(IRI("http://example.com/hasAge".to_string()));
-        assert_eq!(data_property.0 .0, "http://example.com/hasAge");
+    /// Collects every entity mentioned in the ontology's axioms.
+    ///
+    /// An IRI used in several roles (a pun) contributes one entity per role. The
+    /// datatypes of literals are included, as are entities that are only declared.
+    pub fn signature(&self) -> HashSet<Entity> {
+        let mut entities = HashSet::new();
+        for axiom in &self.axioms {
+            visit_axiom_entities(axiom, &mut |entity| {
+                entities.insert(entity);
+            });
+        }
+        entities
     }
 
-    #[test]
-    fn test_individual_creation() {
-        let named_individual = Individual::Named(IRI("http://example.com/individual".to_string()));
-        assert!(matches!(named_individual, Individual::Named(_)));
+    /// Returns the IRIs used in more than one entity role (puns), sorted.
+    ///
+    /// Roles are taken from [`Ontology::signature`], so an IRI that names both a class
+    /// and an individual, for example, is reported once.
+    pub fn puns(&self) -> Vec<IRI> {
+        let mut roles: HashMap<IRI, usize> = HashMap::new();
+        for entity in self.signature() {
+            let iri = match entity {
+                Entity::Class(Class(iri))
+                | Entity::Datatype(Datatype(iri))
+                | Entity::ObjectProperty(ObjectProperty(iri))
+                | Entity::DataProperty(DataProperty(iri))
+                | Entity::AnnotationProperty(iri)
+                | Entity::NamedIndividual(iri) => iri,
+            };
+            *roles.entry(iri).or_default() += 1;
+        }
+        let mut puns: Vec<IRI> = roles.into_iter().filter(|(_, count)| *count > 1).map(|(iri, _)| iri).collect();
+        puns.sort();
+        puns
+    }
 
-        let anonymous_individual = Individual::Anonymous(NodeID("_:b1".to_string()));
-        assert!(matches!(anonymous_individual, Individual::Anonymous(_)));
+    /// Splits the ontology into its terminological part (TBox) and its assertional
+    /// part (ABox), returned in that order.
+    ///
+    /// The ABox holds the assertions about individuals: class and property assertions
+    /// (including negative ones), `SameIndividual` and `DifferentIndividuals`. Every
+    /// other axiom goes to the TBox, including `HasKey`, which constrains a class, and
+    /// annotation assertions. Both parts keep the header of the original (its IRIs,
+    /// imports, annotations and prefixes) and the provenance of their axioms.
+    pub fn split_tbox_abox(&self) -> (Ontology, Ontology) {
+        let (mut tbox, mut abox) = (self.empty_with_header(), self.empty_with_header());
+        for (index, axiom) in self.axioms.iter().enumerate() {
+            let target = match axiom {
+                Axiom::Assertion(Assertion::HasKey { .. }) => &mut tbox,
+                Axiom::Assertion(_) => &mut abox,
+                _ => &mut tbox,
+            };
+            target.axioms.push(axiom.clone());
+            target.axiom_sources.push(self.axiom_source(index).cloned());
+        }
+        (tbox, abox)
     }
 
-    #[test]
-    fn test_node_id_creation() {
-        let node_id = NodeID("_:b1".to_string());
-        assert_eq!(node_id.0, "_:b1");
+    /// Returns an ontology with the same header (IRIs, imports, annotations and
+    /// prefixes) as this one, but no axioms.
+    pub(crate) fn empty_with_header(&self) -> Ontology {
+        Ontology {
+            ontology_iri: self.ontology_iri.clone(),
+            version_iri: self.version_iri.clone(),
+            direct_imports: self.direct_imports.clone(),
+            annotations: self.annotations.clone(),
+            prefixes: self.prefixes.clone(),
+            ..Ontology::default()
+        }
     }
 
-    #[test]
-    fn test_literal_creation() {
-        let literal = Literal {
-            value: "hello".to_string(),
-            datatype: Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())),
-            lang: Some("en".to_string()),
-        };
-        assert_eq!(literal.value, "hello");
-        assert_eq!(literal.lang, Some("en".to_string()));
+    /// Counts how often each object and data property occurs in the ontology's axioms,
+    /// including occurrences nested in class expressions and property chains.
+    ///
+    /// Properties are keyed by IRI, so an inverse `ObjectInverseOf(P)` counts towards
+    /// `P`. Declarations do not count as uses, so properties that are only declared
+    /// or never mentioned do not appear in the result.
+    pub fn property_usage(&self) -> HashMap<IRI, usize> {
+        let mut usage: HashMap<IRI, usize> = HashMap::new();
+        for axiom in self.axioms.iter().filter(|axiom| !matches!(axiom, Axiom::Declaration(_))) {
+            visit_axiom_entities(axiom, &mut |entity| {
+                if let Entity::ObjectProperty(ObjectProperty(iri)) | Entity::DataProperty(DataProperty(iri)) = entity {
+                    *usage.entry(iri).or_default() += 1;
+                }
+            });
+        }
+        usage
     }
 
-    #[test]
-    fn test_subclassof_axiom() {
-        let child_class = Class(IRI("http://example.com/child".to_string()));
-        let parent_class = Class(IRI("http://example.com/parent".to_string()));
+    /// Returns the entities marked deprecated with an `owl:deprecated` annotation.
+    ///
+    /// An annotation assertion counts when its value is the literal `true` (or `1`)
+    /// typed as `xsd:boolean`. The kind of each entity is taken from how its IRI is
+    /// used in the ontology; an IRI used in several roles yields one entity per role,
+    /// and a deprecated IRI that is not otherwise used yields none.
+    pub fn deprecated_entities(&self) -> Vec<Entity> {
+        const OWL_DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+        const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+
+        let signature = self.signature();
+        let mut result = Vec::new();
+        for axiom in &self.axioms {
+            let Axiom::Annotation(AnnotationAxiom::AnnotationAssertion { property, subject, value }) = axiom else {
+                continue;
+            };
+            let is_true = matches!(value, AnnotationValue::Literal(literal)
+                if literal.datatype.0.0 == XSD_BOOLEAN && matches!(literal.value.trim(), "true" | "1"));
+            if property.0 != OWL_DEPRECATED || !is_true {
+                continue;
+            }
+            for candidate in [
+                Entity::Class(Class(subject.clone())),
+                Entity::Datatype(Datatype(subject.clone())),
+                Entity::ObjectProperty(ObjectProperty(subject.clone())),
+                Entity::DataProperty(DataProperty(subject.clone())),
+                Entity::AnnotationProperty(subject.clone()),
+                Entity::NamedIndividual(subject.clone()),
+            ] {
+                if signature.contains(&candidate) && !result.contains(&candidate) {
+                    result.push(candidate);
+                }
+            }
+        }
+        result
+    }
 
-        let axiom = ClassAxiom::SubClassOf {
-            sub_class: ClassExpression::Class(child_class.clone()),
-            super_class: ClassExpression::Class(parent_class.clone()),
+    /// Returns the `rdfs:label` of the entity named by `iri`.
+    ///
+    /// With a language, only labels tagged with it (compared case-insensitively) are
+    /// considered. Without one, an untagged label is preferred over tagged ones. If
+    /// several labels qualify, the first asserted one is returned.
+    pub fn label_of(&self, iri: &IRI, lang: Option<&str>) -> Option<String> {
+        const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+
+        let labels: Vec<&Literal> = self.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::Annotation(AnnotationAxiom::AnnotationAssertion { property, subject, value: AnnotationValue::Literal(literal) })
+                    if property.0 == RDFS_LABEL && subject == iri => Some(literal),
+                _ => None,
+            })
+            .collect();
+        let label = match lang {
+            Some(lang) => labels.iter().find(|label| label.lang.as_deref().is_some_and(|tag| tag.eq_ignore_ascii_case(lang))),
+            None => labels.iter().find(|label| label.lang.is_none()).or(labels.first()),
         };
+        label.map(|label| label.value.clone())
+    }
 
-        if let ClassAxiom::SubClassOf { sub_class, super_class } = axiom {
-            assert_eq!(sub_class, ClassExpression::Class(child_class));
-            assert_eq!(super_class, ClassExpression::Class(parent_class));
-        } else {
-            panic!("Axiom is not SubClassOf");
+    /// Rewrites every IRI in the ontology (entities, individuals, datatypes, facets,
+    /// annotations, imports and the ontology and version IRIs) with `normalizer`, so
+    /// IRIs the normalizer considers equal become identical.
+    pub fn normalize_iris(&mut self, normalizer: &dyn IriNormalizer) {
+        let mut normalize = |iri: &mut IRI| *iri = normalizer.normalize(iri);
+        self.ontology_iri.iter_mut().for_each(&mut normalize);
+        self.version_iri.iter_mut().for_each(&mut normalize);
+        self.direct_imports.iter_mut().for_each(&mut normalize);
+        for annotation in &mut self.annotations {
+            normalize(&mut annotation.property);
+            map_annotation_value_iris(&mut annotation.value, &mut normalize);
+        }
+        self.axiom_sources.iter_mut().flatten().for_each(&mut normalize);
+        for axiom in &mut self.axioms {
+            map_axiom_iris(axiom, &mut normalize);
         }
     }
 
-    #[test]
-    fn test_subobjectpropertyof_axiom() {
-        let sub_prop = ObjectProperty(IRI("http://example.com/subProp".to_string()));
-        let super_prop = ObjectProperty(IRI("http://example.com/superProp".to_string()));
+    /// Puts the IRIs of the named individuals in the ontology's axioms in normal form
+    /// (see [`IRI::normalized`]), so different encodings of one IRI denote a single
+    /// individual. Other IRIs are left as they are.
+    pub(crate) fn normalize_individuals(&mut self) {
+        for axiom in &mut self.axioms {
+            map_axiom_iris(axiom, &mut NormalizeIndividuals);
+        }
+    }
 
-        let axiom = ObjectPropertyAxiom::SubObjectPropertyOf {
-            sub_property: ObjectPropertyExpression::ObjectProperty(sub_prop.clone()),
-            super_property: ObjectPropertyExpression::ObjectProperty(super_prop.clone()),
+    /// Finds cycles in the told subclass hierarchy.
+    ///
+    /// Builds the graph of `SubClassOf` axioms between named classes and returns its
+    /// strongly connected components with more than one class (found with Tarjan's
+    /// algorithm). Every class in such a component is implicitly equivalent to the
+    /// others, which is often unintended. Classes within a cycle, and the cycles
+    /// themselves, are sorted.
+    pub fn subclass_cycles(&self) -> Vec<Vec<Class>> {
+        let mut graph: HashMap<&Class, Vec<&Class>> = HashMap::new();
+        for axiom in &self.axioms {
+            if let Axiom::Class(ClassAxiom::SubClassOf {
+                sub_class: ClassExpression::Class(sub),
+                super_class: ClassExpression::Class(sup),
+            }) = axiom {
+                graph.entry(sub).or_default().push(sup);
+                graph.entry(sup).or_default();
+            }
+        }
+        let mut classes: Vec<&Class> = graph.keys().copied().collect();
+        classes.sort();
+
+        let mut tarjan = Tarjan {
+            graph: &graph,
+            index: HashMap::new(),
+            low_link: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            next_index: 0,
+            components: Vec::new(),
         };
-
-        if let ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } = axiom {
-            assert_eq!(sub_property, ObjectPropertyExpression::ObjectProperty(sub_prop));
-            assert_eq!(super_property, ObjectPropertyExpression::ObjectProperty(super_prop));
-        } else {
-            panic!("Axiom is not SubObjectPropertyOf");
+        for class in classes {
+            if !tarjan.index.contains_key(class) {
+                tarjan.visit(class);
+            }
         }
-    }
 
-    #[test]
-    fn test_subdatapropertyof_axiom() {
-        let sub_prop = DataProperty(IRI("http://example.com/subProp".to_string()));
-        let super_prop = DataProperty(IRI("http://example.com/superProp".to_string()));
+        let mut cycles: Vec<Vec<Class>> = tarjan.components.into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut cycle: Vec<Class> = component.into_iter().cloned().collect();
+                cycle.sort();
+                cycle
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
 
-        let axiom = DataPropertyAxiom::SubDataPropertyOf {
-            sub_property: sub_prop.clone(),
-            super_property: super_prop.clone(),
+    /// Checks inverse property pairs for domains and ranges that do not mirror each
+    /// other.
+    ///
+    /// For `InverseObjectProperties(R, S)` the domain of `R` should be the range of `S`
+    /// and the range of `R` the domain of `S`. A warning is reported for each side that
+    /// is declared on both properties but differs; a side declared on only one property
+    /// is not flagged, since the other simply inherits it. Pairs involving
+    /// `ObjectInverseOf` are skipped. Warnings are sorted.
+    pub fn inverse_consistency_warnings(&self) -> Vec<String> {
+        let domains = self.property_domains();
+        let ranges = self.property_ranges();
+        let declared = |map: &HashMap<ObjectProperty, Vec<ClassExpression>>, property: &ObjectProperty| {
+            let mut expressions = map.get(property).cloned().unwrap_or_default();
+            expressions.sort();
+            expressions.dedup();
+            expressions
+        };
+        let render = |expressions: &[ClassExpression]| {
+            expressions.iter().map(parser::fss_class_expression).collect::<Vec<_>>().join(" ")
         };
 
-        if let DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } = axiom {
-            assert_eq!(sub_property, sub_prop);
-            assert_eq!(super_property, super_prop);
-        } else {
-            panic!("Axiom is not SubDataPropertyOf");
+        let mut warnings = Vec::new();
+        for axiom in &self.axioms {
+            let Axiom::ObjectProperty(ObjectPropertyAxiom::InverseObjectProperties {
+                prop1: ObjectPropertyExpression::ObjectProperty(r),
+                prop2: ObjectPropertyExpression::ObjectProperty(s),
+            }) = axiom else {
+                continue;
+            };
+            for (first, second) in [(r, s), (s, r)] {
+                let domain = declared(&domains, first);
+                let range = declared(&ranges, second);
+                if !domain.is_empty() && !range.is_empty() && domain != range {
+                    warnings.push(format!(
+                        "<{}> and <{}> are inverses, but the domain of <{}> is {} while the range of <{}> is {}",
+                        r.0.0, s.0.0, first.0.0, render(&domain), second.0.0, render(&range),
+                    ));
+                }
+            }
         }
+        warnings.sort();
+        warnings.dedup();
+        warnings
     }
 
-    #[test]
-    fn test_classassertion_axiom() {
-        let class = Class(IRI("http://example.com/class".to_string()));
-        let individual = Individual::Named(IRI("http://example.com/individual".to_string()));
+    /// Gathers domains (`want_domains == true`) or ranges of object properties,
+    /// swapping the two for axioms stated on an inverse property.
+    fn object_property_restrictions(&self, want_domains: bool) -> HashMap<ObjectProperty, Vec<ClassExpression>> {
+        let mut result: HashMap<ObjectProperty, Vec<ClassExpression>> = HashMap::new();
+        for axiom in &self.axioms {
+            let (property, expression, is_domain) = match axiom {
+                Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyDomain { property, domain }) => (property, domain, true),
+                Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyRange { property, range }) => (property, range, false),
+                _ => continue,
+            };
+            let (named, is_domain) = match property {
+                ObjectPropertyExpression::ObjectProperty(p) => (p, is_domain),
+                ObjectPropertyExpression::InverseObjectProperty(p) => (p, !is_domain),
+                ObjectPropertyExpression::ObjectPropertyChain(_) => continue,
+            };
+            if is_domain == want_domains {
+                result.entry(named.clone()).or_default().push(expression.clone());
+            }
+        }
+        result
+    }
+}
 
-        let axiom = Assertion::ClassAssertion {
-            class: ClassExpression::Class(class.clone()),
-            individual: individual.clone(),
+/// Returns `axiom` with its class expressions in canonical form and the operands of
+/// n-ary class axioms sorted. Axioms without class expressions are returned unchanged.
+fn canonical_axiom(axiom: &Axiom) -> Axiom {
+    let sorted = |classes: &[ClassExpression]| {
+        let mut classes: Vec<ClassExpression> = classes.iter().map(ClassExpression::canonical).collect();
+        classes.sort();
+        classes.dedup();
+        classes
+    };
+    match axiom {
+        Axiom::Class(ClassAxiom::SubClassOf { sub_class, super_class }) => Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: sub_class.canonical(),
+            super_class: super_class.canonical(),
+        }),
+        Axiom::Class(ClassAxiom::EquivalentClasses { classes }) => Axiom::Class(ClassAxiom::EquivalentClasses { classes: sorted(classes) }),
+        Axiom::Class(ClassAxiom::DisjointClasses { classes }) => Axiom::Class(ClassAxiom::DisjointClasses { classes: sorted(classes) }),
+        Axiom::Class(ClassAxiom::DisjointUnion { class, disjoint_classes }) => Axiom::Class(ClassAxiom::DisjointUnion {
+            class: class.clone(),
+            disjoint_classes: sorted(disjoint_classes),
+        }),
+        Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyDomain { property, domain }) => {
+            Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyDomain { property: property.clone(), domain: domain.canonical() })
+        }
+        Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyRange { property, range }) => {
+            Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyRange { property: property.clone(), range: range.canonical() })
+        }
+        Axiom::DataProperty(DataPropertyAxiom::DataPropertyDomain { property, domain }) => {
+            Axiom::DataProperty(DataPropertyAxiom::DataPropertyDomain { property: property.clone(), domain: domain.canonical() })
+        }
+        Axiom::Assertion(Assertion::ClassAssertion { class, individual }) => Axiom::Assertion(Assertion::ClassAssertion {
+            class: class.canonical(),
+            individual: individual.clone(),
+        }),
+        _ => axiom.clone(),
+    }
+}
+
+/// Calls `visit` with every entity mentioned in `axiom`, once per occurrence.
+fn visit_axiom_entities(axiom: &Axiom, visit: &mut impl FnMut(Entity)) {
+    match axiom {
+        Axiom::Declaration(entity) => visit(entity.clone()),
+        Axiom::Class(axiom) => match axiom {
+            ClassAxiom::SubClassOf { sub_class, super_class } => {
+                visit_class_expression_entities(sub_class, visit);
+                visit_class_expression_entities(super_class, visit);
+            }
+            ClassAxiom::EquivalentClasses { classes } | ClassAxiom::DisjointClasses { classes } => {
+                classes.iter().for_each(|class| visit_class_expression_entities(class, visit));
+            }
+            ClassAxiom::DisjointUnion { class, disjoint_classes } => {
+                visit(Entity::Class(class.clone()));
+                disjoint_classes.iter().for_each(|class| visit_class_expression_entities(class, visit));
+            }
+        },
+        Axiom::ObjectProperty(axiom) => match axiom {
+            ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } => {
+                visit_property_entities(sub_property, visit);
+                visit_property_entities(super_property, visit);
+            }
+            ObjectPropertyAxiom::EquivalentObjectProperties { properties }
+            | ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                properties.iter().for_each(|property| visit_property_entities(property, visit));
+            }
+            ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 } => {
+                visit_property_entities(prop1, visit);
+                visit_property_entities(prop2, visit);
+            }
+            ObjectPropertyAxiom::ObjectPropertyDomain { property, domain: expression }
+            | ObjectPropertyAxiom::ObjectPropertyRange { property, range: expression } => {
+                visit_property_entities(property, visit);
+                visit_class_expression_entities(expression, visit);
+            }
+            ObjectPropertyAxiom::FunctionalObjectProperty { property }
+            | ObjectPropertyAxiom::InverseFunctionalObjectProperty { property }
+            | ObjectPropertyAxiom::ReflexiveObjectProperty { property }
+            | ObjectPropertyAxiom::IrreflexiveObjectProperty { property }
+            | ObjectPropertyAxiom::SymmetricObjectProperty { property }
+            | ObjectPropertyAxiom::AsymmetricObjectProperty { property }
+            | ObjectPropertyAxiom::TransitiveObjectProperty { property } => visit_property_entities(property, visit),
+        },
+        Axiom::DataProperty(axiom) => match axiom {
+            DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } => {
+                visit(Entity::DataProperty(sub_property.clone()));
+                visit(Entity::DataProperty(super_property.clone()));
+            }
+            DataPropertyAxiom::EquivalentDataProperties { properties } | DataPropertyAxiom::DisjointDataProperties { properties } => {
+                properties.iter().cloned().map(Entity::DataProperty).for_each(&mut *visit);
+            }
+            DataPropertyAxiom::DataPropertyDomain { property, domain } => {
+                visit(Entity::DataProperty(property.clone()));
+                visit_class_expression_entities(domain, visit);
+            }
+            DataPropertyAxiom::DataPropertyRange { property, range } => {
+                visit(Entity::DataProperty(property.clone()));
+                visit_data_range_entities(range, visit);
+            }
+            DataPropertyAxiom::FunctionalDataProperty { property } => {
+                visit(Entity::DataProperty(property.clone()));
+            }
+        },
+        Axiom::Assertion(assertion) => match assertion {
+            Assertion::SameIndividual { individuals } | Assertion::DifferentIndividuals { individuals } => {
+                individuals.iter().for_each(|individual| visit_individual_entity(individual, visit));
+            }
+            Assertion::ClassAssertion { class, individual } => {
+                visit_class_expression_entities(class, visit);
+                visit_individual_entity(individual, visit);
+            }
+            Assertion::ObjectPropertyAssertion { property, source, target }
+            | Assertion::NegativeObjectPropertyAssertion { property, source, target } => {
+                visit_property_entities(property, visit);
+                visit_individual_entity(source, visit);
+                visit_individual_entity(target, visit);
+            }
+            Assertion::DataPropertyAssertion { property, source, target }
+            | Assertion::NegativeDataPropertyAssertion { property, source, target } => {
+                visit(Entity::DataProperty(property.clone()));
+                visit_individual_entity(source, visit);
+                visit(Entity::Datatype(target.datatype.clone()));
+            }
+            Assertion::HasKey { class, object_property_expression, data_property } => {
+                visit(Entity::Class(class.clone()));
+                object_property_expression.iter().for_each(|property| visit_property_entities(property, visit));
+                data_property.iter().cloned().map(Entity::DataProperty).for_each(&mut *visit);
+            }
+        },
+        Axiom::Annotation(AnnotationAxiom::AnnotationAssertion { property, value, .. }) => {
+            visit(Entity::AnnotationProperty(property.clone()));
+            if let AnnotationValue::Literal(literal) = value {
+                visit(Entity::Datatype(literal.datatype.clone()));
+            }
+        }
+    }
+}
+
+fn visit_class_expression_entities(expression: &ClassExpression, visit: &mut impl FnMut(Entity)) {
+    match expression {
+        ClassExpression::Class(class) => {
+            visit(Entity::Class(class.clone()));
+        }
+        ClassExpression::ObjectIntersectionOf(operands) | ClassExpression::ObjectUnionOf(operands) => {
+            operands.iter().for_each(|operand| visit_class_expression_entities(operand, visit));
+        }
+        ClassExpression::ObjectComplementOf(inner) => visit_class_expression_entities(inner, visit),
+        ClassExpression::ObjectOneOf(individuals) => {
+            individuals.iter().for_each(|individual| visit_individual_entity(individual, visit));
+        }
+        ClassExpression::ObjectSomeValuesFrom { property, filler } | ClassExpression::ObjectAllValuesFrom { property, filler } => {
+            visit_property_entities(property, visit);
+            visit_class_expression_entities(filler, visit);
+        }
+        ClassExpression::ObjectHasValue { property, value } => {
+            visit_property_entities(property, visit);
+            visit_individual_entity(value, visit);
+        }
+        ClassExpression::ObjectHasSelf(property) => visit_property_entities(property, visit),
+        ClassExpression::ObjectMinCardinality { property, filler, .. }
+        | ClassExpression::ObjectMaxCardinality { property, filler, .. }
+        | ClassExpression::ObjectExactCardinality { property, filler, .. } => {
+            visit_property_entities(property, visit);
+            if let Some(filler) = filler {
+                visit_class_expression_entities(filler, visit);
+            }
+        }
+        ClassExpression::DataSomeValuesFrom { property, filler } | ClassExpression::DataAllValuesFrom { property, filler } => {
+            visit(Entity::DataProperty(property.clone()));
+            visit_data_range_entities(filler, visit);
+        }
+        ClassExpression::DataHasValue { property, value } => {
+            visit(Entity::DataProperty(property.clone()));
+            visit(Entity::Datatype(value.datatype.clone()));
+        }
+        ClassExpression::DataMinCardinality { property, filler, .. }
+        | ClassExpression::DataMaxCardinality { property, filler, .. }
+        | ClassExpression::DataExactCardinality { property, filler, .. } => {
+            visit(Entity::DataProperty(property.clone()));
+            if let Some(filler) = filler {
+                visit_data_range_entities(filler, visit);
+            }
+        }
+    }
+}
+
+fn visit_property_entities(property: &ObjectPropertyExpression, visit: &mut impl FnMut(Entity)) {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) | ObjectPropertyExpression::InverseObjectProperty(p) => {
+            visit(Entity::ObjectProperty(p.clone()));
+        }
+        ObjectPropertyExpression::ObjectPropertyChain(chain) => {
+            chain.iter().for_each(|property| visit_property_entities(property, visit));
+        }
+    }
+}
+
+fn visit_individual_entity(individual: &Individual, visit: &mut impl FnMut(Entity)) {
+    if let Individual::Named(iri) = individual {
+        visit(Entity::NamedIndividual(iri.clone()));
+    }
+}
+
+fn visit_data_range_entities(range: &DataRange, visit: &mut impl FnMut(Entity)) {
+    match range {
+        DataRange::Datatype(datatype) => {
+            visit(Entity::Datatype(datatype.clone()));
+        }
+        DataRange::DataIntersectionOf(ranges) | DataRange::DataUnionOf(ranges) => {
+            ranges.iter().for_each(|range| visit_data_range_entities(range, visit));
+        }
+        DataRange::DataComplementOf(inner) => visit_data_range_entities(inner, visit),
+        DataRange::DataOneOf(literals) => {
+            literals.iter().map(|literal| Entity::Datatype(literal.datatype.clone())).for_each(&mut *visit);
+        }
+        DataRange::DatatypeRestriction { datatype, .. } => {
+            visit(Entity::Datatype(datatype.clone()));
+        }
+    }
+}
+
+/// A rewrite of the IRIs visited by `map_axiom_iris` and its helpers. The IRIs of
+/// named individuals go through [`individual`](Self::individual), every other IRI
+/// through [`iri`](Self::iri).
+pub(crate) trait IriMap {
+    fn iri(&mut self, iri: &mut IRI);
+
+    fn individual(&mut self, iri: &mut IRI) {
+        self.iri(iri);
+    }
+}
+
+impl<F: FnMut(&mut IRI)> IriMap for F {
+    fn iri(&mut self, iri: &mut IRI) {
+        self(iri);
+    }
+}
+
+/// Puts the IRIs of named individuals in normal form (see [`IRI::normalized`]) and
+/// leaves every other IRI as it is.
+pub(crate) struct NormalizeIndividuals;
+
+impl IriMap for NormalizeIndividuals {
+    fn iri(&mut self, _: &mut IRI) {}
+
+    fn individual(&mut self, iri: &mut IRI) {
+        *iri = iri.normalized();
+    }
+}
+
+pub(crate) fn map_axiom_iris(axiom: &mut Axiom, map: &mut impl IriMap) {
+    match axiom {
+        Axiom::Declaration(entity) => match entity {
+            Entity::Class(Class(iri))
+            | Entity::Datatype(Datatype(iri))
+            | Entity::ObjectProperty(ObjectProperty(iri))
+            | Entity::DataProperty(DataProperty(iri))
+            | Entity::AnnotationProperty(iri) => map.iri(iri),
+            Entity::NamedIndividual(iri) => map.individual(iri),
+        },
+        Axiom::Class(axiom) => match axiom {
+            ClassAxiom::SubClassOf { sub_class, super_class } => {
+                map_class_expression_iris(sub_class, map);
+                map_class_expression_iris(super_class, map);
+            }
+            ClassAxiom::EquivalentClasses { classes } | ClassAxiom::DisjointClasses { classes } => {
+                classes.iter_mut().for_each(|class| map_class_expression_iris(class, map));
+            }
+            ClassAxiom::DisjointUnion { class, disjoint_classes } => {
+                map.iri(&mut class.0);
+                disjoint_classes.iter_mut().for_each(|class| map_class_expression_iris(class, map));
+            }
+        },
+        Axiom::ObjectProperty(axiom) => match axiom {
+            ObjectPropertyAxiom::SubObjectPropertyOf { sub_property: first, super_property: second }
+            | ObjectPropertyAxiom::InverseObjectProperties { prop1: first, prop2: second } => {
+                map_property_iris(first, map);
+                map_property_iris(second, map);
+            }
+            ObjectPropertyAxiom::EquivalentObjectProperties { properties }
+            | ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                properties.iter_mut().for_each(|property| map_property_iris(property, map));
+            }
+            ObjectPropertyAxiom::ObjectPropertyDomain { property, domain: expression }
+            | ObjectPropertyAxiom::ObjectPropertyRange { property, range: expression } => {
+                map_property_iris(property, map);
+                map_class_expression_iris(expression, map);
+            }
+            ObjectPropertyAxiom::FunctionalObjectProperty { property }
+            | ObjectPropertyAxiom::InverseFunctionalObjectProperty { property }
+            | ObjectPropertyAxiom::ReflexiveObjectProperty { property }
+            | ObjectPropertyAxiom::IrreflexiveObjectProperty { property }
+            | ObjectPropertyAxiom::SymmetricObjectProperty { property }
+            | ObjectPropertyAxiom::AsymmetricObjectProperty { property }
+            | ObjectPropertyAxiom::TransitiveObjectProperty { property } => map_property_iris(property, map),
+        },
+        Axiom::DataProperty(axiom) => match axiom {
+            DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } => {
+                map.iri(&mut sub_property.0);
+                map.iri(&mut super_property.0);
+            }
+            DataPropertyAxiom::EquivalentDataProperties { properties } | DataPropertyAxiom::DisjointDataProperties { properties } => {
+                properties.iter_mut().for_each(|property| map.iri(&mut property.0));
+            }
+            DataPropertyAxiom::DataPropertyDomain { property, domain } => {
+                map.iri(&mut property.0);
+                map_class_expression_iris(domain, map);
+            }
+            DataPropertyAxiom::DataPropertyRange { property, range } => {
+                map.iri(&mut property.0);
+                map_data_range_iris(range, map);
+            }
+            DataPropertyAxiom::FunctionalDataProperty { property } => map.iri(&mut property.0),
+        },
+        Axiom::Assertion(assertion) => match assertion {
+            Assertion::SameIndividual { individuals } | Assertion::DifferentIndividuals { individuals } => {
+                individuals.iter_mut().for_each(|individual| map_individual_iri(individual, map));
+            }
+            Assertion::ClassAssertion { class, individual } => {
+                map_class_expression_iris(class, map);
+                map_individual_iri(individual, map);
+            }
+            Assertion::ObjectPropertyAssertion { property, source, target }
+            | Assertion::NegativeObjectPropertyAssertion { property, source, target } => {
+                map_property_iris(property, map);
+                map_individual_iri(source, map);
+                map_individual_iri(target, map);
+            }
+            Assertion::DataPropertyAssertion { property, source, target }
+            | Assertion::NegativeDataPropertyAssertion { property, source, target } => {
+                map.iri(&mut property.0);
+                map_individual_iri(source, map);
+                map.iri(&mut target.datatype.0);
+            }
+            Assertion::HasKey { class, object_property_expression, data_property } => {
+                map.iri(&mut class.0);
+                object_property_expression.iter_mut().for_each(|property| map_property_iris(property, map));
+                data_property.iter_mut().for_each(|property| map.iri(&mut property.0));
+            }
+        },
+        Axiom::Annotation(AnnotationAxiom::AnnotationAssertion { property, subject, value }) => {
+            map.iri(property);
+            map.iri(subject);
+            map_annotation_value_iris(value, map);
+        }
+    }
+}
+
+fn map_annotation_value_iris(value: &mut AnnotationValue, map: &mut impl IriMap) {
+    match value {
+        AnnotationValue::IRI(iri) => map.iri(iri),
+        AnnotationValue::Literal(literal) => map.iri(&mut literal.datatype.0),
+        AnnotationValue::AnonymousIndividual(_) => {}
+    }
+}
+
+pub(crate) fn map_class_expression_iris(expression: &mut ClassExpression, map: &mut impl IriMap) {
+    match expression {
+        ClassExpression::Class(class) => map.iri(&mut class.0),
+        ClassExpression::ObjectIntersectionOf(operands) | ClassExpression::ObjectUnionOf(operands) => {
+            operands.iter_mut().for_each(|operand| map_class_expression_iris(operand, map));
+        }
+        ClassExpression::ObjectComplementOf(inner) => map_class_expression_iris(inner, map),
+        ClassExpression::ObjectOneOf(individuals) => {
+            individuals.iter_mut().for_each(|individual| map_individual_iri(individual, map));
+        }
+        ClassExpression::ObjectSomeValuesFrom { property, filler } | ClassExpression::ObjectAllValuesFrom { property, filler } => {
+            map_property_iris(property, map);
+            map_class_expression_iris(filler, map);
+        }
+        ClassExpression::ObjectHasValue { property, value } => {
+            map_property_iris(property, map);
+            map_individual_iri(value, map);
+        }
+        ClassExpression::ObjectHasSelf(property) => map_property_iris(property, map),
+        ClassExpression::ObjectMinCardinality { property, filler, .. }
+        | ClassExpression::ObjectMaxCardinality { property, filler, .. }
+        | ClassExpression::ObjectExactCardinality { property, filler, .. } => {
+            map_property_iris(property, map);
+            if let Some(filler) = filler {
+                map_class_expression_iris(filler, map);
+            }
+        }
+        ClassExpression::DataSomeValuesFrom { property, filler } | ClassExpression::DataAllValuesFrom { property, filler } => {
+            map.iri(&mut property.0);
+            map_data_range_iris(filler, map);
+        }
+        ClassExpression::DataHasValue { property, value } => {
+            map.iri(&mut property.0);
+            map.iri(&mut value.datatype.0);
+        }
+        ClassExpression::DataMinCardinality { property, filler, .. }
+        | ClassExpression::DataMaxCardinality { property, filler, .. }
+        | ClassExpression::DataExactCardinality { property, filler, .. } => {
+            map.iri(&mut property.0);
+            if let Some(filler) = filler {
+                map_data_range_iris(filler, map);
+            }
+        }
+    }
+}
+
+pub(crate) fn map_property_iris(property: &mut ObjectPropertyExpression, map: &mut impl IriMap) {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) | ObjectPropertyExpression::InverseObjectProperty(p) => map.iri(&mut p.0),
+        ObjectPropertyExpression::ObjectPropertyChain(chain) => {
+            chain.iter_mut().for_each(|property| map_property_iris(property, map));
+        }
+    }
+}
+
+fn map_individual_iri(individual: &mut Individual, map: &mut impl IriMap) {
+    if let Individual::Named(iri) = individual {
+        map.individual(iri);
+    }
+}
+
+fn map_data_range_iris(range: &mut DataRange, map: &mut impl IriMap) {
+    match range {
+        DataRange::Datatype(datatype) => map.iri(&mut datatype.0),
+        DataRange::DataIntersectionOf(ranges) | DataRange::DataUnionOf(ranges) => {
+            ranges.iter_mut().for_each(|range| map_data_range_iris(range, map));
+        }
+        DataRange::DataComplementOf(inner) => map_data_range_iris(inner, map),
+        DataRange::DataOneOf(literals) => literals.iter_mut().for_each(|literal| map.iri(&mut literal.datatype.0)),
+        DataRange::DatatypeRestriction { datatype, restrictions } => {
+            map.iri(&mut datatype.0);
+            for (facet, value) in restrictions {
+                map.iri(facet);
+                map.iri(&mut value.datatype.0);
+            }
+        }
+    }
+}
+
+/// State for Tarjan's strongly connected components algorithm over the told
+/// subclass graph.
+struct Tarjan<'a> {
+    graph: &'a HashMap<&'a Class, Vec<&'a Class>>,
+    index: HashMap<&'a Class, usize>,
+    low_link: HashMap<&'a Class, usize>,
+    stack: Vec<&'a Class>,
+    on_stack: HashSet<&'a Class>,
+    next_index: usize,
+    components: Vec<Vec<&'a Class>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn visit(&mut self, class: &'a Class) {
+        self.index.insert(class, self.next_index);
+        self.low_link.insert(class, self.next_index);
+        self.next_index += 1;
+        self.stack.push(class);
+        self.on_stack.insert(class);
+
+        let graph = self.graph;
+        for &successor in graph.get(class).into_iter().flatten() {
+            if !self.index.contains_key(successor) {
+                self.visit(successor);
+                let low = self.low_link[class].min(self.low_link[successor]);
+                self.low_link.insert(class, low);
+            } else if self.on_stack.contains(successor) {
+                let low = self.low_link[class].min(self.index[successor]);
+                self.low_link.insert(class, low);
+            }
+        }
+
+        if self.low_link[class] == self.index[class] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(member);
+                component.push(member);
+                if member == class {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+impl std::fmt::Display for Ontology {
+    /// Formats the ontology in Functional-Style Syntax (see [`parser::write_ontology`]).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&parser::write_ontology(self))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iri_creation() {
+        let iri = IRI("http://example.com/class".to_string());
+        assert_eq!(iri.0, "http://example.com/class");
+    }
+
+    #[test]
+    fn test_entity_creation() {
+        let class_entity = Entity::Class(Class(IRI("http://example.com/class".to_string())));
+        assert!(matches!(class_entity, Entity::Class(_)));
+
+        let datatype_entity = Entity::Datatype(Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())));
+        assert!(matches!(datatype_entity, Entity::Datatype(_)));
+
+        let object_property_entity = Entity::ObjectProperty(ObjectProperty(IRI("http://example.com/hasPart".to_string())));
+        assert!(matches!(object_property_entity, Entity::ObjectProperty(_)));
+
+        let data_property_entity = Entity::DataProperty(DataProperty(IRI("http://example.com/hasAge".to_string())));
+        assert!(matches!(data_property_entity, Entity::DataProperty(_)));
+
+        let annotation_property_entity = Entity::AnnotationProperty(IRI("http://example.com/comment".to_string()));
+        assert!(matches!(annotation_property_entity, Entity::AnnotationProperty(_)));
+
+        let named_individual_entity = Entity::NamedIndividual(IRI("http://example.com/individual1".to_string()));
+        assert!(matches!(named_individual_entity, Entity::NamedIndividual(_)));
+    }
+
+    #[test]
+    fn test_class_creation() {
+        let class = Class(IRI("http://example.com/MyClass".to_string()));
+        assert_eq!(class.0 .0, "http://example.com/MyClass");
+    }
+
+    #[test]
+    fn test_datatype_creation() {
+        let datatype = Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string()));
+        assert_eq!(datatype.0 .0, "http://www.w3.org/2001/XMLSchema#string");
+    }
+
+    #[test]
+    fn test_object_property_creation() {
+        let object_property = ObjectProperty(IRI("http://example.com/hasPart".to_string()));
+        assert_eq!(object_property.0 .0, "http://example.com/hasPart");
+    }
+
+    #[test]
+    fn test_data_property_creation() {
+        let data_property = DataProperty(IRI("http://example.com/hasAge".to_string()));
+        assert_eq!(data_property.0 .0, "http://example.com/hasAge");
+    }
+
+    #[test]
+    fn test_individual_creation() {
+        let named_individual = Individual::Named(IRI("http://example.com/individual".to_string()));
+        assert!(matches!(named_individual, Individual::Named(_)));
+
+        let anonymous_individual = Individual::Anonymous(NodeID("_:b1".to_string()));
+        assert!(matches!(anonymous_individual, Individual::Anonymous(_)));
+    }
+
+    #[test]
+    fn test_node_id_creation() {
+        let node_id = NodeID("_:b1".to_string());
+        assert_eq!(node_id.0, "_:b1");
+    }
+
+    #[test]
+    fn test_literal_creation() {
+        let literal = Literal {
+            value: "hello".to_string(),
+            datatype: Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())),
+            lang: Some("en".to_string()),
+        };
+        assert_eq!(literal.value, "hello");
+        assert_eq!(literal.lang, Some("en".to_string()));
+    }
+
+    #[test]
+    fn test_subclassof_axiom() {
+        let child_class = Class(IRI("http://example.com/child".to_string()));
+        let parent_class = Class(IRI("http://example.com/parent".to_string()));
+
+        let axiom = ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(child_class.clone()),
+            super_class: ClassExpression::Class(parent_class.clone()),
+        };
+
+        if let ClassAxiom::SubClassOf { sub_class, super_class } = axiom {
+            assert_eq!(sub_class, ClassExpression::Class(child_class));
+            assert_eq!(super_class, ClassExpression::Class(parent_class));
+        } else {
+            panic!("Axiom is not SubClassOf");
+        }
+    }
+
+    #[test]
+    fn test_subobjectpropertyof_axiom() {
+        let sub_prop = ObjectProperty(IRI("http://example.com/subProp".to_string()));
+        let super_prop = ObjectProperty(IRI("http://example.com/superProp".to_string()));
+
+        let axiom = ObjectPropertyAxiom::SubObjectPropertyOf {
+            sub_property: ObjectPropertyExpression::ObjectProperty(sub_prop.clone()),
+            super_property: ObjectPropertyExpression::ObjectProperty(super_prop.clone()),
+        };
+
+        if let ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } = axiom {
+            assert_eq!(sub_property, ObjectPropertyExpression::ObjectProperty(sub_prop));
+            assert_eq!(super_property, ObjectPropertyExpression::ObjectProperty(super_prop));
+        } else {
+            panic!("Axiom is not SubObjectPropertyOf");
+        }
+    }
+
+    #[test]
+    fn test_subdatapropertyof_axiom() {
+        let sub_prop = DataProperty(IRI("http://example.com/subProp".to_string()));
+        let super_prop = DataProperty(IRI("http://example.com/superProp".to_string()));
+
+        let axiom = DataPropertyAxiom::SubDataPropertyOf {
+            sub_property: sub_prop.clone(),
+            super_property: super_prop.clone(),
+        };
+
+        if let DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } = axiom {
+            assert_eq!(sub_property, sub_prop);
+            assert_eq!(super_property, super_prop);
+        } else {
+            panic!("Axiom is not SubDataPropertyOf");
+        }
+    }
+
+    #[test]
+    fn test_classassertion_axiom() {
+        let class = Class(IRI("http://example.com/class".to_string()));
+        let individual = Individual::Named(IRI("http://example.com/individual".to_string()));
+
+        let axiom = Assertion::ClassAssertion {
+            class: ClassExpression::Class(class.clone()),
+            individual: individual.clone(),
         };
 
         if let Assertion::ClassAssertion { class: c, individual: i } = axiom {
@@ -692,6 +2060,109 @@ mod tests {
         assert_eq!(ontology.axioms.len(), 1);
     }
 
+    #[test]
+    fn test_merge_import_records_provenance() {
+        let local = Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(Class(IRI("http://example.com/Student".to_string()))),
+            super_class: ClassExpression::Class(Class(IRI("http://example.com/Person".to_string()))),
+        });
+        let imported_axiom = Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(Class(IRI("http://example.com/Person".to_string()))),
+            super_class: ClassExpression::Class(Class(IRI("http://example.com/Agent".to_string()))),
+        });
+
+        let mut ontology = Ontology {
+            axioms: vec![local.clone()],
+            ..Default::default()
+        };
+        let imported = Ontology {
+            axioms: vec![imported_axiom.clone(), local.clone()],
+            ..Default::default()
+        };
+        let source = IRI("http://example.com/imported".to_string());
+        ontology.merge_import(source.clone(), imported);
+
+        assert_eq!(ontology.axioms, vec![local.clone(), imported_axiom.clone()]);
+        assert_eq!(ontology.axiom_source(0), None);
+        assert_eq!(ontology.axiom_source(1), Some(&source));
+
+        // Removing an axiom shifts the provenance of the ones after it
+        assert_eq!(ontology.remove_axiom(0), local);
+        assert_eq!(ontology.axioms, vec![imported_axiom]);
+        assert_eq!(ontology.axiom_source(0), Some(&source));
+    }
+
+    #[test]
+    fn test_subclass_cycles() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/A>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+  SubClassOf(Class(<http://example.com/D>) Class(<http://example.com/C>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        let cycles = ontology.subclass_cycles();
+        assert_eq!(cycles, vec![vec![
+            Class(IRI("http://example.com/A".to_string())),
+            Class(IRI("http://example.com/B".to_string())),
+        ]]);
+
+        assert!(Ontology::default().subclass_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_inverse_consistency_warnings() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasParent>) ObjectProperty(<http://example.com/hasChild>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Child>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Parent>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Parent>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.inverse_consistency_warnings(), vec![
+            "<http://example.com/hasParent> and <http://example.com/hasChild> are inverses, but the domain of \
+             <http://example.com/hasParent> is Class(<http://example.com/Child>) while the range of \
+             <http://example.com/hasChild> is Class(<http://example.com/Person>)".to_string(),
+        ]);
+
+        // Mirrored declarations, and sides declared on one property only, are fine
+        let input = r#"Ontology(<http://example.com/ontology>
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasParent>) ObjectProperty(<http://example.com/hasChild>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Child>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Child>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Parent>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert!(ontology.inverse_consistency_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_parse_ontology_with_normalizer() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<https://example.com/ontology>
+  Annotation(<https://example.com/seeAlso> <https://example.com/other>)
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<https://example.com/john>))
+)"#;
+        let same_scheme = |iri: &IRI| IRI(iri.0.replacen("https://", "http://", 1));
+        let ontology = OWLParser::parse_ontology_with_normalizer(input, &same_scheme).unwrap();
+        assert_eq!(ontology.axioms[0], ontology.axioms[1]);
+        assert_eq!(ontology.ontology_iri, Some(IRI("http://example.com/ontology".to_string())));
+        assert_eq!(ontology.annotations, vec![Annotation {
+            property: IRI("http://example.com/seeAlso".to_string()),
+            value: AnnotationValue::IRI(IRI("http://example.com/other".to_string())),
+        }]);
+
+        let ontology = OWLParser::parse_ontology_with_normalizer(input, &DefaultNormalizer).unwrap();
+        assert_ne!(ontology.axioms[0], ontology.axioms[1]);
+    }
+
     #[test]
     fn test_parser_iri() {
         use crate::parser::OWLParser;
@@ -710,6 +2181,111 @@ mod tests {
         assert_eq!(prefix, Prefix { name: "ex".to_string(), iri: IRI("http://example.com/".to_string()) });
     }
 
+    #[test]
+    fn test_parser_default_prefix() {
+        use crate::parser::{standard_prefixes, OWLParser, Prefix};
+
+        let prefix = OWLParser::parse_prefix("Prefix(:=<http://example.com/>)").unwrap();
+        assert_eq!(prefix, Prefix { name: String::new(), iri: IRI("http://example.com/".to_string()) });
+
+        let mut prefixes = standard_prefixes();
+        prefixes.insert(prefix.name, prefix.iri.0);
+        let student = Class(OWLParser::expand_abbreviated_iri(":Student", &prefixes).unwrap());
+        assert_eq!(student, Class(IRI("http://example.com/Student".to_string())));
+
+        // Without a default prefix declaration the CURIE cannot be expanded
+        assert!(OWLParser::expand_abbreviated_iri(":Student", &standard_prefixes()).is_err());
+    }
+
+    #[test]
+    fn test_parser_abbreviated_iri_local_part() {
+        use crate::parser::{standard_prefixes, OWLParser};
+
+        let mut prefixes = standard_prefixes();
+        prefixes.insert("ex".to_string(), "http://example.com/".to_string());
+        for local in ["v1.0", "a.b.c", "caf\u{e9}", "x-y_z"] {
+            let iri = OWLParser::expand_abbreviated_iri(&format!("ex:{}", local), &prefixes).unwrap();
+            assert_eq!(iri, IRI(format!("http://example.com/{}", local)));
+        }
+
+        let ontology = OWLParser::parse_ontology(r#"Prefix(ex:=<http://example.com/>)
+Ontology(<http://example.com/ontology>
+  SubClassOf(Class(ex:Release.v1.0) Class(ex:Release))
+)"#).unwrap();
+        let release = ClassExpression::Class(Class(IRI("http://example.com/Release.v1.0".to_string())));
+        assert!(matches!(&ontology.axioms[0], Axiom::Class(ClassAxiom::SubClassOf { sub_class, .. }) if *sub_class == release));
+
+        // A trailing "." is not part of the local name
+        assert!(OWLParser::parse_ontology(r#"Prefix(ex:=<http://example.com/>)
+Ontology(<http://example.com/ontology>
+  SubClassOf(Class(ex:A.) Class(ex:B))
+)"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_ontology_iri_and_version_iri() {
+        use crate::parser::{write_functional_syntax, OWLParser};
+
+        let input = r#"Ontology(<http://ex/o> <http://ex/o/1.0>
+  Import(<http://ex/upper>)
+  SubClassOf(Class(<http://ex/A>) Class(<http://ex/B>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.ontology_iri, Some(IRI("http://ex/o".to_string())));
+        assert_eq!(ontology.version_iri, Some(IRI("http://ex/o/1.0".to_string())));
+        assert_eq!(ontology.direct_imports, vec![IRI("http://ex/upper".to_string())]);
+
+        let mut buffer = Vec::new();
+        write_functional_syntax(&ontology, &mut buffer).unwrap();
+        let reparsed = OWLParser::parse_ontology(&String::from_utf8(buffer).unwrap()).unwrap();
+        assert_eq!(reparsed.ontology_iri, ontology.ontology_iri);
+        assert_eq!(reparsed.version_iri, ontology.version_iri);
+
+        let unversioned = OWLParser::parse_ontology("Ontology(<http://ex/o>)").unwrap();
+        assert_eq!(unversioned.ontology_iri, Some(IRI("http://ex/o".to_string())));
+        assert_eq!(unversioned.version_iri, None);
+        let anonymous = OWLParser::parse_ontology("Ontology()").unwrap();
+        assert_eq!((anonymous.ontology_iri, anonymous.version_iri), (None, None));
+        assert_eq!(Ontology::default().ontology_iri, None);
+    }
+
+    #[test]
+    fn test_parse_ontology_expands_declared_prefixes() {
+        use crate::parser::{write_functional_syntax, OWLParser};
+
+        let input = r#"Prefix(ex:=<http://example.com/school#>)
+Ontology(<http://example.com/ontology>
+  SubClassOf(Class(ex:Student) Class(:Person))
+  ObjectPropertyDomain(ObjectInverseOf(ex:teaches) Class(ex:Student))
+  DataPropertyAssertion(DataProperty(ex:age) NamedIndividual(ex:john) "21"^^xsd:integer)
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        let ex = |name: &str| IRI(format!("http://example.com/school#{}", name));
+        assert_eq!(ontology.axioms[0], Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(Class(ex("Student"))),
+            // The default prefix abbreviates the ontology IRI
+            super_class: ClassExpression::Class(Class(IRI("http://example.com/ontology#Person".to_string()))),
+        }));
+        assert!(matches!(&ontology.axioms[1], Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyDomain {
+            property: ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(iri)), ..
+        }) if *iri == ex("teaches")));
+        assert!(matches!(&ontology.axioms[2], Axiom::Assertion(Assertion::DataPropertyAssertion { property, source, .. })
+            if property.0 == ex("age") && *source == Individual::Named(ex("john"))));
+
+        // Only the declared prefixes are retained, and they are written back out
+        let prefixes = ontology.prefixes.clone().unwrap();
+        assert_eq!(prefixes.len(), 1);
+        assert_eq!(prefixes["ex"], "http://example.com/school#");
+        let mut buffer = Vec::new();
+        write_functional_syntax(&ontology, &mut buffer).unwrap();
+        let written = String::from_utf8(buffer).unwrap();
+        assert!(written.starts_with("Prefix(ex:=<http://example.com/school#>)\nOntology(<http://example.com/ontology>\n"));
+        assert_eq!(OWLParser::parse_ontology(&written).unwrap().axioms, ontology.axioms);
+
+        // An undeclared prefix is an error
+        assert!(OWLParser::parse_ontology("Ontology(\n  SubClassOf(Class(foo:A) Class(foo:B))\n)").is_err());
+    }
+
     #[test]
     fn test_parser_entity() {
         use crate::parser::OWLParser;
@@ -749,7 +2325,56 @@ mod tests {
 
         let input_lang = r#""hello"@en"#;
         let literal_lang = OWLParser::parse_literal(input_lang).unwrap();
-        assert_eq!(literal_lang, Literal { value: "hello".to_string(), datatype: Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())), lang: Some("en".to_string()) });
+        assert_eq!(literal_lang, Literal { value: "hello".to_string(), datatype: Datatype(IRI("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string())), lang: Some("en".to_string()) });
+    }
+
+    #[test]
+    fn test_parser_language_tagged_literal_is_lang_string() {
+        use crate::parser::OWLParser;
+
+        let literal = OWLParser::parse_literal(r#""hi"@en"#).unwrap();
+        assert_eq!(literal.datatype, Datatype(IRI("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string())));
+        assert_eq!(literal.lang, Some("en".to_string()));
+
+        // Untagged literals without a datatype remain xsd:string
+        let plain = OWLParser::parse_literal(r#""hi""#).unwrap();
+        assert_eq!(plain.datatype, Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())));
+    }
+
+    #[test]
+    fn test_parser_data_property_assertion_literal_forms() {
+        use crate::parser::{standard_prefixes, OWLParser};
+
+        let target = |input: &str| match OWLParser::parse_assertion(input).unwrap() {
+            Assertion::DataPropertyAssertion { target, .. } => target,
+            other => panic!("Expected a data property assertion, got {:?}", other),
+        };
+        let xsd_integer = Datatype(IRI("http://www.w3.org/2001/XMLSchema#integer".to_string()));
+
+        let abbreviated = target(r#"DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^xsd:integer)"#);
+        assert_eq!(abbreviated, Literal { value: "22".to_string(), datatype: xsd_integer.clone(), lang: None });
+
+        let full = target(r#"DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)"#);
+        assert_eq!(full, abbreviated);
+
+        let tagged = target(r#"DataPropertyAssertion(DataProperty(<http://example.com/name>) NamedIndividual(<http://example.com/john>) "text"@en)"#);
+        assert_eq!(tagged.value, "text");
+        assert_eq!(tagged.lang, Some("en".to_string()));
+
+        let plain = target(r#"DataPropertyAssertion(DataProperty(<http://example.com/name>) NamedIndividual(<http://example.com/john>) "text")"#);
+        assert_eq!(plain, Literal { value: "text".to_string(), datatype: Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())), lang: None });
+
+        // Custom prefixes are threaded through to the literal datatype
+        let mut prefixes = standard_prefixes();
+        prefixes.insert("ex".to_string(), "http://example.com/types#".to_string());
+        let input = r#"DataPropertyAssertion(DataProperty(<http://example.com/score>) NamedIndividual(<http://example.com/john>) "5"^^ex:score)"#;
+        match OWLParser::parse_assertion_with_prefixes(input, &prefixes).unwrap() {
+            Assertion::DataPropertyAssertion { target, .. } => {
+                assert_eq!(target.datatype, Datatype(IRI("http://example.com/types#score".to_string())));
+            }
+            other => panic!("Expected a data property assertion, got {:?}", other),
+        }
+        assert!(OWLParser::parse_assertion(input).is_err());
     }
 
     #[test]
@@ -823,6 +2448,151 @@ mod tests {
         assert_eq!(ontology.axioms.len(), 1);
     }
 
+    #[test]
+    fn test_parser_ontology_imports() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  Import(<http://example.com/upper>)
+  # imports come before the axioms
+  Import(<http://example.com/units>)
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.direct_imports.len(), 2);
+        assert_eq!(ontology.direct_imports[0], IRI("http://example.com/upper".to_string()));
+        assert_eq!(ontology.direct_imports[1], IRI("http://example.com/units".to_string()));
+        assert_eq!(ontology.axioms.len(), 1);
+    }
+
+    #[test]
+    fn test_parser_ontology_abbreviated_imports() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Prefix(ex:=<http://example.com/>)
+Ontology(<http://example.com/ontology>
+  Import(ex:upper)
+  Import(<http://example.com/units>)
+  SubClassOf(Class(ex:Student) Class(ex:Person))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(
+            ontology.direct_imports,
+            vec![IRI("http://example.com/upper".to_string()), IRI("http://example.com/units".to_string())]
+        );
+        assert_eq!(ontology.axioms.len(), 1);
+
+        let misplaced = "Ontology(<http://example.com/ontology> SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>)) Import(<http://example.com/upper>))";
+        assert!(OWLParser::parse_ontology(misplaced).is_err());
+    }
+
+    #[test]
+    fn test_parser_declarations() {
+        use crate::parser::{OWLParser, write_functional_syntax};
+
+        let axiom = OWLParser::parse_axiom("Declaration(Class(<http://example.com/A>))").unwrap();
+        assert_eq!(axiom, Axiom::Declaration(Entity::Class(Class(IRI("http://example.com/A".to_string())))));
+
+        let input = r#"Prefix(ex:=<http://example.com/>)
+Ontology(<http://example.com/ontology>
+  Declaration(Class(ex:Orphan))
+  Declaration(ObjectProperty(ex:hasPart))
+  SubClassOf(Class(ex:Car) ObjectSomeValuesFrom(ObjectProperty(ex:hasPart) Class(ex:Wheel)))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        let signature = ontology.signature();
+        let iri = |name: &str| IRI(format!("http://example.com/{}", name));
+        assert!(signature.contains(&Entity::Class(Class(iri("Orphan")))));
+        assert!(signature.contains(&Entity::Class(Class(iri("Wheel")))));
+        assert!(signature.contains(&Entity::ObjectProperty(ObjectProperty(iri("hasPart")))));
+
+        let mut written = Vec::new();
+        write_functional_syntax(&ontology, &mut written).unwrap();
+        let reparsed = OWLParser::parse_ontology(&String::from_utf8(written).unwrap()).unwrap();
+        assert_eq!(reparsed.axioms, ontology.axioms);
+    }
+
+    #[test]
+    fn test_parser_ontology_annotations() {
+        use crate::parser::{OWLParser, write_ontology};
+
+        let input = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.com/ontology>
+  Import(<http://example.com/imported>)
+  Annotation(dc:title "My Ontology")
+  Annotation(rdfs:seeAlso <http://example.com/about>)
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.annotations.len(), 2);
+        assert_eq!(ontology.axioms.len(), 1);
+        let title = ontology.annotation(&IRI("http://purl.org/dc/elements/1.1/title".to_string()));
+        assert!(matches!(title, Some(AnnotationValue::Literal(literal)) if literal.value == "My Ontology"));
+        assert_eq!(ontology.annotation(&IRI("http://purl.org/dc/elements/1.1/creator".to_string())), None);
+
+        let reparsed = OWLParser::parse_ontology(&write_ontology(&ontology)).unwrap();
+        assert_eq!(reparsed.annotations, ontology.annotations);
+    }
+
+    #[test]
+    fn test_parser_ontology_with_spans() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  # a comment between axioms
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let axioms = OWLParser::parse_ontology_with_spans(input).unwrap();
+        assert_eq!(axioms.len(), 2);
+
+        let (axiom, span) = &axioms[1];
+        assert!(matches!(axiom, Axiom::Assertion(Assertion::ClassAssertion { .. })));
+        assert_eq!(
+            &input[span.clone()],
+            "ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))"
+        );
+    }
+
+    #[test]
+    fn test_write_functional_syntax_round_trip() {
+        use crate::parser::{write_functional_syntax, OWLParser};
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  Import(<http://example.com/upper>)
+  SubClassOf(Class(<http://example.com/Student>) ObjectIntersectionOf(Class(<http://example.com/Person>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/enrolledIn>) Class(<http://example.com/Course>))))
+  EquivalentClasses(Class(<http://example.com/Adult>) ObjectComplementOf(Class(<http://example.com/Child>)))
+  DisjointClasses(Class(<http://example.com/Student>) Class(<http://example.com/Employee>))
+  SubClassOf(Class(<http://example.com/Parent>) ObjectMinCardinality(1 ObjectProperty(<http://example.com/hasChild>)))
+  SubClassOf(Class(<http://example.com/Couple>) ObjectExactCardinality(2 ObjectProperty(<http://example.com/hasMember>) Class(<http://example.com/Person>)))
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/ancestorOf>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Employee>))
+  SubObjectPropertyOf(ObjectInverseOf(ObjectProperty(<http://example.com/hasParent>)) ObjectProperty(<http://example.com/hasChild>))
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/worksFor>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/acme>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  DataPropertyAssertion(DataProperty(<http://example.com/name>) NamedIndividual(<http://example.com/john>) "John"@en)
+  HasKey(Class(<http://example.com/Person>) (ObjectProperty(<http://example.com/bornIn>)) (DataProperty(<http://example.com/name>)))
+  HasKey(Class(<http://example.com/Product>) () (DataProperty(<http://example.com/hasSKU>)))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        let iri = |name: &str| IRI(format!("http://example.com/{}", name));
+        assert_eq!(ontology.axioms[ontology.axioms.len() - 1], Axiom::Assertion(Assertion::HasKey {
+            class: Class(iri("Product")),
+            object_property_expression: vec![],
+            data_property: vec![DataProperty(iri("hasSKU"))],
+        }));
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_functional_syntax(&ontology, &mut buffer).unwrap();
+        let written = String::from_utf8(buffer).unwrap();
+
+        let reparsed = OWLParser::parse_ontology(&written).unwrap();
+        assert_eq!(reparsed.direct_imports, ontology.direct_imports);
+        assert_eq!(reparsed.axioms, ontology.axioms);
+    }
+
     #[test]
     fn test_parser_data_property() {
         use crate::parser::OWLParser;
@@ -854,6 +2624,468 @@ mod tests {
 )"#;
         let ontology = OWLParser::parse_ontology(input).unwrap();
         assert_eq!(ontology.axioms.len(), 9);
+
+        let works_for = ObjectProperty(IRI("http://example.com/worksFor".to_string()));
+        let has_age = DataProperty(IRI("http://example.com/hasAge".to_string()));
+
+        let domains = ontology.property_domains();
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[&works_for], vec![ClassExpression::Class(Class(IRI("http://example.com/Employee".to_string())))]);
+        let ranges = ontology.property_ranges();
+        assert_eq!(ranges[&works_for], vec![ClassExpression::Class(Class(IRI("http://example.com/Organization".to_string())))]);
+
+        let data_domains = ontology.data_property_domains();
+        assert_eq!(data_domains[&has_age], vec![ClassExpression::Class(Class(IRI("http://example.com/Person".to_string())))]);
+        let data_ranges = ontology.data_property_ranges();
+        assert_eq!(data_ranges[&has_age], vec![DataRange::Datatype(Datatype(IRI("http://www.w3.org/2001/XMLSchema#integer".to_string())))]);
+    }
+
+    #[test]
+    fn test_split_tbox_abox() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.com/ontology> <http://example.com/ontology/1.0>
+  Annotation(dc:title "Staff")
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Employee>) Class(<http://example.com/Person>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Employee>))
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ClassAssertion(Class(<http://example.com/Employee>) NamedIndividual(<http://example.com/mary>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/worksFor>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/acme>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)
+)"#;
+        let mut ontology = OWLParser::parse_ontology(input).unwrap();
+        ontology.direct_imports.push(IRI("http://example.com/upper".to_string()));
+        let (tbox, abox) = ontology.split_tbox_abox();
+
+        assert_eq!(tbox.axioms, ontology.axioms[..4].to_vec());
+        assert_eq!(abox.axioms, ontology.axioms[4..].to_vec());
+        assert!(tbox.axioms.iter().any(|axiom| matches!(axiom, Axiom::Class(ClassAxiom::SubClassOf { .. }))));
+        assert!(abox.axioms.iter().all(|axiom| matches!(axiom, Axiom::Assertion(_))));
+        for part in [&tbox, &abox] {
+            assert_eq!(part.ontology_iri, ontology.ontology_iri);
+            assert_eq!(part.version_iri, ontology.version_iri);
+            assert_eq!(part.direct_imports, ontology.direct_imports);
+            assert_eq!(part.annotations, ontology.annotations);
+            assert_eq!(part.prefixes, ontology.prefixes);
+        }
+    }
+
+    #[test]
+    fn test_property_usage() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  Declaration(ObjectProperty(<http://example.com/hasParent>))
+  Declaration(DataProperty(<http://example.com/hasName>))
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Person>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Organization>))
+  DataPropertyDomain(DataProperty(<http://example.com/hasAge>) Class(<http://example.com/Person>))
+  FunctionalDataProperty(DataProperty(<http://example.com/hasAge>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/mary>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/worksFor>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/company>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  SubClassOf(
+    Class(<http://example.com/Person>)
+    ObjectIntersectionOf(
+      ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>))
+      ObjectMaxCardinality(1 ObjectInverseOf(ObjectProperty(<http://example.com/hasParent>)) Class(<http://example.com/Person>))
+    )
+  )
+)"#;
+        let usage = OWLParser::parse_ontology(input).unwrap().property_usage();
+        let count = |name: &str| usage.get(&IRI(format!("http://example.com/{}", name))).copied();
+
+        assert_eq!(count("hasParent"), Some(5));
+        assert_eq!(count("worksFor"), Some(3));
+        assert_eq!(count("hasAge"), Some(3));
+        assert_eq!(count("hasName"), None);
+        assert_eq!(usage.len(), 3);
+    }
+
+    #[test]
+    fn test_property_domains_through_inverse() {
+        let has_parent = ObjectProperty(IRI("http://example.com/hasParent".to_string()));
+        let person = ClassExpression::Class(Class(IRI("http://example.com/Person".to_string())));
+        let ontology = Ontology {
+            axioms: vec![Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyRange {
+                property: ObjectPropertyExpression::InverseObjectProperty(has_parent.clone()),
+                range: person.clone(),
+            })],
+            ..Default::default()
+        };
+
+        assert_eq!(ontology.property_domains()[&has_parent], vec![person]);
+        assert!(ontology.property_ranges().is_empty());
+    }
+
+    #[test]
+    fn test_parse_object_inverse_of_forms() {
+        use crate::parser::OWLParser;
+
+        let expected = ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(IRI("http://example.com/hasChild".to_string())));
+        assert_eq!(
+            OWLParser::parse_object_property_expression("ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>))").unwrap(),
+            expected
+        );
+        assert_eq!(
+            OWLParser::parse_object_property_expression("ObjectInverseOf(<http://example.com/hasChild>)").unwrap(),
+            expected
+        );
+        assert!(OWLParser::parse_object_property_expression("ObjectInverseOf(Class(<http://example.com/Person>))").is_err());
+    }
+
+    #[test]
+    fn test_object_property_characteristics() {
+        use crate::parser::OWLParser;
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/relatedTo>))
+  SymmetricObjectProperty(ObjectProperty(<http://example.com/relatedTo>))
+  FunctionalObjectProperty(ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>)))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasSon>) ObjectProperty(<http://example.com/hasChild>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        let characteristics = ontology.object_property_characteristics();
+
+        let related_to = ObjectProperty(IRI("http://example.com/relatedTo".to_string()));
+        assert_eq!(characteristics[&related_to], PropertyCharacteristics {
+            symmetric: true,
+            transitive: true,
+            ..Default::default()
+        });
+
+        let has_child = ObjectProperty(IRI("http://example.com/hasChild".to_string()));
+        assert_eq!(characteristics[&has_child], PropertyCharacteristics {
+            inverse_functional: true,
+            ..Default::default()
+        });
+        assert_eq!(characteristics.len(), 2);
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_axiom_order() {
+        let sub_class_of = |sub: &str, sup: &str| Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(Class(IRI(format!("http://example.com/{}", sub)))),
+            super_class: ClassExpression::Class(Class(IRI(format!("http://example.com/{}", sup)))),
+        });
+        let forward = Ontology {
+            direct_imports: vec![IRI("http://example.com/a".to_string()), IRI("http://example.com/b".to_string())],
+            axioms: vec![sub_class_of("A", "B"), sub_class_of("B", "C"), sub_class_of("C", "D")],
+            ..Default::default()
+        };
+        let mut reordered = forward.clone();
+        reordered.direct_imports.reverse();
+        reordered.axioms = vec![sub_class_of("C", "D"), sub_class_of("A", "B"), sub_class_of("B", "C")];
+
+        assert_eq!(forward.canonical_hash(), reordered.canonical_hash());
+
+        let mut changed = forward.clone();
+        changed.axioms[0] = sub_class_of("A", "C");
+        assert_ne!(forward.canonical_hash(), changed.canonical_hash());
+    }
+
+    #[test]
+    fn test_label_of() {
+        let person = IRI("http://example.com/Person".to_string());
+        let label = |value: &str, lang: &str| Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+            property: IRI("http://www.w3.org/2000/01/rdf-schema#label".to_string()),
+            subject: person.clone(),
+            value: AnnotationValue::Literal(Literal {
+                value: value.to_string(),
+                datatype: Datatype(IRI("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string())),
+                lang: Some(lang.to_string()),
+            }),
+        });
+        let ontology = Ontology {
+            axioms: vec![label("Personne", "fr"), label("Person", "en")],
+            ..Default::default()
+        };
+
+        assert_eq!(ontology.label_of(&person, Some("en")), Some("Person".to_string()));
+        assert_eq!(ontology.label_of(&person, Some("EN")), Some("Person".to_string()));
+        assert_eq!(ontology.label_of(&person, None), Some("Personne".to_string()));
+        assert_eq!(ontology.label_of(&person, Some("de")), None);
+        assert_eq!(ontology.label_of(&IRI("http://example.com/Student".to_string()), None), None);
+    }
+
+    #[test]
+    fn test_class_expression_ordering_is_deterministic() {
+        let class = |name: &str| ClassExpression::Class(Class(IRI(format!("http://example.com/{}", name))));
+        let r = ObjectPropertyExpression::ObjectProperty(ObjectProperty(IRI("http://example.com/R".to_string())));
+        let expressions = vec![
+            ClassExpression::some(r.clone(), class("C")),
+            ClassExpression::complement(class("A")),
+            class("B"),
+            ClassExpression::union([class("B"), class("A")]),
+            class("A"),
+            ClassExpression::intersection([class("A"), class("B")]),
+            ClassExpression::some(r.clone(), class("A")),
+        ];
+
+        let mut forward = expressions.clone();
+        forward.sort();
+        let mut backward: Vec<ClassExpression> = expressions.into_iter().rev().collect();
+        backward.sort();
+        assert_eq!(forward, backward);
+
+        // Variants are ordered by declaration, then structurally
+        assert_eq!(forward, vec![
+            class("A"),
+            class("B"),
+            ClassExpression::intersection([class("A"), class("B")]),
+            ClassExpression::union([class("B"), class("A")]),
+            ClassExpression::complement(class("A")),
+            ClassExpression::some(r.clone(), class("A")),
+            ClassExpression::some(r, class("C")),
+        ]);
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_operand_order() {
+        let class = |name: &str| ClassExpression::Class(Class(IRI(format!("http://example.com/{}", name))));
+        let equivalent = |classes: Vec<ClassExpression>| Ontology {
+            axioms: vec![Axiom::Class(ClassAxiom::EquivalentClasses { classes })],
+            ..Default::default()
+        };
+
+        let first = equivalent(vec![class("A"), ClassExpression::intersection([class("B"), class("C")])]);
+        let second = equivalent(vec![ClassExpression::intersection([class("C"), class("B")]), class("A")]);
+        assert_eq!(first.canonical_hash(), second.canonical_hash());
+    }
+
+    #[test]
+    fn test_deprecated_entities() {
+        let old_student = IRI("http://example.com/OldStudent".to_string());
+        let boolean = Datatype(IRI("http://www.w3.org/2001/XMLSchema#boolean".to_string()));
+        let deprecated = |subject: &IRI, value: &str| Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+            property: IRI("http://www.w3.org/2002/07/owl#deprecated".to_string()),
+            subject: subject.clone(),
+            value: AnnotationValue::Literal(Literal { value: value.to_string(), datatype: boolean.clone(), lang: None }),
+        });
+        let person = IRI("http://example.com/Person".to_string());
+        let ontology = Ontology {
+            axioms: vec![
+                Axiom::Class(ClassAxiom::SubClassOf {
+                    sub_class: ClassExpression::Class(Class(old_student.clone())),
+                    super_class: ClassExpression::Class(Class(person.clone())),
+                }),
+                deprecated(&old_student, "true"),
+                deprecated(&person, "false"),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(ontology.deprecated_entities(), vec![Entity::Class(Class(old_student))]);
+    }
+
+    #[test]
+    fn test_puns() {
+        let input = r#"Ontology(<http://example.com/ontology>
+            SubClassOf(Class(<http://example.com/Eagle>) Class(<http://example.com/Bird>))
+            ClassAssertion(Class(<http://example.com/Species>) NamedIndividual(<http://example.com/Eagle>))
+            ObjectPropertyAssertion(ObjectProperty(<http://example.com/eats>) NamedIndividual(<http://example.com/Eagle>) NamedIndividual(<http://example.com/Fish>))
+        )"#;
+        let ontology = parser::OWLParser::parse_ontology(input).unwrap();
+
+        assert_eq!(ontology.puns(), vec![IRI("http://example.com/Eagle".to_string())]);
+        assert!(Ontology::default().puns().is_empty());
+    }
+
+    #[test]
+    fn test_iri_normalization() {
+        let normalize = |iri: &str| IRI(iri.to_string()).normalized().0;
+
+        assert_eq!(normalize("HTTP://Example.COM/%7Ejohn"), "http://example.com/~john");
+        assert_eq!(normalize("http://example.com/a%2fb%3F"), "http://example.com/a%2Fb%3F");
+        assert_eq!(normalize("http://User@EXAMPLE.com:8080/Path"), "http://User@example.com:8080/Path");
+        assert_eq!(normalize("urn:ISBN:%30%31"), "urn:ISBN:01");
+        assert_eq!(normalize("http://example.com/100%"), "http://example.com/100%");
+        assert_eq!(
+            Individual::Named(IRI("http://EXAMPLE.com/%41".to_string())).normalized(),
+            Individual::Named(IRI("http://example.com/A".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parser_custom_datatype_range() {
+        use crate::parser::OWLParser;
+
+        let axiom = OWLParser::parse_data_property_axiom(
+            "DataPropertyRange(DataProperty(<http://example.com/hasSKU>) Datatype(<http://example.com/datatypes#SKU>))",
+        ).unwrap();
+        assert_eq!(axiom, DataPropertyAxiom::DataPropertyRange {
+            property: DataProperty(IRI("http://example.com/hasSKU".to_string())),
+            range: DataRange::Datatype(Datatype(IRI("http://example.com/datatypes#SKU".to_string()))),
+        });
+    }
+
+    #[test]
+    fn test_parser_annotation_assertion_round_trip() {
+        use crate::parser::{write_functional_syntax, OWLParser};
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  AnnotationAssertion(rdfs:label <http://example.com/Student> "Étudiant"@fr-CA)
+  AnnotationAssertion(AnnotationProperty(<http://example.com/seeAlso>) <http://example.com/Student> _:b1)
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.axioms[1], Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+            property: IRI("http://www.w3.org/2000/01/rdf-schema#label".to_string()),
+            subject: IRI("http://example.com/Student".to_string()),
+            value: AnnotationValue::Literal(Literal {
+                value: "Étudiant".to_string(),
+                datatype: Datatype(IRI("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string())),
+                lang: Some("fr-CA".to_string()),
+            }),
+        }));
+        assert!(matches!(&ontology.axioms[2], Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+            value: AnnotationValue::AnonymousIndividual(NodeID(id)), ..
+        }) if id == "_:b1"));
+
+        let mut buffer = Vec::new();
+        write_functional_syntax(&ontology, &mut buffer).unwrap();
+        let reparsed = OWLParser::parse_ontology(&String::from_utf8(buffer).unwrap()).unwrap();
+        assert_eq!(reparsed.axioms, ontology.axioms);
+
+        // Annotations carry no logical meaning
+        assert!(crate::api::Reasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_parse_ontology_with_cache() {
+        use crate::parser::{AxiomParseCache, OWLParser};
+
+        let original = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let mut cache = AxiomParseCache::new();
+        let first = OWLParser::parse_ontology_with_cache(original, &mut cache).unwrap();
+        assert_eq!(first.axioms, OWLParser::parse_ontology(original).unwrap().axioms);
+        assert_eq!((cache.hits(), cache.misses()), (0, 3));
+
+        // Only the edited axiom is parsed again
+        let edited = original.replace("NamedIndividual(<http://example.com/john>)", "NamedIndividual(<http://example.com/mary>)");
+        let second = OWLParser::parse_ontology_with_cache(&edited, &mut cache).unwrap();
+        assert_eq!(second.axioms, OWLParser::parse_ontology(&edited).unwrap().axioms);
+        assert_eq!((cache.hits(), cache.misses()), (2, 4));
+        assert_eq!(cache.len(), 4);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[test]
+    fn test_parser_data_one_of_range() {
+        use crate::parser::OWLParser;
+
+        let axiom = OWLParser::parse_data_property_axiom(
+            r#"DataPropertyRange(DataProperty(<http://example.com/hasSize>) DataOneOf("S" "1"^^xsd:integer))"#,
+        ).unwrap();
+        let xsd = |name: &str| Datatype(IRI(format!("http://www.w3.org/2001/XMLSchema#{}", name)));
+        assert_eq!(axiom, DataPropertyAxiom::DataPropertyRange {
+            property: DataProperty(IRI("http://example.com/hasSize".to_string())),
+            range: DataRange::DataOneOf(vec![
+                Literal { value: "S".to_string(), datatype: xsd("string"), lang: None },
+                Literal { value: "1".to_string(), datatype: xsd("integer"), lang: None },
+            ]),
+        });
+    }
+
+    #[test]
+    fn test_parser_datatype_restriction() {
+        use crate::parser::OWLParser;
+
+        let xsd = |name: &str| Datatype(IRI(format!("http://www.w3.org/2001/XMLSchema#{}", name)));
+        let integer = |value: &str| Literal { value: value.to_string(), datatype: xsd("integer"), lang: None };
+        let adult_age = DataRange::DatatypeRestriction {
+            datatype: xsd("integer"),
+            restrictions: vec![(IRI("http://www.w3.org/2001/XMLSchema#minInclusive".to_string()), integer("18"))],
+        };
+        assert_eq!(OWLParser::parse_data_range(r#"DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer)"#).unwrap(), adult_age);
+        assert_eq!(crate::datatypes::literal_in_range(&integer("21"), &adult_age), crate::datatypes::Membership::Member);
+        assert_eq!(crate::datatypes::literal_in_range(&integer("17"), &adult_age), crate::datatypes::Membership::NonMember);
+
+        // Full IRIs, several facets and the boolean combinations
+        let range = OWLParser::parse_data_range(r#"DataUnionOf(
+            DatatypeRestriction(Datatype(<http://www.w3.org/2001/XMLSchema#integer>)
+                <http://www.w3.org/2001/XMLSchema#minInclusive> "0"^^xsd:integer
+                xsd:maxExclusive "10"^^xsd:integer)
+            DataIntersectionOf(Datatype(xsd:string) DataComplementOf(DataOneOf("none"))))"#).unwrap();
+        assert_eq!(range, DataRange::DataUnionOf(vec![
+            DataRange::DatatypeRestriction {
+                datatype: xsd("integer"),
+                restrictions: vec![
+                    (IRI("http://www.w3.org/2001/XMLSchema#minInclusive".to_string()), integer("0")),
+                    (IRI("http://www.w3.org/2001/XMLSchema#maxExclusive".to_string()), integer("10")),
+                ],
+            },
+            DataRange::DataIntersectionOf(vec![
+                DataRange::Datatype(xsd("string")),
+                DataRange::DataComplementOf(Box::new(DataRange::DataOneOf(vec![
+                    Literal { value: "none".to_string(), datatype: xsd("string"), lang: None },
+                ]))),
+            ]),
+        ]));
+
+        // A facet without a value is an error
+        assert!(OWLParser::parse_data_range("DatatypeRestriction(xsd:integer xsd:minInclusive)").is_err());
+    }
+
+    #[test]
+    fn test_parser_data_class_expressions() {
+        use crate::parser::{OWLParser, write_functional_syntax};
+
+        let xsd = |name: &str| Datatype(IRI(format!("http://www.w3.org/2001/XMLSchema#{}", name)));
+        let has_age = DataProperty(IRI("http://example.com/hasAge".to_string()));
+        let adult = OWLParser::parse_class_expression(
+            r#"DataSomeValuesFrom(DataProperty(<http://example.com/hasAge>) DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer))"#,
+        ).unwrap();
+        assert_eq!(adult, ClassExpression::DataSomeValuesFrom {
+            property: has_age.clone(),
+            filler: DataRange::DatatypeRestriction {
+                datatype: xsd("integer"),
+                restrictions: vec![(
+                    IRI("http://www.w3.org/2001/XMLSchema#minInclusive".to_string()),
+                    Literal { value: "18".to_string(), datatype: xsd("integer"), lang: None },
+                )],
+            },
+        });
+
+        assert_eq!(
+            OWLParser::parse_class_expression(r#"DataHasValue(DataProperty(<http://example.com/hasAge>) "42"^^xsd:integer)"#).unwrap(),
+            ClassExpression::DataHasValue {
+                property: has_age.clone(),
+                value: Literal { value: "42".to_string(), datatype: xsd("integer"), lang: None },
+            }
+        );
+        assert_eq!(
+            OWLParser::parse_class_expression("DataMaxCardinality(1 DataProperty(<http://example.com/hasAge>))").unwrap(),
+            ClassExpression::DataMaxCardinality { max: 1, property: has_age.clone(), filler: None }
+        );
+
+        // Every form survives a round trip through the writer, nested in object expressions too
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Adult>) DataSomeValuesFrom(DataProperty(<http://example.com/hasAge>) DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer)))
+  SubClassOf(Class(<http://example.com/Person>) DataAllValuesFrom(DataProperty(<http://example.com/hasAge>) Datatype(xsd:nonNegativeInteger)))
+  SubClassOf(Class(<http://example.com/Person>) DataExactCardinality(1 DataProperty(<http://example.com/hasAge>) Datatype(xsd:integer)))
+  SubClassOf(Class(<http://example.com/Person>) DataMinCardinality(1 DataProperty(<http://example.com/hasName>)))
+  ClassAssertion(ObjectIntersectionOf(Class(<http://example.com/Person>) DataHasValue(DataProperty(<http://example.com/hasName>) "Ann")) NamedIndividual(<http://example.com/ann>))
+)"#;
+        let ontology = OWLParser::parse_ontology(input).unwrap();
+        assert_eq!(ontology.axioms.len(), 5);
+        assert!(ontology.signature().contains(&Entity::DataProperty(DataProperty(IRI("http://example.com/hasName".to_string())))));
+        let mut written = Vec::new();
+        write_functional_syntax(&ontology, &mut written).unwrap();
+        assert_eq!(OWLParser::parse_ontology(&String::from_utf8(written).unwrap()).unwrap().axioms, ontology.axioms);
     }
 
     #[test]
@@ -6,9 +6,35 @@
 use crate::{
     Axiom, ClassAxiom, ObjectPropertyAxiom, DataPropertyAxiom, 
     Assertion, ClassExpression, ObjectPropertyExpression,
-    Ontology
+    Ontology, Datatype
 };
 
+/// The datatypes of the OWL 2 datatype map that OWL 2 EL admits (OWL 2 Profiles,
+/// section 2.2.1). The remaining standard datatypes, such as `xsd:double`,
+/// `xsd:boolean` or `xsd:int`, are excluded because they could make reasoning in
+/// EL non-polynomial.
+const EL_DATATYPES: &[&str] = &[
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral",
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral",
+    "http://www.w3.org/2000/01/rdf-schema#Literal",
+    "http://www.w3.org/2002/07/owl#real",
+    "http://www.w3.org/2002/07/owl#rational",
+    "http://www.w3.org/2001/XMLSchema#decimal",
+    "http://www.w3.org/2001/XMLSchema#integer",
+    "http://www.w3.org/2001/XMLSchema#nonNegativeInteger",
+    "http://www.w3.org/2001/XMLSchema#string",
+    "http://www.w3.org/2001/XMLSchema#normalizedString",
+    "http://www.w3.org/2001/XMLSchema#token",
+    "http://www.w3.org/2001/XMLSchema#Name",
+    "http://www.w3.org/2001/XMLSchema#NCName",
+    "http://www.w3.org/2001/XMLSchema#NMTOKEN",
+    "http://www.w3.org/2001/XMLSchema#hexBinary",
+    "http://www.w3.org/2001/XMLSchema#base64Binary",
+    "http://www.w3.org/2001/XMLSchema#anyURI",
+    "http://www.w3.org/2001/XMLSchema#dateTime",
+    "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
+];
+
 /// Represents the OWL 2 profiles
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OwlProfile {
@@ -29,7 +55,7 @@ pub struct ProfileCheckResult {
     pub profile: OwlProfile,
     /// Whether the ontology conforms to the profile
     pub conforms: bool,
-    /// Reasons why the ontology doesn't conform (if it doesn't)
+    /// Reasons why the ontology doesn't conform (if it doesn't), sorted
     pub violations: Vec<String>,
 }
 
@@ -51,6 +77,9 @@ pub fn check_profile_compliance(ontology: &Ontology, profile: OwlProfile) -> Pro
             // Full OWL 2 allows everything, so no violations
         },
     }
+
+    // Sort so that reports are stable and diffable, independent of axiom order
+    violations.sort();
     
     ProfileCheckResult {
         profile,
@@ -59,23 +88,60 @@ pub fn check_profile_compliance(ontology: &Ontology, profile: OwlProfile) -> Pro
     }
 }
 
+/// Splits an ontology into the largest sub-ontology that conforms to a profile and
+/// the axioms that do not.
+///
+/// Each axiom is checked on its own, so the conforming part can be handed to a
+/// profile-specific reasoner. The returned ontology keeps the header (IRIs, imports,
+/// annotations and prefixes) and axiom provenance of the original; the removed axioms
+/// are listed in ontology order.
+pub fn profile_subset(ontology: &Ontology, profile: OwlProfile) -> (Ontology, Vec<Axiom>) {
+    let mut subset = ontology.empty_with_header();
+    let mut removed = Vec::new();
+
+    for (index, axiom) in ontology.axioms.iter().enumerate() {
+        let mut violations = Vec::new();
+        match profile {
+            OwlProfile::EL => check_el_axiom(axiom, &mut violations),
+            OwlProfile::QL => check_ql_axiom(axiom, &mut violations),
+            OwlProfile::RL => check_rl_axiom(axiom, &mut violations),
+            OwlProfile::Full => {},
+        }
+        if violations.is_empty() {
+            subset.axioms.push(axiom.clone());
+            subset.axiom_sources.push(ontology.axiom_source(index).cloned());
+        } else {
+            removed.push(axiom.clone());
+        }
+    }
+
+    (subset, removed)
+}
+
 /// Checks EL profile compliance
 fn check_el_profile(ontology: &Ontology, violations: &mut Vec<String>) {
     for axiom in &ontology.axioms {
-        match axiom {
-            Axiom::Class(class_axiom) => {
-                check_el_class_axiom(class_axiom, violations);
-            },
-            Axiom::ObjectProperty(op_axiom) => {
-                check_el_object_property_axiom(op_axiom, violations);
-            },
-            Axiom::DataProperty(dp_axiom) => {
-                check_el_data_property_axiom(dp_axiom, violations);
-            },
-            Axiom::Assertion(assertion) => {
-                check_el_assertion(assertion, violations);
-            },
-        }
+        check_el_axiom(axiom, violations);
+    }
+}
+
+/// Checks if a single axiom is EL-compliant
+fn check_el_axiom(axiom: &Axiom, violations: &mut Vec<String>) {
+    match axiom {
+        Axiom::Class(class_axiom) => {
+            check_el_class_axiom(class_axiom, violations);
+        },
+        Axiom::ObjectProperty(op_axiom) => {
+            check_el_object_property_axiom(op_axiom, violations);
+        },
+        Axiom::DataProperty(dp_axiom) => {
+            check_el_data_property_axiom(dp_axiom, violations);
+        },
+        Axiom::Assertion(assertion) => {
+            check_el_assertion(assertion, violations);
+        },
+        // Declarations and annotations have no logical meaning and are allowed in every profile
+        Axiom::Declaration(_) | Axiom::Annotation(_) => {},
     }
 }
 
@@ -221,8 +287,10 @@ fn check_el_data_property_axiom(axiom: &DataPropertyAxiom, violations: &mut Vec<
         DataPropertyAxiom::DataPropertyRange { property: _, range } => {
             // Data property ranges in EL are restricted to datatypes
             match range {
-                crate::DataRange::Datatype(_) => {
-                    // Datatypes are EL-compliant
+                crate::DataRange::Datatype(datatype) => {
+                    if !is_el_datatype(datatype) {
+                        violations.push(format!("DataPropertyRange axiom uses datatype <{}>, which is not in the EL datatype map", datatype.0.0));
+                    }
                 },
                 _ => {
                     violations.push("DataPropertyRange axiom has non-EL range expression".to_string());
@@ -277,7 +345,7 @@ fn is_el_class_expression(expr: &ClassExpression) -> bool {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
             // Intersections are EL-compliant if all sub-expressions are EL-compliant
-            sub_exprs.iter().all(|sub_expr| is_el_class_expression(sub_expr))
+            sub_exprs.iter().all(is_el_class_expression)
         },
         ClassExpression::ObjectSomeValuesFrom { property: _, filler } => {
             // Some values from is EL-compliant if filler is EL-compliant
@@ -287,11 +355,45 @@ fn is_el_class_expression(expr: &ClassExpression) -> bool {
             // Has value is EL-compliant
             true
         },
+        ClassExpression::DataSomeValuesFrom { property: _, filler } => {
+            // Data some values from is EL-compliant if the data range is
+            is_el_data_range(filler)
+        },
+        ClassExpression::DataHasValue { property: _, value: _ } => {
+            // Data has value is EL-compliant
+            true
+        },
         // All other class expressions are not EL-compliant
         _ => false,
     }
 }
 
+/// Checks if a data range is EL-compliant: an EL datatype, an intersection of EL data
+/// ranges, or an enumeration of a single literal
+fn is_el_data_range(range: &crate::DataRange) -> bool {
+    match range {
+        crate::DataRange::Datatype(datatype) => is_el_datatype(datatype),
+        crate::DataRange::DataIntersectionOf(ranges) => ranges.iter().all(is_el_data_range),
+        crate::DataRange::DataOneOf(literals) => literals.len() == 1,
+        _ => false,
+    }
+}
+
+/// Checks if a datatype may be used in EL.
+///
+/// Datatypes of the OWL 2 datatype map (the XSD, RDF, RDFS and OWL namespaces) must
+/// be in the EL datatype map; other datatypes are user-defined and allowed.
+fn is_el_datatype(datatype: &Datatype) -> bool {
+    const STANDARD_NAMESPACES: [&str; 4] = [
+        "http://www.w3.org/2001/XMLSchema#",
+        "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+        "http://www.w3.org/2000/01/rdf-schema#",
+        "http://www.w3.org/2002/07/owl#",
+    ];
+    let iri = datatype.0.0.as_str();
+    EL_DATATYPES.contains(&iri) || !STANDARD_NAMESPACES.iter().any(|namespace| iri.starts_with(namespace))
+}
+
 /// Checks if an object property expression is EL-compliant
 fn is_el_object_property_expression(expr: &ObjectPropertyExpression) -> bool {
     match expr {
@@ -305,20 +407,26 @@ fn is_el_object_property_expression(expr: &ObjectPropertyExpression) -> bool {
 /// Checks QL profile compliance
 fn check_ql_profile(ontology: &Ontology, violations: &mut Vec<String>) {
     for axiom in &ontology.axioms {
-        match axiom {
-            Axiom::Class(class_axiom) => {
-                check_ql_class_axiom(class_axiom, violations);
-            },
-            Axiom::ObjectProperty(op_axiom) => {
-                check_ql_object_property_axiom(op_axiom, violations);
-            },
-            Axiom::DataProperty(dp_axiom) => {
-                check_ql_data_property_axiom(dp_axiom, violations);
-            },
-            Axiom::Assertion(assertion) => {
-                check_ql_assertion(assertion, violations);
-            },
-        }
+        check_ql_axiom(axiom, violations);
+    }
+}
+
+/// Checks if a single axiom is QL-compliant
+fn check_ql_axiom(axiom: &Axiom, violations: &mut Vec<String>) {
+    match axiom {
+        Axiom::Class(class_axiom) => {
+            check_ql_class_axiom(class_axiom, violations);
+        },
+        Axiom::ObjectProperty(op_axiom) => {
+            check_ql_object_property_axiom(op_axiom, violations);
+        },
+        Axiom::DataProperty(dp_axiom) => {
+            check_ql_data_property_axiom(dp_axiom, violations);
+        },
+        Axiom::Assertion(assertion) => {
+            check_ql_assertion(assertion, violations);
+        },
+        Axiom::Declaration(_) | Axiom::Annotation(_) => {},
     }
 }
 
@@ -387,13 +495,10 @@ fn check_ql_object_property_axiom(axiom: &ObjectPropertyAxiom, violations: &mut
 
 /// Checks if a data property axiom is QL-compliant
 fn check_ql_data_property_axiom(axiom: &DataPropertyAxiom, violations: &mut Vec<String>) {
-    match axiom {
-        DataPropertyAxiom::FunctionalDataProperty { property: _ } => {
-            // FunctionalDataProperty is not allowed in QL
-            violations.push("FunctionalDataProperty axiom is not allowed in QL profile".to_string());
-        },
-        // All other data property axioms are allowed in QL
-        _ => {},
+    // All other data property axioms are allowed in QL
+    if let DataPropertyAxiom::FunctionalDataProperty { property: _ } = axiom {
+        // FunctionalDataProperty is not allowed in QL
+        violations.push("FunctionalDataProperty axiom is not allowed in QL profile".to_string());
     }
 }
 
@@ -432,7 +537,7 @@ fn is_ql_superclass_expression(expr: &ClassExpression) -> bool {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
             // Intersections are allowed in superclass position
-            sub_exprs.iter().all(|sub_expr| is_ql_superclass_expression(sub_expr))
+            sub_exprs.iter().all(is_ql_superclass_expression)
         },
         ClassExpression::ObjectComplementOf(sub_expr) => {
             // Complement is allowed in superclass position
@@ -446,17 +551,31 @@ fn is_ql_superclass_expression(expr: &ClassExpression) -> bool {
             // ObjectHasValue is allowed in superclass position
             true
         },
+        ClassExpression::DataSomeValuesFrom { property: _, filler } => {
+            // DataSomeValuesFrom is allowed in superclass position with a datatype or
+            // an intersection of datatypes
+            is_ql_data_range(filler)
+        },
         // All other class expressions are not allowed in superclass position in QL
         _ => false,
     }
 }
 
+/// Checks if a data range is valid in QL: a datatype or an intersection of QL data ranges
+fn is_ql_data_range(range: &crate::DataRange) -> bool {
+    match range {
+        crate::DataRange::Datatype(_) => true,
+        crate::DataRange::DataIntersectionOf(ranges) => ranges.iter().all(is_ql_data_range),
+        _ => false,
+    }
+}
+
 /// Checks if a class expression is valid in QL (general)
 fn is_ql_valid_class_expression(expr: &ClassExpression) -> bool {
     match expr {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
-            sub_exprs.iter().all(|sub_expr| is_ql_valid_class_expression(sub_expr))
+            sub_exprs.iter().all(is_ql_valid_class_expression)
         },
         ClassExpression::ObjectComplementOf(sub_expr) => {
             is_ql_valid_class_expression(sub_expr)
@@ -477,10 +596,10 @@ fn is_rl_valid_class_expression(expr: &ClassExpression) -> bool {
     match expr {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
-            sub_exprs.iter().all(|sub_expr| is_rl_valid_class_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_valid_class_expression)
         },
         ClassExpression::ObjectUnionOf(sub_exprs) => {
-            sub_exprs.iter().all(|sub_expr| is_rl_valid_class_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_valid_class_expression)
         },
         ClassExpression::ObjectComplementOf(sub_expr) => {
             is_rl_valid_class_expression(sub_expr)
@@ -498,15 +617,24 @@ fn is_rl_valid_class_expression(expr: &ClassExpression) -> bool {
         ClassExpression::ObjectHasSelf(_) => true,
         ClassExpression::ObjectMinCardinality { min, property: _, filler } => {
             // Only min 0 or 1 allowed in RL
-            *min <= 1 && filler.as_ref().map_or(true, |f| is_rl_valid_class_expression(f))
+            *min <= 1 && filler.as_ref().is_none_or(|f| is_rl_valid_class_expression(f))
         },
         ClassExpression::ObjectMaxCardinality { max, property: _, filler } => {
             // Only max 0 or 1 allowed in RL
-            *max <= 1 && filler.as_ref().map_or(true, |f| is_rl_valid_class_expression(f))
+            *max <= 1 && filler.as_ref().is_none_or(|f| is_rl_valid_class_expression(f))
         },
         ClassExpression::ObjectExactCardinality { cardinality, property: _, filler } => {
             // Only exact 0 or 1 allowed in RL
-            *cardinality <= 1 && filler.as_ref().map_or(true, |f| is_rl_valid_class_expression(f))
+            *cardinality <= 1 && filler.as_ref().is_none_or(|f| is_rl_valid_class_expression(f))
+        },
+        ClassExpression::DataSomeValuesFrom { .. }
+        | ClassExpression::DataAllValuesFrom { .. }
+        | ClassExpression::DataHasValue { .. } => true,
+        ClassExpression::DataMinCardinality { min: n, .. }
+        | ClassExpression::DataMaxCardinality { max: n, .. }
+        | ClassExpression::DataExactCardinality { cardinality: n, .. } => {
+            // Only 0 or 1 allowed in RL
+            *n <= 1
         },
     }
 }
@@ -514,20 +642,26 @@ fn is_rl_valid_class_expression(expr: &ClassExpression) -> bool {
 /// Checks RL profile compliance
 fn check_rl_profile(ontology: &Ontology, violations: &mut Vec<String>) {
     for axiom in &ontology.axioms {
-        match axiom {
-            Axiom::Class(class_axiom) => {
-                check_rl_class_axiom(class_axiom, violations);
-            },
-            Axiom::ObjectProperty(op_axiom) => {
-                check_rl_object_property_axiom(op_axiom, violations);
-            },
-            Axiom::DataProperty(dp_axiom) => {
-                check_rl_data_property_axiom(dp_axiom, violations);
-            },
-            Axiom::Assertion(assertion) => {
-                check_rl_assertion(assertion, violations);
-            },
-        }
+        check_rl_axiom(axiom, violations);
+    }
+}
+
+/// Checks if a single axiom is RL-compliant
+fn check_rl_axiom(axiom: &Axiom, violations: &mut Vec<String>) {
+    match axiom {
+        Axiom::Class(class_axiom) => {
+            check_rl_class_axiom(class_axiom, violations);
+        },
+        Axiom::ObjectProperty(op_axiom) => {
+            check_rl_object_property_axiom(op_axiom, violations);
+        },
+        Axiom::DataProperty(dp_axiom) => {
+            check_rl_data_property_axiom(dp_axiom, violations);
+        },
+        Axiom::Assertion(assertion) => {
+            check_rl_assertion(assertion, violations);
+        },
+        Axiom::Declaration(_) | Axiom::Annotation(_) => {},
     }
 }
 
@@ -566,13 +700,10 @@ fn check_rl_class_axiom(axiom: &ClassAxiom, violations: &mut Vec<String>) {
 
 /// Checks if an object property axiom is RL-compliant
 fn check_rl_object_property_axiom(axiom: &ObjectPropertyAxiom, violations: &mut Vec<String>) {
-    match axiom {
-        ObjectPropertyAxiom::ReflexiveObjectProperty { property: _ } => {
-            // ReflexiveObjectProperty is not allowed in RL
-            violations.push("ReflexiveObjectProperty axiom is not allowed in RL profile".to_string());
-        },
-        // All other object property axioms are allowed in RL
-        _ => {},
+    // All other object property axioms are allowed in RL
+    if let ObjectPropertyAxiom::ReflexiveObjectProperty { property: _ } = axiom {
+        // ReflexiveObjectProperty is not allowed in RL
+        violations.push("ReflexiveObjectProperty axiom is not allowed in RL profile".to_string());
     }
 }
 
@@ -585,12 +716,11 @@ fn check_rl_data_property_axiom(_axiom: &DataPropertyAxiom, _violations: &mut Ve
 /// Checks if an assertion is RL-compliant
 fn check_rl_assertion(assertion: &Assertion, violations: &mut Vec<String>) {
     match assertion {
-        Assertion::ClassAssertion { class, individual: _ } => {
+        Assertion::ClassAssertion { class, individual: _ }
             // Class assertions in RL are restricted to superclass expressions
-            if !is_rl_superclass_expression(class) {
+            if !is_rl_superclass_expression(class) => {
                 violations.push("ClassAssertion has non-RL class expression".to_string());
-            }
-        },
+            },
         Assertion::HasKey { class: _, object_property_expression: _, data_property: _ } => {
             // HasKey is allowed in RL but with restrictions
             // For now, we'll allow it but note that a full implementation would check the restrictions
@@ -606,11 +736,11 @@ fn is_rl_subclass_expression(expr: &ClassExpression) -> bool {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
             // Intersections are RL-compliant if all sub-expressions are RL-compliant
-            sub_exprs.iter().all(|sub_expr| is_rl_subclass_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_subclass_expression)
         },
         ClassExpression::ObjectUnionOf(sub_exprs) => {
             // Unions are RL-compliant if all sub-expressions are RL-compliant
-            sub_exprs.iter().all(|sub_expr| is_rl_subclass_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_subclass_expression)
         },
         ClassExpression::ObjectOneOf(individuals) => {
             // Enumerations are RL-compliant
@@ -624,6 +754,10 @@ fn is_rl_subclass_expression(expr: &ClassExpression) -> bool {
             // Has value is RL-compliant if property is RL-compliant
             is_rl_object_property_expression(property)
         },
+        ClassExpression::DataSomeValuesFrom { .. } | ClassExpression::DataHasValue { .. } => {
+            // Data some values from and data has value are RL-compliant
+            true
+        },
         // All other class expressions are not RL-compliant in subclass position
         _ => false,
     }
@@ -635,11 +769,11 @@ fn is_rl_superclass_expression(expr: &ClassExpression) -> bool {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
             // Intersections are RL-compliant if all sub-expressions are RL-compliant
-            sub_exprs.iter().all(|sub_expr| is_rl_superclass_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_superclass_expression)
         },
         ClassExpression::ObjectUnionOf(sub_exprs) => {
             // Unions are RL-compliant if all sub-expressions are RL-compliant
-            sub_exprs.iter().all(|sub_expr| is_rl_superclass_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_superclass_expression)
         },
         ClassExpression::ObjectOneOf(individuals) => {
             // Enumerations are RL-compliant
@@ -664,7 +798,15 @@ fn is_rl_superclass_expression(expr: &ClassExpression) -> bool {
         ClassExpression::ObjectMaxCardinality { max, property, filler } => {
             // Only max 0 or 1 allowed in RL
             *max <= 1 && is_rl_object_property_expression(property) && 
-            filler.as_ref().map_or(true, |f| is_rl_superclass_expression(f))
+            filler.as_ref().is_none_or(|f| is_rl_superclass_expression(f))
+        },
+        ClassExpression::DataAllValuesFrom { .. } | ClassExpression::DataHasValue { .. } => {
+            // Data all values from and data has value are RL-compliant
+            true
+        },
+        ClassExpression::DataMaxCardinality { max, .. } => {
+            // Only max 0 or 1 allowed in RL
+            *max <= 1
         },
         // All other class expressions are not RL-compliant in superclass position
         _ => false,
@@ -677,12 +819,13 @@ fn is_rl_equivalent_expression(expr: &ClassExpression) -> bool {
         ClassExpression::Class(_) => true,
         ClassExpression::ObjectIntersectionOf(sub_exprs) => {
             // Intersections are RL-compliant if all sub-expressions are RL-compliant
-            sub_exprs.iter().all(|sub_expr| is_rl_equivalent_expression(sub_expr))
+            sub_exprs.iter().all(is_rl_equivalent_expression)
         },
         ClassExpression::ObjectHasValue { property, value: _ } => {
             // Has value is RL-compliant if property is RL-compliant
             is_rl_object_property_expression(property)
         },
+        ClassExpression::DataHasValue { .. } => true,
         // All other class expressions are not RL-compliant in equivalent position
         _ => false,
     }
@@ -700,6 +843,241 @@ fn is_rl_object_property_expression(expr: &ObjectPropertyExpression) -> bool {
     }
 }
 
+/// Computes the description logic expressivity of an ontology, e.g. `ALC` or `SHOIN(D)`.
+///
+/// The name starts from `EL` (conjunction and existentials only), `AL` (with universal
+/// restrictions) or `ALC` (with union or complement). Transitive properties only have a
+/// letter as part of `S`, which extends `ALC`, so an ontology that uses them is named
+/// from `S` even when it needs no complement. It is followed by `H` for a property hierarchy (or `R` for
+/// property chains and the other complex role axioms), `O` for nominals, `I` for
+/// inverse properties, `F`, `N` or `Q` for functional, unqualified and qualified number
+/// restrictions, and `(D)` when data properties are used.
+pub fn expressivity(ontology: &Ontology) -> String {
+    let mut features = LanguageFeatures::default();
+    for axiom in &ontology.axioms {
+        features.add_axiom(axiom);
+    }
+
+    let mut name = String::new();
+    if features.transitive {
+        name.push('S');
+    } else if features.complement {
+        name.push_str("ALC");
+    } else if features.universal {
+        name.push_str("AL");
+    } else {
+        name.push_str("EL");
+    }
+    if features.complex_roles {
+        name.push('R');
+    } else if features.role_hierarchy {
+        name.push('H');
+    }
+    if features.nominals {
+        name.push('O');
+    }
+    if features.inverses {
+        name.push('I');
+    }
+    if features.qualified_cardinality {
+        name.push('Q');
+    } else if features.cardinality {
+        name.push('N');
+    } else if features.functional {
+        name.push('F');
+    }
+    if features.datatypes {
+        name.push_str("(D)");
+    }
+    name
+}
+
+/// The constructors used by an ontology, as needed to name its expressivity.
+#[derive(Default)]
+struct LanguageFeatures {
+    universal: bool,
+    complement: bool,
+    transitive: bool,
+    role_hierarchy: bool,
+    complex_roles: bool,
+    nominals: bool,
+    inverses: bool,
+    functional: bool,
+    cardinality: bool,
+    qualified_cardinality: bool,
+    datatypes: bool,
+}
+
+impl LanguageFeatures {
+    fn add_axiom(&mut self, axiom: &Axiom) {
+        match axiom {
+            Axiom::Class(class_axiom) => match class_axiom {
+                ClassAxiom::SubClassOf { sub_class, super_class } => {
+                    self.add_class_expression(sub_class);
+                    self.add_class_expression(super_class);
+                }
+                ClassAxiom::EquivalentClasses { classes } => {
+                    classes.iter().for_each(|class| self.add_class_expression(class));
+                }
+                ClassAxiom::DisjointClasses { classes } => {
+                    // A single operand is disjoint from nothing and needs no negation
+                    self.complement |= classes.len() > 1;
+                    classes.iter().for_each(|class| self.add_class_expression(class));
+                }
+                ClassAxiom::DisjointUnion { disjoint_classes, .. } => {
+                    self.complement |= disjoint_classes.len() > 1;
+                    disjoint_classes.iter().for_each(|class| self.add_class_expression(class));
+                }
+            },
+            Axiom::ObjectProperty(property_axiom) => match property_axiom {
+                ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } => {
+                    self.role_hierarchy = true;
+                    self.add_property(sub_property);
+                    self.add_property(super_property);
+                }
+                ObjectPropertyAxiom::EquivalentObjectProperties { properties } => {
+                    self.role_hierarchy = true;
+                    properties.iter().for_each(|property| self.add_property(property));
+                }
+                ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                    self.complex_roles = true;
+                    properties.iter().for_each(|property| self.add_property(property));
+                }
+                ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 } => {
+                    self.inverses = true;
+                    self.add_property(prop1);
+                    self.add_property(prop2);
+                }
+                ObjectPropertyAxiom::ObjectPropertyDomain { property, domain: expression }
+                | ObjectPropertyAxiom::ObjectPropertyRange { property, range: expression } => {
+                    // Domains and ranges are expressible in EL, so need no universal restriction
+                    self.add_property(property);
+                    self.add_class_expression(expression);
+                }
+                ObjectPropertyAxiom::FunctionalObjectProperty { property } => {
+                    self.functional = true;
+                    self.add_property(property);
+                }
+                ObjectPropertyAxiom::InverseFunctionalObjectProperty { property } => {
+                    self.functional = true;
+                    self.inverses = true;
+                    self.add_property(property);
+                }
+                ObjectPropertyAxiom::ReflexiveObjectProperty { property }
+                | ObjectPropertyAxiom::IrreflexiveObjectProperty { property }
+                | ObjectPropertyAxiom::AsymmetricObjectProperty { property } => {
+                    self.complex_roles = true;
+                    self.add_property(property);
+                }
+                ObjectPropertyAxiom::SymmetricObjectProperty { property } => {
+                    self.inverses = true;
+                    self.add_property(property);
+                }
+                ObjectPropertyAxiom::TransitiveObjectProperty { property } => {
+                    self.transitive = true;
+                    self.add_property(property);
+                }
+            },
+            Axiom::DataProperty(data_axiom) => {
+                self.datatypes = true;
+                if let DataPropertyAxiom::DataPropertyDomain { domain, .. } = data_axiom {
+                    self.add_class_expression(domain);
+                }
+            }
+            Axiom::Assertion(assertion) => match assertion {
+                Assertion::ClassAssertion { class, .. } => self.add_class_expression(class),
+                Assertion::ObjectPropertyAssertion { property, .. } => self.add_property(property),
+                Assertion::NegativeObjectPropertyAssertion { property, .. } => {
+                    self.complement = true;
+                    self.add_property(property);
+                }
+                Assertion::DataPropertyAssertion { .. } | Assertion::NegativeDataPropertyAssertion { .. } => {
+                    self.datatypes = true;
+                }
+                // Equality and inequality between individuals are plain ABox facts, not nominals
+                Assertion::SameIndividual { .. } | Assertion::DifferentIndividuals { .. } => {}
+                Assertion::HasKey { object_property_expression, data_property, .. } => {
+                    object_property_expression.iter().for_each(|property| self.add_property(property));
+                    if !data_property.is_empty() {
+                        self.datatypes = true;
+                    }
+                }
+            },
+            Axiom::Declaration(_) | Axiom::Annotation(_) => {}
+        }
+    }
+
+    fn add_class_expression(&mut self, expression: &ClassExpression) {
+        match expression {
+            ClassExpression::Class(_) => {}
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                operands.iter().for_each(|operand| self.add_class_expression(operand));
+            }
+            ClassExpression::ObjectUnionOf(operands) => {
+                self.complement = true;
+                operands.iter().for_each(|operand| self.add_class_expression(operand));
+            }
+            ClassExpression::ObjectComplementOf(inner) => {
+                self.complement = true;
+                self.add_class_expression(inner);
+            }
+            ClassExpression::ObjectOneOf(_) => self.nominals = true,
+            ClassExpression::ObjectSomeValuesFrom { property, filler } => {
+                self.add_property(property);
+                self.add_class_expression(filler);
+            }
+            ClassExpression::ObjectAllValuesFrom { property, filler } => {
+                self.universal = true;
+                self.add_property(property);
+                self.add_class_expression(filler);
+            }
+            ClassExpression::ObjectHasValue { property, .. } => {
+                self.nominals = true;
+                self.add_property(property);
+            }
+            ClassExpression::ObjectHasSelf(property) => {
+                self.complex_roles = true;
+                self.add_property(property);
+            }
+            ClassExpression::ObjectMinCardinality { min: n, property, filler }
+            | ClassExpression::ObjectMaxCardinality { max: n, property, filler }
+            | ClassExpression::ObjectExactCardinality { cardinality: n, property, filler } => {
+                self.add_property(property);
+                let is_qualified = filler.as_deref().is_some_and(|filler| {
+                    !matches!(filler, ClassExpression::Class(class) if class.is_thing())
+                });
+                if is_qualified {
+                    self.qualified_cardinality = true;
+                } else if *n > 1 {
+                    self.cardinality = true;
+                } else {
+                    self.functional = true;
+                }
+                if let Some(filler) = filler {
+                    self.add_class_expression(filler);
+                }
+            }
+            ClassExpression::DataSomeValuesFrom { .. }
+            | ClassExpression::DataAllValuesFrom { .. }
+            | ClassExpression::DataHasValue { .. }
+            | ClassExpression::DataMinCardinality { .. }
+            | ClassExpression::DataMaxCardinality { .. }
+            | ClassExpression::DataExactCardinality { .. } => self.datatypes = true,
+        }
+    }
+
+    fn add_property(&mut self, property: &ObjectPropertyExpression) {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(_) => {}
+            ObjectPropertyExpression::InverseObjectProperty(_) => self.inverses = true,
+            ObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                self.complex_roles = true;
+                chain.iter().for_each(|property| self.add_property(property));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -724,6 +1102,135 @@ mod tests {
         assert!(result.violations.is_empty());
     }
 
+    #[test]
+    fn test_expressivity() {
+        let el = load_ontology(r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+)"#).unwrap();
+        assert_eq!(expressivity(&el), "EL");
+
+        let shoin = load_ontology(r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) ObjectComplementOf(Class(<http://example.com/Employee>)))
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/ancestorOf>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/parentOf>) ObjectProperty(<http://example.com/ancestorOf>))
+  SubClassOf(Class(<http://example.com/Root>) ObjectHasValue(ObjectProperty(<http://example.com/parentOf>) NamedIndividual(<http://example.com/adam>)))
+  SubClassOf(Class(<http://example.com/Orphan>) ObjectAllValuesFrom(ObjectInverseOf(ObjectProperty(<http://example.com/parentOf>)) Class(<http://example.com/Dead>)))
+  SubClassOf(Class(<http://example.com/Couple>) ObjectMaxCardinality(2 ObjectProperty(<http://example.com/hasMember>)))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/adam>) "930"^^<http://www.w3.org/2001/XMLSchema#integer>)
+)"#).unwrap();
+        assert_eq!(expressivity(&shoin), "SHOIN(D)");
+
+        let el_with_assertions = load_ontology(r#"Ontology(<http://example.com/ontology>
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Parent>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>))
+  SameIndividual(NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/anne>))
+  DifferentIndividuals(NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/bob>))
+)"#).unwrap();
+        assert_eq!(expressivity(&el_with_assertions), "EL");
+
+        let transitive = load_ontology(r#"Ontology(<http://example.com/ontology>
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/ancestorOf>))
+  SubClassOf(Class(<http://example.com/Ancestor>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/ancestorOf>) Class(<http://example.com/Person>)))
+)"#).unwrap();
+        assert_eq!(expressivity(&transitive), "S");
+    }
+
+    #[test]
+    fn test_single_operand_class_axioms() {
+        let ontology = load_ontology(r#"Ontology(<http://example.com/ontology>
+  EquivalentClasses(Class(<http://example.com/Student>))
+  DisjointClasses(Class(<http://example.com/Person>))
+)"#).unwrap();
+
+        for profile in [OwlProfile::EL, OwlProfile::QL, OwlProfile::RL] {
+            let result = check_profile_compliance(&ontology, profile);
+            assert!(result.conforms);
+            assert!(result.violations.is_empty());
+        }
+        assert_eq!(expressivity(&ontology), "EL");
+    }
+
+    #[test]
+    fn test_data_class_expressions() {
+        let ontology = load_ontology(r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Adult>) DataSomeValuesFrom(DataProperty(<http://example.com/hasAge>) Datatype(xsd:integer)))
+  SubClassOf(Class(<http://example.com/Ann>) DataHasValue(DataProperty(<http://example.com/hasName>) "Ann"))
+)"#).unwrap();
+        assert!(check_profile_compliance(&ontology, OwlProfile::EL).conforms);
+        assert_eq!(expressivity(&ontology), "EL(D)");
+
+        // Universal and cardinality data restrictions, and faceted ranges, are not EL
+        for superclass in [
+            "DataAllValuesFrom(DataProperty(<http://example.com/hasAge>) Datatype(xsd:integer))",
+            "DataMaxCardinality(1 DataProperty(<http://example.com/hasAge>))",
+            r#"DataSomeValuesFrom(DataProperty(<http://example.com/hasAge>) DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer))"#,
+        ] {
+            let ontology = load_ontology(&format!(
+                "Ontology(<http://example.com/ontology> SubClassOf(Class(<http://example.com/Adult>) {}))",
+                superclass
+            )).unwrap();
+            assert!(!check_profile_compliance(&ontology, OwlProfile::EL).conforms);
+        }
+    }
+
+    #[test]
+    fn test_profile_subset() {
+        let ontology = load_ontology(r#"Ontology(<http://example.com/ontology> <http://example.com/ontology/1.0>
+  Annotation(<http://www.w3.org/2000/01/rdf-schema#comment> "People")
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(ObjectUnionOf(Class(<http://example.com/Student>) Class(<http://example.com/Employee>)) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#).unwrap();
+
+        let (subset, removed) = profile_subset(&ontology, OwlProfile::EL);
+
+        assert_eq!(removed, vec![ontology.axioms[1].clone()]);
+        assert_eq!(subset.axioms, vec![ontology.axioms[0].clone(), ontology.axioms[2].clone(), ontology.axioms[3].clone()]);
+        assert!(check_profile_compliance(&subset, OwlProfile::EL).conforms);
+        assert_eq!(subset.ontology_iri, ontology.ontology_iri);
+        assert_eq!(subset.version_iri, ontology.version_iri);
+        assert_eq!(subset.annotations, ontology.annotations);
+    }
+
+    #[test]
+    fn test_violations_are_sorted() {
+        let axioms = [
+            "SubClassOf(ObjectUnionOf(Class(<http://example.com/Student>) Class(<http://example.com/Employee>)) Class(<http://example.com/Person>))",
+            "TransitiveObjectProperty(ObjectProperty(<http://example.com/ancestorOf>))",
+            "DataPropertyRange(DataProperty(<http://example.com/weight>) Datatype(<http://www.w3.org/2001/XMLSchema#double>))",
+            "SubClassOf(Class(<http://example.com/Person>) ObjectAllValuesFrom(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>)))",
+        ];
+        let ontology = |order: &[usize]| {
+            let body: Vec<&str> = order.iter().map(|&index| axioms[index]).collect();
+            load_ontology(&format!("Ontology(<http://example.com/ontology>\n{}\n)", body.join("\n"))).unwrap()
+        };
+
+        for profile in [OwlProfile::EL, OwlProfile::QL, OwlProfile::RL] {
+            let forward = check_profile_compliance(&ontology(&[0, 1, 2, 3]), profile.clone());
+            let backward = check_profile_compliance(&ontology(&[3, 2, 1, 0]), profile);
+            assert_eq!(forward.violations, backward.violations);
+            assert!(forward.violations.is_sorted());
+        }
+    }
+
+    #[test]
+    fn test_el_datatype_map() {
+        let range = |datatype: &str| load_ontology(&format!(r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/weight>) Datatype(<{}>))
+)"#, datatype)).unwrap();
+
+        let double = check_profile_compliance(&range("http://www.w3.org/2001/XMLSchema#double"), OwlProfile::EL);
+        assert!(!double.conforms);
+        assert_eq!(double.violations, vec![
+            "DataPropertyRange axiom uses datatype <http://www.w3.org/2001/XMLSchema#double>, which is not in the EL datatype map".to_string(),
+        ]);
+
+        assert!(check_profile_compliance(&range("http://www.w3.org/2001/XMLSchema#integer"), OwlProfile::EL).conforms);
+        assert!(check_profile_compliance(&range("http://www.w3.org/2001/XMLSchema#string"), OwlProfile::EL).conforms);
+        assert!(check_profile_compliance(&range("http://example.com/datatypes#Grams"), OwlProfile::EL).conforms);
+    }
+
     #[test]
     fn test_non_el_profile_checker() {
         // Ontology with union (not EL-compliant)
@@ -1,11 +1,120 @@
-use crate::{Axiom, Class, ClassAxiom, ClassExpression, DataProperty, DataPropertyAxiom, DataRange, Datatype, Entity, IRI, Individual, Literal, ObjectProperty, ObjectPropertyAxiom, ObjectPropertyExpression, Assertion};
+use crate::{Annotation, AnnotationAxiom, AnnotationValue, Axiom, Class, ClassAxiom, ClassExpression, DataProperty, DataPropertyAxiom, DataRange, Datatype, Entity, IRI, IriNormalizer, Individual, Literal, ObjectProperty, ObjectPropertyAxiom, ObjectPropertyExpression, Ontology, Assertion};
 use pest::Parser;
+use pest::iterators::Pair;
 use pest_derive::Parser;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::ops::Range;
+
+/// An axiom paired with the byte range of its source text.
+pub type SpannedAxiom = (Axiom, Range<usize>);
+
+/// Maps prefix names (without the trailing colon) to the namespace IRIs they abbreviate.
+pub type PrefixMap = HashMap<String, String>;
+
+/// Returns the prefixes that are predefined in OWL 2 Functional-Style Syntax:
+/// `rdf:`, `rdfs:`, `xsd:` and `owl:`.
+pub fn standard_prefixes() -> PrefixMap {
+    [
+        ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+        ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+        ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+        ("owl", "http://www.w3.org/2002/07/owl#"),
+    ]
+    .into_iter()
+    .map(|(name, iri)| (name.to_string(), iri.to_string()))
+    .collect()
+}
+
+/// Returns the namespace the default prefix `:` abbreviates when a document does not
+/// declare it: the ontology IRI, followed by `#` unless it already ends in `#` or `/`.
+fn default_namespace(ontology_iri: &IRI) -> String {
+    if ontology_iri.0.ends_with('#') || ontology_iri.0.ends_with('/') {
+        ontology_iri.0.clone()
+    } else {
+        format!("{}#", ontology_iri.0)
+    }
+}
 
 #[derive(Parser)]
 #[grammar = "grammar.pest"]
 pub struct OWLParser;
 
+/// Memoizes parsed axioms by their exact source text.
+///
+/// Re-parsing a document after a small edit with
+/// [`OWLParser::parse_ontology_with_cache`] only converts the axioms whose text
+/// changed; unchanged axioms are taken from the cache. Entries are never evicted, so
+/// call [`clear`](Self::clear) when the cached documents are no longer of interest.
+/// Cached axioms are only valid for the prefixes they were parsed with, so they are
+/// dropped when the prefixes change.
+#[derive(Debug, Clone, Default)]
+pub struct AxiomParseCache {
+    axioms: HashMap<String, Axiom>,
+    prefixes: PrefixMap,
+    hits: usize,
+    misses: usize,
+}
+
+impl AxiomParseCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an axiom, returning the cached result if the same text was parsed before.
+    /// Axioms that fail to parse are not cached.
+    pub fn parse_axiom(&mut self, input: &str) -> Result<Axiom, Box<pest::error::Error<Rule>>> {
+        self.parse_axiom_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an axiom with the given prefixes, returning the cached result if the
+    /// same text was parsed before with the same prefixes.
+    pub fn parse_axiom_with_prefixes(&mut self, input: &str, prefixes: &PrefixMap) -> Result<Axiom, Box<pest::error::Error<Rule>>> {
+        if self.prefixes != *prefixes {
+            self.axioms.clear();
+            self.prefixes = prefixes.clone();
+        }
+        if let Some(axiom) = self.axioms.get(input) {
+            self.hits += 1;
+            return Ok(axiom.clone());
+        }
+        self.misses += 1;
+        let axiom = OWLParser::parse_axiom_with_prefixes(input, prefixes)?;
+        self.axioms.insert(input.to_string(), axiom.clone());
+        Ok(axiom)
+    }
+
+    /// The number of axioms taken from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of axioms that had to be parsed.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// The number of distinct axiom texts in the cache.
+    pub fn len(&self) -> usize {
+        self.axioms.len()
+    }
+
+    /// Returns `true` if nothing has been cached.
+    pub fn is_empty(&self) -> bool {
+        self.axioms.is_empty()
+    }
+
+    /// Removes every cached axiom and resets the hit and miss counters.
+    pub fn clear(&mut self) {
+        self.axioms.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+/// A prefix declaration, `Prefix(name:=<iri>)`. The name is empty for the default
+/// prefix, `Prefix(:=<iri>)`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Prefix {
     pub name: String,
@@ -20,10 +129,10 @@ impl OWLParser {
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected IRI but found nothing".to_string(),
                 },
-                pest::Span::new(input, 0, input.len()).unwrap_or_else(|_| pest::Span::new(" ", 0, 1).unwrap())
+                pest::Span::new(input, 0, input.len()).unwrap_or_else(|| pest::Span::new(" ", 0, 1).unwrap())
             ))
         })?;
-        let inner = pair.into_inner().find(|p| p.as_rule() == Rule::iri_content).ok_or_else(|| {
+        let inner = pair.clone().into_inner().find(|p| p.as_rule() == Rule::iri_content).ok_or_else(|| {
             Box::new(pest::error::Error::new_from_span(
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected IRI content but found nothing".to_string(),
@@ -34,6 +143,16 @@ impl OWLParser {
         Ok(IRI(inner.as_str().to_string()))
     }
 
+    /// Parses a full IRI such as `<http://example.com/Student>`, or expands an
+    /// abbreviated one such as `ex:Student` with the given prefixes.
+    pub fn parse_iri_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<IRI, Box<pest::error::Error<Rule>>> {
+        if input.trim_start().starts_with('<') {
+            OWLParser::parse_iri(input)
+        } else {
+            OWLParser::expand_abbreviated_iri(input, prefixes)
+        }
+    }
+
     pub fn parse_prefix(input: &str) -> Result<Prefix, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::prefix, input)?;
         let pair = pairs.next().ok_or_else(|| {
@@ -41,19 +160,15 @@ impl OWLParser {
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected prefix declaration but found nothing".to_string(),
                 },
-                pest::Span::new(input, 0, input.len()).unwrap_or_else(|_| pest::Span::new(" ", 0, 1).unwrap())
-            ))
-        })?;
-        let mut inner = pair.into_inner();
-        let name_pair = inner.next().ok_or_else(|| {
-            Box::new(pest::error::Error::new_from_span(
-                pest::error::ErrorVariant::CustomError {
-                    message: "Expected prefix name but found nothing".to_string(),
-                },
-                pair.as_span()
+                pest::Span::new(input, 0, input.len()).unwrap_or_else(|| pest::Span::new(" ", 0, 1).unwrap())
             ))
         })?;
-        let name = name_pair.as_str().to_string();
+        let mut inner = pair.clone().into_inner().peekable();
+        // The name is empty for the default prefix, `Prefix(:=<...>)`
+        let name = match inner.peek() {
+            Some(name_pair) if name_pair.as_rule() == Rule::pnc_name => inner.next().unwrap().as_str().to_string(),
+            _ => String::new(),
+        };
         
         let iri_pair = inner.next().ok_or_else(|| {
             Box::new(pest::error::Error::new_from_span(
@@ -63,7 +178,7 @@ impl OWLParser {
                 pair.as_span()
             ))
         })?;
-        let iri_inner = iri_pair.into_inner().next().ok_or_else(|| {
+        let iri_inner = iri_pair.clone().into_inner().next().ok_or_else(|| {
             Box::new(pest::error::Error::new_from_span(
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected IRI content but found nothing".to_string(),
@@ -77,17 +192,22 @@ impl OWLParser {
     }
 
     pub fn parse_entity(input: &str) -> Result<Entity, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_entity_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an entity, expanding an abbreviated IRI with the given prefixes.
+    pub fn parse_entity_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<Entity, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::entity, input)?;
         let entity_rule_pair = pairs.next().ok_or_else(|| {
             Box::new(pest::error::Error::new_from_span(
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected entity but found nothing".to_string(),
                 },
-                pest::Span::new(input, 0, input.len()).unwrap_or_else(|_| pest::Span::new(" ", 0, 1).unwrap())
+                pest::Span::new(input, 0, input.len()).unwrap_or_else(|| pest::Span::new(" ", 0, 1).unwrap())
             ))
         })?; // This is the pair for the matched entity rule (e.g., class, datatype)
 
-        let inner_rule_pair = entity_rule_pair.into_inner().next().ok_or_else(|| {
+        let inner_rule_pair = entity_rule_pair.clone().into_inner().next().ok_or_else(|| {
             Box::new(pest::error::Error::new_from_span(
                 pest::error::ErrorVariant::CustomError {
                     message: "Expected entity type but found nothing".to_string(),
@@ -98,7 +218,7 @@ impl OWLParser {
 
         let entity = match inner_rule_pair.as_rule() {
             Rule::class => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for class but found nothing".to_string(),
@@ -107,10 +227,10 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::Class(Class(OWLParser::parse_iri(iri_str)?))
+                Entity::Class(Class(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::datatype => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for datatype but found nothing".to_string(),
@@ -119,10 +239,10 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::Datatype(Datatype(OWLParser::parse_iri(iri_str)?))
+                Entity::Datatype(Datatype(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::object_property => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for object property but found nothing".to_string(),
@@ -131,10 +251,10 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::ObjectProperty(ObjectProperty(OWLParser::parse_iri(iri_str)?))
+                Entity::ObjectProperty(ObjectProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::data_property => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for data property but found nothing".to_string(),
@@ -143,10 +263,10 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::DataProperty(DataProperty(OWLParser::parse_iri(iri_str)?))
+                Entity::DataProperty(DataProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::annotation_property => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for annotation property but found nothing".to_string(),
@@ -155,10 +275,10 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::AnnotationProperty(OWLParser::parse_iri(iri_str)?)
+                Entity::AnnotationProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?)
             },
             Rule::named_individual => {
-                let iri_pair = inner_rule_pair.into_inner().next().ok_or_else(|| {
+                let iri_pair = inner_rule_pair.clone().into_inner().next().ok_or_else(|| {
                     Box::new(pest::error::Error::new_from_span(
                         pest::error::ErrorVariant::CustomError {
                             message: "Expected IRI for named individual but found nothing".to_string(),
@@ -167,7 +287,7 @@ impl OWLParser {
                     ))
                 })?;
                 let iri_str = iri_pair.as_str();
-                Entity::NamedIndividual(OWLParser::parse_iri(iri_str)?)
+                Entity::NamedIndividual(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?)
             },
             _ => {
                 return Err(Box::new(pest::error::Error::new_from_span(
@@ -182,12 +302,36 @@ impl OWLParser {
         Ok(entity)
     }
 
+    /// Expands an abbreviated IRI such as `xsd:integer` using the given prefixes.
+    ///
+    /// A CURIE with an empty prefix name, such as `:Student`, is expanded with the
+    /// default prefix, stored in the map under the empty string.
+    pub fn expand_abbreviated_iri(input: &str, prefixes: &PrefixMap) -> Result<IRI, Box<pest::error::Error<Rule>>> {
+        let mut pairs = OWLParser::parse(Rule::abbreviated_iri, input)?;
+        let pair = pairs.next().unwrap();
+        let (name, local) = pair.as_str().split_once(':').unwrap();
+        let namespace = prefixes.get(name).ok_or_else(|| {
+            Box::new(pest::error::Error::new_from_span(
+                pest::error::ErrorVariant::CustomError {
+                    message: format!("Undeclared prefix '{}:'", name),
+                },
+                pair.as_span()
+            ))
+        })?;
+        Ok(IRI(format!("{}{}", namespace, local)))
+    }
+
     pub fn parse_literal(input: &str) -> Result<Literal, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_literal_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses a literal, expanding an abbreviated datatype IRI with the given prefixes.
+    pub fn parse_literal_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<Literal, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::literal, input)?;
         let literal_pair = pairs.next().unwrap();
         let mut inner_pairs = literal_pair.into_inner();
 
-        let value = inner_pairs.next().unwrap().as_str().to_string();
+        let value = unescape_literal_value(inner_pairs.next().unwrap().as_str());
         let mut datatype = Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())); // Default to string for now
         let mut lang: Option<String> = None;
 
@@ -197,7 +341,12 @@ impl OWLParser {
                     // This is the datatype IRI
                     datatype = Datatype(OWLParser::parse_iri(next_pair.as_str())?);
                 }
+                Rule::abbreviated_iri => {
+                    datatype = Datatype(OWLParser::expand_abbreviated_iri(next_pair.as_str(), prefixes)?);
+                }
                 Rule::lang_tag => {
+                    // Language-tagged literals have the datatype rdf:langString (RDF 1.1)
+                    datatype = Datatype(IRI("http://www.w3.org/1999/02/22-rdf-syntax-ns#langString".to_string()));
                     lang = Some(next_pair.as_str().to_string());
                 }
                 _ => unreachable!(),
@@ -208,6 +357,11 @@ impl OWLParser {
     }
 
     pub fn parse_class_expression(input: &str) -> Result<ClassExpression, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_class_expression_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses a class expression, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_class_expression_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<ClassExpression, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::class_expression, input)?;
         let class_expression_pair = pairs.next().unwrap();
         let inner_rule_pair = class_expression_pair.into_inner().next().unwrap();
@@ -215,57 +369,47 @@ impl OWLParser {
         let class_expression = match inner_rule_pair.as_rule() {
             Rule::class => {
                 let iri_str = inner_rule_pair.into_inner().next().unwrap().as_str();
-                ClassExpression::Class(Class(OWLParser::parse_iri(iri_str)?))
+                ClassExpression::Class(Class(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::object_intersection_of => {
-                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ClassExpression::ObjectIntersectionOf(classes)
             },
             Rule::object_union_of => {
-                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ClassExpression::ObjectUnionOf(classes)
             },
             Rule::object_complement_of => {
-                let class_expr = OWLParser::parse_class_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let class_expr = OWLParser::parse_class_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ClassExpression::ObjectComplementOf(Box::new(class_expr))
             },
             Rule::object_one_of => {
                 let mut individuals = Vec::new();
                 for p in inner_rule_pair.into_inner() {
-                    let entity = OWLParser::parse_entity(p.as_str())?;
-                    if let Entity::NamedIndividual(iri) = entity {
-                        individuals.push(Individual::Named(iri));
-                    } else {
-                        panic!("Expected a NamedIndividual in ObjectOneOf, but got {:?}", entity);
-                    }
+                    individuals.push(OWLParser::individual_from_pair(p, prefixes)?);
                 }
                 ClassExpression::ObjectOneOf(individuals)
             },
             Rule::object_some_values_from => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let filler = Box::new(OWLParser::parse_class_expression(inner.next().unwrap().as_str())?);
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let filler = Box::new(OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?);
                 ClassExpression::ObjectSomeValuesFrom { property, filler }
             },
             Rule::object_all_values_from => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let filler = Box::new(OWLParser::parse_class_expression(inner.next().unwrap().as_str())?);
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let filler = Box::new(OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?);
                 ClassExpression::ObjectAllValuesFrom { property, filler }
             },
             Rule::object_has_value => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let value_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let value = if let Entity::NamedIndividual(iri) = value_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in ObjectHasValue, but got {:?}", value_entity);
-                };
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let value = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
                 ClassExpression::ObjectHasValue { property, value }
             },
             Rule::object_has_self => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ClassExpression::ObjectHasSelf(property)
             },
             Rule::object_min_cardinality => {
@@ -286,9 +430,9 @@ impl OWLParser {
                 })?;
                 
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let filler = if let Some(filler_pair) = inner.next() {
-                    Some(Box::new(OWLParser::parse_class_expression(filler_pair.as_str())?))
+                    Some(Box::new(OWLParser::parse_class_expression_with_prefixes(filler_pair.as_str(), prefixes)?))
                 } else {
                     None
                 };
@@ -312,9 +456,9 @@ impl OWLParser {
                 })?;
                 
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let filler = if let Some(filler_pair) = inner.next() {
-                    Some(Box::new(OWLParser::parse_class_expression(filler_pair.as_str())?))
+                    Some(Box::new(OWLParser::parse_class_expression_with_prefixes(filler_pair.as_str(), prefixes)?))
                 } else {
                     None
                 };
@@ -338,27 +482,149 @@ impl OWLParser {
                 })?;
                 
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let filler = if let Some(filler_pair) = inner.next() {
-                    Some(Box::new(OWLParser::parse_class_expression(filler_pair.as_str())?))
+                    Some(Box::new(OWLParser::parse_class_expression_with_prefixes(filler_pair.as_str(), prefixes)?))
                 } else {
                     None
                 };
                 ClassExpression::ObjectExactCardinality { cardinality, property, filler }
             },
+            Rule::data_some_values_from | Rule::data_all_values_from => {
+                let rule = inner_rule_pair.as_rule();
+                let mut inner = inner_rule_pair.into_inner();
+                let property = OWLParser::data_property_from_pair(inner.next().unwrap(), prefixes)?;
+                let filler = OWLParser::parse_data_range_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                if rule == Rule::data_some_values_from {
+                    ClassExpression::DataSomeValuesFrom { property, filler }
+                } else {
+                    ClassExpression::DataAllValuesFrom { property, filler }
+                }
+            },
+            Rule::data_has_value => {
+                let mut inner = inner_rule_pair.into_inner();
+                let property = OWLParser::data_property_from_pair(inner.next().unwrap(), prefixes)?;
+                let value = OWLParser::parse_literal_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                ClassExpression::DataHasValue { property, value }
+            },
+            Rule::data_min_cardinality | Rule::data_max_cardinality | Rule::data_exact_cardinality => {
+                let rule = inner_rule_pair.as_rule();
+                let n = OWLParser::cardinality_from_pair(&inner_rule_pair)?;
+                let mut inner = inner_rule_pair.into_inner();
+                let property = OWLParser::data_property_from_pair(inner.next().unwrap(), prefixes)?;
+                let filler = inner.next()
+                    .map(|filler_pair| OWLParser::parse_data_range_with_prefixes(filler_pair.as_str(), prefixes))
+                    .transpose()?;
+                match rule {
+                    Rule::data_min_cardinality => ClassExpression::DataMinCardinality { min: n, property, filler },
+                    Rule::data_max_cardinality => ClassExpression::DataMaxCardinality { max: n, property, filler },
+                    _ => ClassExpression::DataExactCardinality { cardinality: n, property, filler },
+                }
+            },
             _ => unreachable!(),
         };
         Ok(class_expression)
     }
 
+    /// Reads the named or anonymous individual of an `individual` pair.
+    fn individual_from_pair(pair: Pair<Rule>, prefixes: &PrefixMap) -> Result<Individual, Box<pest::error::Error<Rule>>> {
+        let inner = pair.into_inner().next().unwrap();
+        match inner.as_rule() {
+            Rule::anonymous_individual => Ok(Individual::Anonymous(crate::NodeID(inner.as_str().to_string()))),
+            _ => {
+                let iri_str = inner.into_inner().next().unwrap().as_str();
+                Ok(Individual::Named(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
+            }
+        }
+    }
+
+    /// Reads the data property of a `data_property` pair.
+    fn data_property_from_pair(pair: Pair<Rule>, prefixes: &PrefixMap) -> Result<DataProperty, Box<pest::error::Error<Rule>>> {
+        let iri_str = pair.into_inner().next().unwrap().as_str();
+        Ok(DataProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
+    }
+
+    /// Reads the number that follows the opening parenthesis of a cardinality restriction.
+    fn cardinality_from_pair(pair: &Pair<Rule>) -> Result<u32, Box<pest::error::Error<Rule>>> {
+        let text = pair.as_str();
+        let digits = text[text.find('(').unwrap() + 1..].trim_start();
+        let digits = &digits[..digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len())];
+        digits.parse().map_err(|e| {
+            Box::new(pest::error::Error::new_from_span(
+                pest::error::ErrorVariant::CustomError {
+                    message: format!("Failed to parse cardinality '{}': {}", digits, e),
+                },
+                pair.as_span()
+            ))
+        })
+    }
+
+    pub fn parse_data_range(input: &str) -> Result<DataRange, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_data_range_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses a data range, expanding abbreviated IRIs with the given prefixes.
+    ///
+    /// The datatype of a `DatatypeRestriction` and its facets may be written as plain
+    /// full or abbreviated IRIs, e.g. `DatatypeRestriction(xsd:integer xsd:minInclusive "18"^^xsd:integer)`.
+    pub fn parse_data_range_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<DataRange, Box<pest::error::Error<Rule>>> {
+        let mut pairs = OWLParser::parse(Rule::data_range, input)?;
+        let range_pair = pairs.next().unwrap().into_inner().next().unwrap();
+        let operands = |pair: Pair<Rule>| pair.into_inner()
+            .map(|operand| OWLParser::parse_data_range_with_prefixes(operand.as_str(), prefixes))
+            .collect::<Result<Vec<_>, _>>();
+        let range = match range_pair.as_rule() {
+            Rule::data_intersection_of => DataRange::DataIntersectionOf(operands(range_pair)?),
+            Rule::data_union_of => DataRange::DataUnionOf(operands(range_pair)?),
+            Rule::data_complement_of => DataRange::DataComplementOf(Box::new(operands(range_pair)?.remove(0))),
+            Rule::data_one_of => DataRange::DataOneOf(range_pair.into_inner()
+                .map(|literal_pair| OWLParser::parse_literal_with_prefixes(literal_pair.as_str(), prefixes))
+                .collect::<Result<_, _>>()?),
+            Rule::datatype_restriction => {
+                let mut inner = range_pair.into_inner();
+                let datatype_pair = inner.next().unwrap();
+                let datatype_iri = match datatype_pair.as_rule() {
+                    Rule::datatype => datatype_pair.into_inner().next().unwrap(),
+                    _ => datatype_pair,
+                };
+                let datatype = Datatype(OWLParser::parse_iri_with_prefixes(datatype_iri.as_str(), prefixes)?);
+                let mut restrictions = Vec::new();
+                while let (Some(facet), Some(value)) = (inner.next(), inner.next()) {
+                    restrictions.push((
+                        OWLParser::parse_iri_with_prefixes(facet.as_str(), prefixes)?,
+                        OWLParser::parse_literal_with_prefixes(value.as_str(), prefixes)?,
+                    ));
+                }
+                DataRange::DatatypeRestriction { datatype, restrictions }
+            },
+            _ => {
+                // Any datatype IRI is accepted, including custom datatypes the reasoner
+                // does not know; those are kept as opaque datatypes
+                let iri_pair = range_pair.into_inner().next().unwrap();
+                DataRange::Datatype(Datatype(OWLParser::parse_iri_with_prefixes(iri_pair.as_str(), prefixes)?))
+            }
+        };
+        Ok(range)
+    }
+
     pub fn parse_object_property(input: &str) -> Result<ObjectProperty, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_object_property_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an object property, expanding an abbreviated IRI with the given prefixes.
+    pub fn parse_object_property_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<ObjectProperty, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::object_property, input)?;
         let object_property_pair = pairs.next().unwrap();
         let iri_str = object_property_pair.into_inner().next().unwrap().as_str();
-        Ok(ObjectProperty(OWLParser::parse_iri(iri_str)?))
+        Ok(ObjectProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
     }
 
     pub fn parse_object_property_expression(input: &str) -> Result<ObjectPropertyExpression, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_object_property_expression_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an object property expression, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_object_property_expression_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<ObjectPropertyExpression, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::object_property_expression, input)?;
         let object_property_expression_pair = pairs.next().unwrap();
         let inner_rule_pair = object_property_expression_pair.into_inner().next().unwrap();
@@ -366,14 +632,29 @@ impl OWLParser {
         let object_property_expression = match inner_rule_pair.as_rule() {
             Rule::object_property => {
                 let iri_str = inner_rule_pair.into_inner().next().unwrap().as_str();
-                ObjectPropertyExpression::ObjectProperty(ObjectProperty(OWLParser::parse_iri(iri_str)?))
+                ObjectPropertyExpression::ObjectProperty(ObjectProperty(OWLParser::parse_iri_with_prefixes(iri_str, prefixes)?))
             },
             Rule::object_inverse_of_rule => {
-                let iri_str = inner_rule_pair.into_inner().next().unwrap().into_inner().next().unwrap().as_str();
-                ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(OWLParser::parse_iri(iri_str)?))
+                // The inverted property is either wrapped, `ObjectInverseOf(ObjectProperty(<R>))`,
+                // or given directly, `ObjectInverseOf(<R>)`
+                let span = inner_rule_pair.as_span();
+                let property_pair = inner_rule_pair.into_inner().next();
+                let iri = match property_pair.as_ref().map(|pair| pair.as_rule()) {
+                    Some(Rule::object_property) => OWLParser::parse_object_property_with_prefixes(property_pair.unwrap().as_str(), prefixes)?.0,
+                    Some(Rule::iri | Rule::abbreviated_iri) => OWLParser::parse_iri_with_prefixes(property_pair.unwrap().as_str(), prefixes)?,
+                    _ => {
+                        return Err(Box::new(pest::error::Error::new_from_span(
+                            pest::error::ErrorVariant::CustomError {
+                                message: "Expected an object property or IRI inside ObjectInverseOf".to_string(),
+                            },
+                            span
+                        )));
+                    }
+                };
+                ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(iri))
             },
             Rule::object_property_chain => {
-                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ObjectPropertyExpression::ObjectPropertyChain(properties)
             },
             _ => unreachable!(),
@@ -382,6 +663,11 @@ impl OWLParser {
     }
 
     pub fn parse_class_axiom(input: &str) -> Result<ClassAxiom, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_class_axiom_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses a class axiom, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_class_axiom_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<ClassAxiom, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::class_axiom, input)?;
         let class_axiom_pair = pairs.next().unwrap();
         let inner_rule_pair = class_axiom_pair.into_inner().next().unwrap();
@@ -389,27 +675,27 @@ impl OWLParser {
         let class_axiom = match inner_rule_pair.as_rule() {
             Rule::sub_class_of => {
                 let mut inner = inner_rule_pair.into_inner();
-                let sub_class = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
-                let super_class = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
+                let sub_class = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let super_class = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 ClassAxiom::SubClassOf { sub_class, super_class }
             },
             Rule::equivalent_classes => {
-                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ClassAxiom::EquivalentClasses { classes }
             },
             Rule::disjoint_classes => {
-                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let classes: Vec<ClassExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_class_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ClassAxiom::DisjointClasses { classes }
             },
             Rule::disjoint_union => {
                 let mut inner = inner_rule_pair.into_inner();
-                let class_expr = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
+                let class_expr = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let class = if let ClassExpression::Class(c) = class_expr {
                     c
                 } else {
                     panic!("Expected a Class in DisjointUnion, but got {:?}", class_expr);
                 };
-                let disjoint_classes: Vec<ClassExpression> = inner.map(|p| OWLParser::parse_class_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let disjoint_classes: Vec<ClassExpression> = inner.map(|p| OWLParser::parse_class_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ClassAxiom::DisjointUnion { class, disjoint_classes }
             },
             _ => unreachable!(),
@@ -418,6 +704,11 @@ impl OWLParser {
     }
 
     pub fn parse_object_property_axiom(input: &str) -> Result<ObjectPropertyAxiom, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_object_property_axiom_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an object property axiom, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_object_property_axiom_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<ObjectPropertyAxiom, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::object_property_axiom, input)?;
         let object_property_axiom_pair = pairs.next().unwrap();
         let inner_rule_pair = object_property_axiom_pair.into_inner().next().unwrap();
@@ -425,62 +716,62 @@ impl OWLParser {
         let object_property_axiom = match inner_rule_pair.as_rule() {
             Rule::sub_object_property_of => {
                 let mut inner = inner_rule_pair.into_inner();
-                let sub_property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let super_property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
+                let sub_property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let super_property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property }
             },
             Rule::equivalent_object_properties => {
-                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ObjectPropertyAxiom::EquivalentObjectProperties { properties }
             },
             Rule::disjoint_object_properties => {
-                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression(p.as_str())).collect::<Result<Vec<_>, _>>()?;
+                let properties: Vec<ObjectPropertyExpression> = inner_rule_pair.into_inner().map(|p| OWLParser::parse_object_property_expression_with_prefixes(p.as_str(), prefixes)).collect::<Result<Vec<_>, _>>()?;
                 ObjectPropertyAxiom::DisjointObjectProperties { properties }
             },
             Rule::inverse_object_properties => {
                 let mut inner = inner_rule_pair.into_inner();
-                let prop1 = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let prop2 = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
+                let prop1 = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let prop2 = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 }
             },
             Rule::object_property_domain => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let domain = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let domain = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::ObjectPropertyDomain { property, domain }
             },
             Rule::object_property_range => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let range = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let range = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::ObjectPropertyRange { property, range }
             },
             Rule::functional_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::FunctionalObjectProperty { property }
             },
             Rule::inverse_functional_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::InverseFunctionalObjectProperty { property }
             },
             Rule::reflexive_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::ReflexiveObjectProperty { property }
             },
             Rule::irreflexive_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::IrreflexiveObjectProperty { property }
             },
             Rule::symmetric_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::SymmetricObjectProperty { property }
             },
             Rule::asymmetric_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::AsymmetricObjectProperty { property }
             },
             Rule::transitive_object_property => {
-                let property = OWLParser::parse_object_property_expression(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 ObjectPropertyAxiom::TransitiveObjectProperty { property }
             },
             _ => unreachable!(),
@@ -489,6 +780,11 @@ impl OWLParser {
     }
 
     pub fn parse_data_property_axiom(input: &str) -> Result<DataPropertyAxiom, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_data_property_axiom_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses a data property axiom, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_data_property_axiom_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<DataPropertyAxiom, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::data_property_axiom, input)?;
         let data_property_axiom_pair = pairs.next().unwrap();
         let inner_rule_pair = data_property_axiom_pair.into_inner().next().unwrap();
@@ -496,13 +792,13 @@ impl OWLParser {
         let data_property_axiom = match inner_rule_pair.as_rule() {
             Rule::sub_data_property_of => {
                 let mut inner = inner_rule_pair.into_inner();
-                let sub_property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let sub_property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let sub_property = if let Entity::DataProperty(dp) = sub_property_entity {
                     dp
                 } else {
                     panic!("Expected a DataProperty in SubDataPropertyOf, but got {:?}", sub_property_entity);
                 };
-                let super_property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let super_property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let super_property = if let Entity::DataProperty(dp) = super_property_entity {
                     dp
                 } else {
@@ -513,7 +809,7 @@ impl OWLParser {
             Rule::equivalent_data_properties => {
                 let mut properties = Vec::new();
                 for p in inner_rule_pair.into_inner() {
-                    let entity = OWLParser::parse_entity(p.as_str())?;
+                    let entity = OWLParser::parse_entity_with_prefixes(p.as_str(), prefixes)?;
                     if let Entity::DataProperty(dp) = entity {
                         properties.push(dp);
                     } else {
@@ -525,7 +821,7 @@ impl OWLParser {
             Rule::disjoint_data_properties => {
                 let mut properties = Vec::new();
                 for p in inner_rule_pair.into_inner() {
-                    let entity = OWLParser::parse_entity(p.as_str())?;
+                    let entity = OWLParser::parse_entity_with_prefixes(p.as_str(), prefixes)?;
                     if let Entity::DataProperty(dp) = entity {
                         properties.push(dp);
                     } else {
@@ -536,33 +832,28 @@ impl OWLParser {
             },
             Rule::data_property_domain => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let property = if let Entity::DataProperty(dp) = property_entity {
                     dp
                 } else {
                     panic!("Expected a DataProperty in DataPropertyDomain, but got {:?}", property_entity);
                 };
-                let domain = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
+                let domain = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 DataPropertyAxiom::DataPropertyDomain { property, domain }
             },
             Rule::data_property_range => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let property = if let Entity::DataProperty(dp) = property_entity {
                     dp
                 } else {
                     panic!("Expected a DataProperty in DataPropertyRange, but got {:?}", property_entity);
                 };
-                let range_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let range = if let Entity::Datatype(dt) = range_entity {
-                    DataRange::Datatype(dt)
-                } else {
-                    panic!("Expected a Datatype in DataPropertyRange, but got {:?}", range_entity);
-                };
+                let range = OWLParser::parse_data_range_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 DataPropertyAxiom::DataPropertyRange { property, range }
             },
             Rule::functional_data_property => {
-                let property_entity = OWLParser::parse_entity(inner_rule_pair.into_inner().next().unwrap().as_str())?;
+                let property_entity = OWLParser::parse_entity_with_prefixes(inner_rule_pair.into_inner().next().unwrap().as_str(), prefixes)?;
                 let property = if let Entity::DataProperty(dp) = property_entity {
                     dp
                 } else {
@@ -576,6 +867,11 @@ impl OWLParser {
     }
 
     pub fn parse_assertion(input: &str) -> Result<Assertion, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_assertion_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an assertion, resolving abbreviated IRIs in literal targets with the given prefixes.
+    pub fn parse_assertion_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<Assertion, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::assertion, input)?;
         let assertion_pair = pairs.next().unwrap();
         let inner_rule_pair = assertion_pair.into_inner().next().unwrap();
@@ -584,105 +880,84 @@ impl OWLParser {
             Rule::same_individual => {
                 let mut individuals = Vec::new();
                 for p in inner_rule_pair.into_inner() {
-                    let entity = OWLParser::parse_entity(p.as_str())?;
-                    if let Entity::NamedIndividual(iri) = entity {
-                        individuals.push(Individual::Named(iri));
-                    } else {
-                        panic!("Expected a NamedIndividual in SameIndividual, but got {:?}", entity);
-                    }
+                    individuals.push(OWLParser::individual_from_pair(p, prefixes)?);
                 }
                 Assertion::SameIndividual { individuals }
             },
             Rule::different_individuals => {
                 let mut individuals = Vec::new();
                 for p in inner_rule_pair.into_inner() {
-                    let entity = OWLParser::parse_entity(p.as_str())?;
-                    if let Entity::NamedIndividual(iri) = entity {
-                        individuals.push(Individual::Named(iri));
-                    } else {
-                        panic!("Expected a NamedIndividual in DifferentIndividuals, but got {:?}", entity);
-                    }
+                    individuals.push(OWLParser::individual_from_pair(p, prefixes)?);
                 }
                 Assertion::DifferentIndividuals { individuals }
             },
             Rule::class_assertion => {
                 let mut inner = inner_rule_pair.into_inner();
-                let class_expression = OWLParser::parse_class_expression(inner.next().unwrap().as_str())?;
-                let individual_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let individual = if let Entity::NamedIndividual(iri) = individual_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in ClassAssertion, but got {:?}", individual_entity);
-                };
+                let class_expression = OWLParser::parse_class_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let individual = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
                 Assertion::ClassAssertion { class: class_expression, individual }
             },
             Rule::object_property_assertion => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let source_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let source = if let Entity::NamedIndividual(iri) = source_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in ObjectPropertyAssertion, but got {:?}", source_entity);
-                };
-                let target_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let target = if let Entity::NamedIndividual(iri) = target_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in ObjectPropertyAssertion, but got {:?}", target_entity);
-                };
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let source = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
+                let target = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
                 Assertion::ObjectPropertyAssertion { property, source, target }
             },
             Rule::data_property_assertion => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let property = if let Entity::DataProperty(dp) = property_entity {
                     dp
                 } else {
                     panic!("Expected a DataProperty in DataPropertyAssertion, but got {:?}", property_entity);
                 };
-                let source_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let source = if let Entity::NamedIndividual(iri) = source_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in DataPropertyAssertion, but got {:?}", source_entity);
-                };
-                let target = OWLParser::parse_literal(inner.next().unwrap().as_str())?;
+                let source = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
+                let target = OWLParser::parse_literal_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 Assertion::DataPropertyAssertion { property, source, target }
             },
             Rule::negative_object_property_assertion => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property = OWLParser::parse_object_property_expression(inner.next().unwrap().as_str())?;
-                let source_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let source = if let Entity::NamedIndividual(iri) = source_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in NegativeObjectPropertyAssertion, but got {:?}", source_entity);
-                };
-                let target_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let target = if let Entity::NamedIndividual(iri) = target_entity {
-                    Individual::Named(iri)
-                } else {
-                    panic!("Expected a NamedIndividual in NegativeObjectPropertyAssertion, but got {:?}", target_entity);
-                };
+                let property = OWLParser::parse_object_property_expression_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let source = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
+                let target = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
                 Assertion::NegativeObjectPropertyAssertion { property, source, target }
             },
             Rule::negative_data_property_assertion => {
                 let mut inner = inner_rule_pair.into_inner();
-                let property_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
+                let property_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
                 let property = if let Entity::DataProperty(dp) = property_entity {
                     dp
                 } else {
                     panic!("Expected a DataProperty in NegativeDataPropertyAssertion, but got {:?}", property_entity);
                 };
-                let source_entity = OWLParser::parse_entity(inner.next().unwrap().as_str())?;
-                let source = if let Entity::NamedIndividual(iri) = source_entity {
-                    Individual::Named(iri)
+                let source = OWLParser::individual_from_pair(inner.next().unwrap(), prefixes)?;
+                let target = OWLParser::parse_literal_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                Assertion::NegativeDataPropertyAssertion { property, source, target }
+            },
+            Rule::has_key => {
+                let mut inner = inner_rule_pair.into_inner();
+                let class_entity = OWLParser::parse_entity_with_prefixes(inner.next().unwrap().as_str(), prefixes)?;
+                let class = if let Entity::Class(class) = class_entity {
+                    class
                 } else {
-                    panic!("Expected a NamedIndividual in NegativeDataPropertyAssertion, but got {:?}", source_entity);
+                    panic!("Expected a Class in HasKey, but got {:?}", class_entity);
                 };
-                let target = OWLParser::parse_literal(inner.next().unwrap().as_str())?;
-                Assertion::NegativeDataPropertyAssertion { property, source, target }
+                let mut object_property_expression = Vec::new();
+                let mut data_property = Vec::new();
+                for p in inner {
+                    if p.as_rule() == Rule::object_property_expression {
+                        object_property_expression.push(OWLParser::parse_object_property_expression_with_prefixes(p.as_str(), prefixes)?);
+                    } else {
+                        let entity = OWLParser::parse_entity_with_prefixes(p.as_str(), prefixes)?;
+                        if let Entity::DataProperty(dp) = entity {
+                            data_property.push(dp);
+                        } else {
+                            panic!("Expected a DataProperty in HasKey, but got {:?}", entity);
+                        }
+                    }
+                }
+                Assertion::HasKey { class, object_property_expression, data_property }
             },
             _ => unreachable!(),
         };
@@ -690,47 +965,508 @@ impl OWLParser {
     }
 
     pub fn parse_axiom(input: &str) -> Result<Axiom, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_axiom_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an axiom, resolving abbreviated IRIs with the given prefixes.
+    pub fn parse_axiom_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<Axiom, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::axiom, input)?;
         let axiom_pair = pairs.next().unwrap();
         let inner_rule_pair = axiom_pair.into_inner().next().unwrap();
 
         let axiom = match inner_rule_pair.as_rule() {
-            Rule::class_axiom => Axiom::Class(OWLParser::parse_class_axiom(inner_rule_pair.as_str())?),
-            Rule::object_property_axiom => Axiom::ObjectProperty(OWLParser::parse_object_property_axiom(inner_rule_pair.as_str())?),
-            Rule::data_property_axiom => Axiom::DataProperty(OWLParser::parse_data_property_axiom(inner_rule_pair.as_str())?),
-            Rule::assertion => Axiom::Assertion(OWLParser::parse_assertion(inner_rule_pair.as_str())?),
+            Rule::declaration => {
+                let entity_pair = inner_rule_pair.into_inner().next().unwrap();
+                Axiom::Declaration(OWLParser::parse_entity_with_prefixes(entity_pair.as_str(), prefixes)?)
+            }
+            Rule::class_axiom => Axiom::Class(OWLParser::parse_class_axiom_with_prefixes(inner_rule_pair.as_str(), prefixes)?),
+            Rule::object_property_axiom => Axiom::ObjectProperty(OWLParser::parse_object_property_axiom_with_prefixes(inner_rule_pair.as_str(), prefixes)?),
+            Rule::data_property_axiom => Axiom::DataProperty(OWLParser::parse_data_property_axiom_with_prefixes(inner_rule_pair.as_str(), prefixes)?),
+            Rule::assertion => Axiom::Assertion(OWLParser::parse_assertion_with_prefixes(inner_rule_pair.as_str(), prefixes)?),
+            Rule::annotation_axiom => Axiom::Annotation(OWLParser::parse_annotation_assertion_with_prefixes(inner_rule_pair.as_str(), prefixes)?),
             _ => unreachable!(),
         };
         Ok(axiom)
     }
 
+    /// Parses an `AnnotationAssertion`. The annotation property may be written as
+    /// `AnnotationProperty(<iri>)` or as a plain IRI, and IRIs may be abbreviated with
+    /// the standard prefixes (`rdfs:label`).
+    pub fn parse_annotation_assertion(input: &str) -> Result<AnnotationAxiom, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_annotation_assertion_with_prefixes(input, &standard_prefixes())
+    }
+
+    /// Parses an `AnnotationAssertion`, expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_annotation_assertion_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<AnnotationAxiom, Box<pest::error::Error<Rule>>> {
+        let mut pairs = OWLParser::parse(Rule::annotation_assertion, input)?;
+        let mut inner = pairs.next().unwrap().into_inner();
+        let property = OWLParser::annotation_iri(inner.next().unwrap(), prefixes)?;
+        let subject = OWLParser::annotation_iri(inner.next().unwrap(), prefixes)?;
+        let value = OWLParser::annotation_value(inner.next().unwrap(), prefixes)?;
+        Ok(AnnotationAxiom::AnnotationAssertion { property, subject, value })
+    }
+
+    /// Parses an ontology annotation such as `Annotation(rdfs:label "My ontology")`,
+    /// expanding abbreviated IRIs with the given prefixes.
+    pub fn parse_annotation_with_prefixes(input: &str, prefixes: &PrefixMap) -> Result<Annotation, Box<pest::error::Error<Rule>>> {
+        let mut pairs = OWLParser::parse(Rule::annotation, input)?;
+        let mut inner = pairs.next().unwrap().into_inner();
+        let property = OWLParser::annotation_iri(inner.next().unwrap(), prefixes)?;
+        let value = OWLParser::annotation_value(inner.next().unwrap(), prefixes)?;
+        Ok(Annotation { property, value })
+    }
+
+    /// Reads an annotation property or subject, written as `AnnotationProperty(<iri>)`,
+    /// a full IRI or an abbreviated IRI.
+    fn annotation_iri(pair: pest::iterators::Pair<Rule>, prefixes: &PrefixMap) -> Result<IRI, Box<pest::error::Error<Rule>>> {
+        match pair.as_rule() {
+            Rule::annotation_property => OWLParser::parse_iri_with_prefixes(pair.into_inner().next().unwrap().as_str(), prefixes),
+            Rule::abbreviated_iri => OWLParser::expand_abbreviated_iri(pair.as_str(), prefixes),
+            _ => OWLParser::parse_iri(pair.as_str()),
+        }
+    }
+
+    fn annotation_value(pair: pest::iterators::Pair<Rule>, prefixes: &PrefixMap) -> Result<AnnotationValue, Box<pest::error::Error<Rule>>> {
+        Ok(match pair.as_rule() {
+            Rule::literal => AnnotationValue::Literal(OWLParser::parse_literal_with_prefixes(pair.as_str(), prefixes)?),
+            Rule::anonymous_individual => AnnotationValue::AnonymousIndividual(crate::NodeID(pair.as_str().to_string())),
+            _ => AnnotationValue::IRI(OWLParser::annotation_iri(pair, prefixes)?),
+        })
+    }
+
     pub fn parse_ontology(input: &str) -> Result<crate::Ontology, Box<pest::error::Error<Rule>>> {
+        Ok(OWLParser::parse_ontology_with_max_axioms(input, usize::MAX)?.expect("no axiom limit"))
+    }
+
+    /// Parses an ontology document and rewrites every IRI in it with `normalizer`
+    /// (see [`Ontology::normalize_iris`]).
+    pub fn parse_ontology_with_normalizer(input: &str, normalizer: &dyn IriNormalizer) -> Result<crate::Ontology, Box<pest::error::Error<Rule>>> {
+        let mut ontology = OWLParser::parse_ontology(input)?;
+        ontology.normalize_iris(normalizer);
+        Ok(ontology)
+    }
+
+    /// Parses an ontology document, giving up as soon as it finds more than
+    /// `max_axioms` axioms. Returns `Ok(None)` in that case, before the remaining
+    /// axioms are converted.
+    pub(crate) fn parse_ontology_with_max_axioms(input: &str, max_axioms: usize) -> Result<Option<crate::Ontology>, Box<pest::error::Error<Rule>>> {
+        OWLParser::parse_ontology_with_axiom_parser(input, max_axioms, OWLParser::parse_axiom_with_prefixes)
+    }
+
+    /// Parses an ontology document, taking unchanged axioms from `cache` instead of
+    /// parsing them again. The document structure is still checked in full.
+    pub fn parse_ontology_with_cache(input: &str, cache: &mut AxiomParseCache) -> Result<crate::Ontology, Box<pest::error::Error<Rule>>> {
+        Ok(OWLParser::parse_ontology_with_axiom_parser(input, usize::MAX, |text, prefixes| cache.parse_axiom_with_prefixes(text, prefixes))?.expect("no axiom limit"))
+    }
+
+    /// Parses an ontology document, converting the text of each axiom with
+    /// `parse_axiom` and the document's prefixes. Returns `Ok(None)` once more than
+    /// `max_axioms` axioms are found.
+    ///
+    /// The standard prefixes are always available, and the `Prefix` declarations
+    /// before `Ontology(` add to or override them. Unless declared, the default prefix
+    /// `:` abbreviates the ontology IRI followed by `#`.
+    fn parse_ontology_with_axiom_parser(
+        input: &str,
+        max_axioms: usize,
+        mut parse_axiom: impl FnMut(&str, &PrefixMap) -> Result<Axiom, Box<pest::error::Error<Rule>>>,
+    ) -> Result<Option<crate::Ontology>, Box<pest::error::Error<Rule>>> {
         let mut pairs = OWLParser::parse(Rule::ontology, input)?;
         let ontology_pair = pairs.next().unwrap();
         let mut inner = ontology_pair.into_inner();
 
-        // The first optional element is the ontology IRI
         let mut ontology = crate::Ontology::default();
-        
-        // Check if the first element is an IRI
-        if let Some(first_pair) = inner.peek() {
-            if first_pair.as_rule() == Rule::iri {
-                let iri_pair = inner.next().unwrap();
-                let _iri = OWLParser::parse_iri(iri_pair.as_str())?;
-                // For now, we'll just note that we have an IRI but we're not storing it
-                // In a more complete implementation, we would store the ontology IRI
+        let declared = OWLParser::declared_prefixes(&mut inner)?;
+        let mut prefixes = standard_prefixes();
+        prefixes.extend(declared.clone());
+        if !declared.is_empty() {
+            ontology.prefixes = Some(declared);
+        }
+
+        // The prefixes are followed by the optional ontology IRI and version IRI
+        if let Some(first_pair) = inner.peek()
+            && first_pair.as_rule() == Rule::iri {
+                let iri = OWLParser::parse_iri(inner.next().unwrap().as_str())?;
+                prefixes.entry(String::new()).or_insert_with(|| default_namespace(&iri));
+                ontology.ontology_iri = Some(iri);
+                if let Some(version_pair) = inner.peek()
+                    && version_pair.as_rule() == Rule::iri {
+                        ontology.version_iri = Some(OWLParser::parse_iri(inner.next().unwrap().as_str())?);
+                    }
+            }
+
+        // Parse the import directives, the ontology annotations and all the axioms
+        for axiom_pair in inner {
+            match axiom_pair.as_rule() {
+                Rule::import => {
+                    let iri_pair = axiom_pair.into_inner().next().unwrap();
+                    ontology.direct_imports.push(OWLParser::parse_iri_with_prefixes(iri_pair.as_str(), &prefixes)?);
+                }
+                Rule::annotation => {
+                    ontology.annotations.push(OWLParser::parse_annotation_with_prefixes(axiom_pair.as_str(), &prefixes)?);
+                }
+                Rule::axiom => {
+                    if ontology.axioms.len() == max_axioms {
+                        return Ok(None);
+                    }
+                    let axiom = parse_axiom(axiom_pair.as_str(), &prefixes)?;
+                    ontology.axioms.push(axiom);
+                }
+                // Skip comments (they don't need to be processed)
+                _ => {}
             }
         }
 
-        // Parse all the axioms
+        Ok(Some(ontology))
+    }
+
+    /// Consumes the `Prefix` declarations (and comments) at the start of an ontology
+    /// document and returns the prefixes they declare.
+    fn declared_prefixes(pairs: &mut pest::iterators::Pairs<Rule>) -> Result<PrefixMap, Box<pest::error::Error<Rule>>> {
+        let mut declared = PrefixMap::new();
+        while let Some(pair) = pairs.peek()
+            && matches!(pair.as_rule(), Rule::prefix | Rule::comment) {
+                pairs.next();
+                if pair.as_rule() == Rule::prefix {
+                    let prefix = OWLParser::parse_prefix(pair.as_str())?;
+                    declared.insert(prefix.name, prefix.iri.0);
+                }
+            }
+        Ok(declared)
+    }
+
+    /// Parses an ontology document and returns each axiom together with the byte range
+    /// of its source text in `input`, in document order.
+    ///
+    /// This is intended for tooling such as editors that need to map axioms back to
+    /// their location (highlighting, go-to-definition).
+    pub fn parse_ontology_with_spans(input: &str) -> Result<Vec<SpannedAxiom>, Box<pest::error::Error<Rule>>> {
+        let mut pairs = OWLParser::parse(Rule::ontology, input)?;
+        let ontology_pair = pairs.next().unwrap();
+        let mut inner = ontology_pair.into_inner();
+        let mut prefixes = standard_prefixes();
+        prefixes.extend(OWLParser::declared_prefixes(&mut inner)?);
+
+        let mut axioms = Vec::new();
         for axiom_pair in inner {
-            if axiom_pair.as_rule() == Rule::axiom {
-                let axiom = OWLParser::parse_axiom(axiom_pair.as_str())?;
-                ontology.axioms.push(axiom);
+            match axiom_pair.as_rule() {
+                Rule::iri => {
+                    let iri = OWLParser::parse_iri(axiom_pair.as_str())?;
+                    prefixes.entry(String::new()).or_insert_with(|| default_namespace(&iri));
+                }
+                Rule::axiom => {
+                    let span = axiom_pair.as_span();
+                    let axiom = OWLParser::parse_axiom_with_prefixes(axiom_pair.as_str(), &prefixes)?;
+                    axioms.push((axiom, span.start()..span.end()));
+                }
+                _ => {}
             }
-            // Skip comments (they don't need to be processed)
         }
 
-        Ok(ontology)
+        Ok(axioms)
     }
-}
\ No newline at end of file
+}
+
+/// Writes an ontology in Functional-Style Syntax to `writer`, one axiom per line.
+///
+/// Axioms are rendered and flushed one at a time, so large ontologies can be written
+/// without building the whole document in memory. The output can be read back with
+/// [`OWLParser::parse_ontology`]. The prefixes the ontology was parsed with are
+/// declared first, but IRIs are always written in full.
+pub fn write_functional_syntax<W: Write>(ontology: &Ontology, writer: &mut W) -> io::Result<()> {
+    if let Some(prefixes) = &ontology.prefixes {
+        let mut prefixes: Vec<(&String, &String)> = prefixes.iter().collect();
+        prefixes.sort();
+        for (name, namespace) in prefixes {
+            writeln!(writer, "Prefix({}:=<{}>)", name, namespace)?;
+        }
+    }
+    match (&ontology.ontology_iri, &ontology.version_iri) {
+        (Some(iri), Some(version_iri)) => writeln!(writer, "Ontology({} {}", fss_iri(iri), fss_iri(version_iri))?,
+        (Some(iri), None) => writeln!(writer, "Ontology({}", fss_iri(iri))?,
+        (None, _) => writeln!(writer, "Ontology(")?,
+    }
+    for import in &ontology.direct_imports {
+        writeln!(writer, "  Import({})", fss_iri(import))?;
+    }
+    for annotation in &ontology.annotations {
+        writeln!(writer, "  Annotation(AnnotationProperty({}) {})", fss_iri(&annotation.property), fss_annotation_value(&annotation.value))?;
+    }
+    for axiom in &ontology.axioms {
+        writeln!(writer, "  {}", fss_axiom(axiom))?;
+        writer.flush()?;
+    }
+    writeln!(writer, ")")?;
+    writer.flush()
+}
+
+/// Renders an ontology in Functional-Style Syntax, exactly as
+/// [`write_functional_syntax`] writes it. `Ontology` implements `Display` the same way.
+pub fn write_ontology(ontology: &Ontology) -> String {
+    let mut buffer = Vec::new();
+    write_functional_syntax(ontology, &mut buffer).expect("writing to memory cannot fail");
+    String::from_utf8(buffer).expect("Functional-Style Syntax is valid UTF-8")
+}
+
+fn fss_iri(iri: &IRI) -> String {
+    format!("<{}>", iri.0)
+}
+
+fn fss_class(class: &Class) -> String {
+    format!("Class({})", fss_iri(&class.0))
+}
+
+fn fss_datatype(datatype: &Datatype) -> String {
+    format!("Datatype({})", fss_iri(&datatype.0))
+}
+
+fn fss_data_property(property: &DataProperty) -> String {
+    format!("DataProperty({})", fss_iri(&property.0))
+}
+
+fn fss_individual(individual: &Individual) -> String {
+    match individual {
+        Individual::Named(iri) => format!("NamedIndividual({})", fss_iri(iri)),
+        Individual::Anonymous(node_id) => node_id.0.clone(),
+    }
+}
+
+/// Replaces the `\"` and `\\` escapes of a quoted string with the characters they
+/// stand for. The grammar admits no other escapes.
+fn unescape_literal_value(escaped: &str) -> String {
+    let mut value = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        value.push(if c == '\\' { chars.next().unwrap_or(c) } else { c });
+    }
+    value
+}
+
+fn fss_literal(literal: &Literal) -> String {
+    let value = literal.value.replace('\\', "\\\\").replace('"', "\\\"");
+    match &literal.lang {
+        Some(lang) => format!("\"{}\"@{}", value, lang),
+        None => format!("\"{}\"^^{}", value, fss_iri(&literal.datatype.0)),
+    }
+}
+
+fn fss_list<T>(items: &[T], render: impl Fn(&T) -> String) -> String {
+    items.iter().map(render).collect::<Vec<_>>().join(" ")
+}
+
+pub(crate) fn fss_object_property_expression(property: &ObjectPropertyExpression) -> String {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) => format!("ObjectProperty({})", fss_iri(&p.0)),
+        ObjectPropertyExpression::InverseObjectProperty(p) => format!("ObjectInverseOf(ObjectProperty({}))", fss_iri(&p.0)),
+        ObjectPropertyExpression::ObjectPropertyChain(chain) => {
+            format!("ObjectPropertyChain({})", fss_list(chain, fss_object_property_expression))
+        }
+    }
+}
+
+fn fss_cardinality(name: &str, n: u32, property: &ObjectPropertyExpression, filler: &Option<Box<ClassExpression>>) -> String {
+    match filler {
+        Some(filler) => format!("{}({} {} {})", name, n, fss_object_property_expression(property), fss_class_expression(filler)),
+        None => format!("{}({} {})", name, n, fss_object_property_expression(property)),
+    }
+}
+
+fn fss_data_cardinality(name: &str, n: u32, property: &DataProperty, filler: &Option<DataRange>) -> String {
+    match filler {
+        Some(filler) => format!("{}({} {} {})", name, n, fss_data_property(property), fss_data_range(filler)),
+        None => format!("{}({} {})", name, n, fss_data_property(property)),
+    }
+}
+
+pub(crate) fn fss_class_expression(expression: &ClassExpression) -> String {
+    match expression {
+        ClassExpression::Class(class) => fss_class(class),
+        ClassExpression::ObjectIntersectionOf(operands) => format!("ObjectIntersectionOf({})", fss_list(operands, fss_class_expression)),
+        ClassExpression::ObjectUnionOf(operands) => format!("ObjectUnionOf({})", fss_list(operands, fss_class_expression)),
+        ClassExpression::ObjectComplementOf(inner) => format!("ObjectComplementOf({})", fss_class_expression(inner)),
+        ClassExpression::ObjectOneOf(individuals) => format!("ObjectOneOf({})", fss_list(individuals, fss_individual)),
+        ClassExpression::ObjectSomeValuesFrom { property, filler } => {
+            format!("ObjectSomeValuesFrom({} {})", fss_object_property_expression(property), fss_class_expression(filler))
+        }
+        ClassExpression::ObjectAllValuesFrom { property, filler } => {
+            format!("ObjectAllValuesFrom({} {})", fss_object_property_expression(property), fss_class_expression(filler))
+        }
+        ClassExpression::ObjectHasValue { property, value } => {
+            format!("ObjectHasValue({} {})", fss_object_property_expression(property), fss_individual(value))
+        }
+        ClassExpression::ObjectHasSelf(property) => format!("ObjectHasSelf({})", fss_object_property_expression(property)),
+        ClassExpression::ObjectMinCardinality { min, property, filler } => fss_cardinality("ObjectMinCardinality", *min, property, filler),
+        ClassExpression::ObjectMaxCardinality { max, property, filler } => fss_cardinality("ObjectMaxCardinality", *max, property, filler),
+        ClassExpression::ObjectExactCardinality { cardinality, property, filler } => {
+            fss_cardinality("ObjectExactCardinality", *cardinality, property, filler)
+        }
+        ClassExpression::DataSomeValuesFrom { property, filler } => {
+            format!("DataSomeValuesFrom({} {})", fss_data_property(property), fss_data_range(filler))
+        }
+        ClassExpression::DataAllValuesFrom { property, filler } => {
+            format!("DataAllValuesFrom({} {})", fss_data_property(property), fss_data_range(filler))
+        }
+        ClassExpression::DataHasValue { property, value } => {
+            format!("DataHasValue({} {})", fss_data_property(property), fss_literal(value))
+        }
+        ClassExpression::DataMinCardinality { min, property, filler } => fss_data_cardinality("DataMinCardinality", *min, property, filler),
+        ClassExpression::DataMaxCardinality { max, property, filler } => fss_data_cardinality("DataMaxCardinality", *max, property, filler),
+        ClassExpression::DataExactCardinality { cardinality, property, filler } => {
+            fss_data_cardinality("DataExactCardinality", *cardinality, property, filler)
+        }
+    }
+}
+
+fn fss_data_range(range: &DataRange) -> String {
+    match range {
+        DataRange::Datatype(datatype) => fss_datatype(datatype),
+        DataRange::DataIntersectionOf(ranges) => format!("DataIntersectionOf({})", fss_list(ranges, fss_data_range)),
+        DataRange::DataUnionOf(ranges) => format!("DataUnionOf({})", fss_list(ranges, fss_data_range)),
+        DataRange::DataComplementOf(inner) => format!("DataComplementOf({})", fss_data_range(inner)),
+        DataRange::DataOneOf(literals) => format!("DataOneOf({})", fss_list(literals, fss_literal)),
+        DataRange::DatatypeRestriction { datatype, restrictions } => {
+            let facets = fss_list(restrictions, |(facet, value)| format!("{} {}", fss_iri(facet), fss_literal(value)));
+            format!("DatatypeRestriction({} {})", fss_datatype(datatype), facets)
+        }
+    }
+}
+
+fn fss_entity(entity: &Entity) -> String {
+    match entity {
+        Entity::Class(class) => fss_class(class),
+        Entity::Datatype(datatype) => fss_datatype(datatype),
+        Entity::ObjectProperty(property) => format!("ObjectProperty({})", fss_iri(&property.0)),
+        Entity::DataProperty(property) => fss_data_property(property),
+        Entity::AnnotationProperty(iri) => format!("AnnotationProperty({})", fss_iri(iri)),
+        Entity::NamedIndividual(iri) => format!("NamedIndividual({})", fss_iri(iri)),
+    }
+}
+
+pub(crate) fn fss_axiom(axiom: &Axiom) -> String {
+    match axiom {
+        Axiom::Declaration(entity) => format!("Declaration({})", fss_entity(entity)),
+        Axiom::Class(axiom) => match axiom {
+            ClassAxiom::SubClassOf { sub_class, super_class } => {
+                format!("SubClassOf({} {})", fss_class_expression(sub_class), fss_class_expression(super_class))
+            }
+            ClassAxiom::EquivalentClasses { classes } => format!("EquivalentClasses({})", fss_list(classes, fss_class_expression)),
+            ClassAxiom::DisjointClasses { classes } => format!("DisjointClasses({})", fss_list(classes, fss_class_expression)),
+            ClassAxiom::DisjointUnion { class, disjoint_classes } => {
+                format!("DisjointUnion({} {})", fss_class(class), fss_list(disjoint_classes, fss_class_expression))
+            }
+        },
+        Axiom::ObjectProperty(axiom) => match axiom {
+            ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } => format!(
+                "SubObjectPropertyOf({} {})",
+                fss_object_property_expression(sub_property),
+                fss_object_property_expression(super_property)
+            ),
+            ObjectPropertyAxiom::EquivalentObjectProperties { properties } => {
+                format!("EquivalentObjectProperties({})", fss_list(properties, fss_object_property_expression))
+            }
+            ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                format!("DisjointObjectProperties({})", fss_list(properties, fss_object_property_expression))
+            }
+            ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 } => format!(
+                "InverseObjectProperties({} {})",
+                fss_object_property_expression(prop1),
+                fss_object_property_expression(prop2)
+            ),
+            ObjectPropertyAxiom::ObjectPropertyDomain { property, domain } => {
+                format!("ObjectPropertyDomain({} {})", fss_object_property_expression(property), fss_class_expression(domain))
+            }
+            ObjectPropertyAxiom::ObjectPropertyRange { property, range } => {
+                format!("ObjectPropertyRange({} {})", fss_object_property_expression(property), fss_class_expression(range))
+            }
+            ObjectPropertyAxiom::FunctionalObjectProperty { property } => {
+                format!("FunctionalObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::InverseFunctionalObjectProperty { property } => {
+                format!("InverseFunctionalObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::ReflexiveObjectProperty { property } => {
+                format!("ReflexiveObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::IrreflexiveObjectProperty { property } => {
+                format!("IrreflexiveObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::SymmetricObjectProperty { property } => {
+                format!("SymmetricObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::AsymmetricObjectProperty { property } => {
+                format!("AsymmetricObjectProperty({})", fss_object_property_expression(property))
+            }
+            ObjectPropertyAxiom::TransitiveObjectProperty { property } => {
+                format!("TransitiveObjectProperty({})", fss_object_property_expression(property))
+            }
+        },
+        Axiom::DataProperty(axiom) => match axiom {
+            DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } => {
+                format!("SubDataPropertyOf({} {})", fss_data_property(sub_property), fss_data_property(super_property))
+            }
+            DataPropertyAxiom::EquivalentDataProperties { properties } => {
+                format!("EquivalentDataProperties({})", fss_list(properties, fss_data_property))
+            }
+            DataPropertyAxiom::DisjointDataProperties { properties } => {
+                format!("DisjointDataProperties({})", fss_list(properties, fss_data_property))
+            }
+            DataPropertyAxiom::DataPropertyDomain { property, domain } => {
+                format!("DataPropertyDomain({} {})", fss_data_property(property), fss_class_expression(domain))
+            }
+            DataPropertyAxiom::DataPropertyRange { property, range } => {
+                format!("DataPropertyRange({} {})", fss_data_property(property), fss_data_range(range))
+            }
+            DataPropertyAxiom::FunctionalDataProperty { property } => format!("FunctionalDataProperty({})", fss_data_property(property)),
+        },
+        Axiom::Assertion(assertion) => match assertion {
+            Assertion::SameIndividual { individuals } => format!("SameIndividual({})", fss_list(individuals, fss_individual)),
+            Assertion::DifferentIndividuals { individuals } => format!("DifferentIndividuals({})", fss_list(individuals, fss_individual)),
+            Assertion::ClassAssertion { class, individual } => {
+                format!("ClassAssertion({} {})", fss_class_expression(class), fss_individual(individual))
+            }
+            Assertion::ObjectPropertyAssertion { property, source, target } => format!(
+                "ObjectPropertyAssertion({} {} {})",
+                fss_object_property_expression(property),
+                fss_individual(source),
+                fss_individual(target)
+            ),
+            Assertion::DataPropertyAssertion { property, source, target } => format!(
+                "DataPropertyAssertion({} {} {})",
+                fss_data_property(property),
+                fss_individual(source),
+                fss_literal(target)
+            ),
+            Assertion::NegativeObjectPropertyAssertion { property, source, target } => format!(
+                "NegativeObjectPropertyAssertion({} {} {})",
+                fss_object_property_expression(property),
+                fss_individual(source),
+                fss_individual(target)
+            ),
+            Assertion::NegativeDataPropertyAssertion { property, source, target } => format!(
+                "NegativeDataPropertyAssertion({} {} {})",
+                fss_data_property(property),
+                fss_individual(source),
+                fss_literal(target)
+            ),
+            Assertion::HasKey { class, object_property_expression, data_property } => format!(
+                "HasKey({} ({}) ({}))",
+                fss_class(class),
+                fss_list(object_property_expression, fss_object_property_expression),
+                fss_list(data_property, fss_data_property)
+            ),
+        },
+        Axiom::Annotation(annotation) => match annotation {
+            AnnotationAxiom::AnnotationAssertion { property, subject, value } => {
+                format!("AnnotationAssertion(AnnotationProperty({}) {} {})", fss_iri(property), fss_iri(subject), fss_annotation_value(value))
+            }
+        },
+    }
+}
+
+fn fss_annotation_value(value: &AnnotationValue) -> String {
+    match value {
+        AnnotationValue::IRI(iri) => fss_iri(iri),
+        AnnotationValue::AnonymousIndividual(node_id) => node_id.0.clone(),
+        AnnotationValue::Literal(literal) => fss_literal(literal),
+    }
+}
@@ -7,6 +7,9 @@
 //! - Turtle
 //! - JSON-LD
 //! 
+//! Functional-Style Syntax documents can also be streamed to Turtle with
+//! [`convert_fss_to_turtle`], which maps each axiom to RDF as it is read.
+//! 
 //! ## Usage
 //! 
 //! ```rust,ignore
@@ -15,11 +18,18 @@
 //! convert_rdf_format("input.ttl", "output.rdf", RdfFormat::Turtle, RdfFormat::RdfXml)?;
 //! ```
 
-use crate::{Ontology, api::Owl2RsError};
+use crate::{Annotation, Assertion, AnnotationAxiom, AnnotationValue, Axiom, Class, ClassAxiom, ClassExpression, DataProperty, DataPropertyAxiom, DataRange, Datatype, Entity, Individual, IRI, Literal, NodeID, ObjectProperty, ObjectPropertyAxiom, ObjectPropertyExpression, Ontology, api::Owl2RsError};
+use crate::parser::{OWLParser, standard_prefixes};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use std::io::{BufReader, BufWriter};
-use oxrdfio::{RdfParser, RdfSerializer, RdfFormat};
-use oxrdf::Quad;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use oxrdfio::{RdfParser, RdfSerializer, RdfFormat, WriterQuadSerializer};
+use oxrdf::{BlankNode, NamedNode, NamedOrBlankNode, Quad, Term, Triple};
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
 
 /// Converts an RDF file from one format to another.
 /// 
@@ -41,11 +51,11 @@ pub fn convert_rdf_format<P: AsRef<Path>>(
     output_format: RdfFormat
 ) -> Result<(), Owl2RsError> {
     // Open input file
-    let input_file = std::fs::File::open(input_path).map_err(|e| Owl2RsError::IoError(e))?;
+    let input_file = std::fs::File::open(input_path).map_err(Owl2RsError::IoError)?;
     let reader = BufReader::new(input_file);
     
     // Open output file
-    let output_file = std::fs::File::create(output_path).map_err(|e| Owl2RsError::IoError(e))?;
+    let output_file = std::fs::File::create(output_path).map_err(Owl2RsError::IoError)?;
     let writer = BufWriter::new(output_file);
     
     // Create parser and serializer
@@ -90,6 +100,708 @@ pub fn convert_rdf_format<P: AsRef<Path>>(
     Ok(())
 }
 
+/// Converts an ontology in OWL 2 Functional-Style Syntax to Turtle.
+/// 
+/// The input is read incrementally: each `Prefix`, `Import` and axiom is parsed as soon
+/// as its closing parenthesis is read, and the corresponding triples (following the OWL 2
+/// mapping to RDF graphs) are written straight away. The whole ontology is never held in
+/// memory, so arbitrarily large documents can be converted.
+/// 
+/// # Arguments
+/// 
+/// * `reader` - Source of the Functional-Style Syntax document
+/// * `writer` - Destination for the Turtle output
+/// 
+/// # Returns
+/// 
+/// * `Ok(())` - Conversion successful
+/// * `Err(Owl2RsError)` - An error if an axiom cannot be parsed or the output cannot be written
+pub fn convert_fss_to_turtle<R: Read, W: Write>(reader: R, writer: W) -> Result<(), Owl2RsError> {
+    let mut output = RdfWriter::new(RdfSerializer::from_format(RdfFormat::Turtle).for_writer(writer));
+    let mut prefixes = standard_prefixes();
+    let mut ontology: Option<NamedOrBlankNode> = None;
+    let mut in_ontology = false;
+
+    // The text of the item currently being read, and the scanner state
+    let mut item = String::new();
+    let mut depth = 0usize;
+    let mut in_iri = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_comment = false;
+
+    for line in BufReader::new(reader).lines() {
+        let line = line?;
+        for c in line.chars().chain(std::iter::once('\n')) {
+            if in_comment {
+                in_comment = c != '\n';
+                continue;
+            }
+            if in_string {
+                item.push(c);
+                // Strings follow the grammar's literal_value: only `\"` and `\\` are escapes
+                if escaped {
+                    if !matches!(c, '"' | '\\') {
+                        return Err(stream_error(&format!("invalid escape '\\{}' in string literal", c)));
+                    }
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    in_string = false;
+                }
+                continue;
+            }
+            if in_iri {
+                item.push(c);
+                if c == '>' {
+                    in_iri = false;
+                    // An IRI directly inside `Ontology(` is the ontology IRI
+                    if in_ontology && depth == 1 && item.trim_start().starts_with('<') {
+                        let iri = OWLParser::parse_iri(item.trim())?;
+                        if ontology.is_none() {
+                            let node: NamedOrBlankNode = named(&iri).into();
+                            output.triple(node.clone(), rdf("type"), owl("Ontology"))?;
+                            ontology = Some(node);
+                        } else if let Some(node) = &ontology {
+                            output.triple(node.clone(), owl("versionIRI"), named(&iri))?;
+                        }
+                        item.clear();
+                    }
+                }
+                continue;
+            }
+            match c {
+                '#' => {
+                    in_comment = true;
+                    continue;
+                }
+                '<' => in_iri = true,
+                '"' => in_string = true,
+                '(' => {
+                    depth += 1;
+                    if depth == 1 && !in_ontology && item.trim() == "Ontology" {
+                        in_ontology = true;
+                        item.clear();
+                        continue;
+                    }
+                }
+                ')' => {
+                    if depth == 0 {
+                        return Err(stream_error("unbalanced ')'"));
+                    }
+                    depth -= 1;
+                    if in_ontology && depth == 0 {
+                        in_ontology = false;
+                        if !item.trim().is_empty() {
+                            return Err(stream_error(&format!("unexpected content in ontology: {}", item.trim())));
+                        }
+                        item.clear();
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            item.push(c);
+
+            let item_complete = c == ')' && depth == usize::from(in_ontology);
+            if !item_complete {
+                continue;
+            }
+            let text = item.trim();
+            if !in_ontology {
+                let prefix = OWLParser::parse_prefix(text)?;
+                prefixes.insert(prefix.name, prefix.iri.0);
+            } else {
+                let node = match &ontology {
+                    Some(node) => node.clone(),
+                    None => {
+                        let node: NamedOrBlankNode = output.blank_node().into();
+                        output.triple(node.clone(), rdf("type"), owl("Ontology"))?;
+                        ontology = Some(node.clone());
+                        node
+                    }
+                };
+                if text.starts_with("Import(") {
+                    let iri = text.trim_start_matches("Import(").trim_end_matches(')').trim();
+                    output.triple(node, owl("imports"), named(&OWLParser::parse_iri(iri)?))?;
+                } else if text.starts_with("Annotation(") {
+                    let annotation = OWLParser::parse_annotation_with_prefixes(text, &prefixes)?;
+                    let value = output.annotation_value(&annotation.value);
+                    output.triple(node, named(&annotation.property), value)?;
+                } else {
+                    output.axiom(&OWLParser::parse_axiom_with_prefixes(text, &prefixes)?)?;
+                }
+            }
+            item.clear();
+        }
+    }
+
+    if depth != 0 || in_iri || in_string || !item.trim().is_empty() {
+        return Err(stream_error("unexpected end of input"));
+    }
+    output.finish()?;
+    Ok(())
+}
+
+/// Serializes an ontology to Turtle, following the OWL 2 mapping to RDF graphs.
+/// 
+/// The ontology header (its IRI, version IRI, imports and annotations) is written
+/// first, followed by the triples of each axiom in order. RDF cannot tell data and
+/// annotation properties apart from object properties by their use alone, so those
+/// that are used without being declared get a typing triple as well. The output can
+/// be read back with [`load_ontology_from_turtle`].
+/// 
+/// # Arguments
+/// 
+/// * `ontology` - The ontology to serialize
+/// * `writer` - Destination for the Turtle output
+/// 
+/// # Returns
+/// 
+/// * `Ok(())` - Serialization successful
+/// * `Err(Owl2RsError)` - An error if the output cannot be written
+pub fn write_ontology_to_turtle(ontology: &Ontology, writer: impl Write) -> Result<(), Owl2RsError> {
+    let mut output = RdfWriter::new(RdfSerializer::from_format(RdfFormat::Turtle).for_writer(writer));
+
+    let node: NamedOrBlankNode = match &ontology.ontology_iri {
+        Some(iri) => named(iri).into(),
+        None => output.blank_node().into(),
+    };
+    output.triple(node.clone(), rdf("type"), owl("Ontology"))?;
+    if let (Some(_), Some(version_iri)) = (&ontology.ontology_iri, &ontology.version_iri) {
+        output.triple(node.clone(), owl("versionIRI"), named(version_iri))?;
+    }
+    for import in &ontology.direct_imports {
+        output.triple(node.clone(), owl("imports"), named(import))?;
+    }
+    for annotation in &ontology.annotations {
+        let value = output.annotation_value(&annotation.value);
+        output.triple(node.clone(), named(&annotation.property), value)?;
+    }
+
+    let declared: HashSet<&Entity> = ontology.axioms.iter()
+        .filter_map(|axiom| match axiom {
+            Axiom::Declaration(entity) => Some(entity),
+            _ => None,
+        })
+        .collect();
+    let mut implicit: Vec<(IRI, Entity)> = ontology.signature().into_iter()
+        .chain(ontology.annotations.iter().map(|annotation| Entity::AnnotationProperty(annotation.property.clone())))
+        .filter_map(|entity| match &entity {
+            Entity::DataProperty(DataProperty(iri)) | Entity::AnnotationProperty(iri)
+                if !declared.contains(&entity) && ![RDF, RDFS, OWL].iter().any(|namespace| iri.0.starts_with(namespace)) =>
+            {
+                Some((iri.clone(), entity))
+            }
+            _ => None,
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    implicit.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+    for (_, entity) in implicit {
+        output.axiom(&Axiom::Declaration(entity))?;
+    }
+
+    for axiom in &ontology.axioms {
+        output.axiom(axiom)?;
+    }
+    output.finish()?;
+    Ok(())
+}
+
+/// Builds the error returned for malformed streamed input.
+fn stream_error(message: &str) -> Owl2RsError {
+    Owl2RsError::StreamingError(format!("Malformed Functional-Style Syntax: {}", message))
+}
+
+fn named(iri: &IRI) -> NamedNode {
+    NamedNode::new_unchecked(iri.0.clone())
+}
+
+fn rdf(name: &str) -> NamedNode {
+    NamedNode::new_unchecked(format!("{}{}", RDF, name))
+}
+
+fn rdfs(name: &str) -> NamedNode {
+    NamedNode::new_unchecked(format!("{}{}", RDFS, name))
+}
+
+fn owl(name: &str) -> NamedNode {
+    NamedNode::new_unchecked(format!("{}{}", OWL, name))
+}
+
+fn literal(literal: &Literal) -> oxrdf::Literal {
+    match &literal.lang {
+        Some(lang) => oxrdf::Literal::new_language_tagged_literal_unchecked(literal.value.clone(), lang.to_lowercase()),
+        None => oxrdf::Literal::new_typed_literal(literal.value.clone(), named(&literal.datatype.0)),
+    }
+}
+
+fn non_negative_integer(value: u32) -> oxrdf::Literal {
+    oxrdf::Literal::new_typed_literal(value.to_string(), NamedNode::new_unchecked(format!("{}nonNegativeInteger", XSD)))
+}
+
+/// Writes OWL 2 axioms as RDF triples, following the OWL 2 mapping to RDF graphs.
+///
+/// Class expressions, data ranges and lists are written as blank-node structures.
+/// Anonymous individuals are relabelled through the same counter as those nodes, so
+/// an input label such as `_:b1` cannot collide with a generated one.
+struct RdfWriter<W: Write> {
+    serializer: WriterQuadSerializer<W>,
+    next_blank_node: u64,
+    anonymous: HashMap<NodeID, BlankNode>,
+}
+
+impl<W: Write> RdfWriter<W> {
+    fn new(serializer: WriterQuadSerializer<W>) -> Self {
+        RdfWriter { serializer, next_blank_node: 0, anonymous: HashMap::new() }
+    }
+
+    fn finish(self) -> io::Result<W> {
+        self.serializer.finish()
+    }
+
+    fn blank_node(&mut self) -> BlankNode {
+        self.next_blank_node += 1;
+        BlankNode::new_unchecked(format!("b{}", self.next_blank_node))
+    }
+
+    fn triple(&mut self, subject: impl Into<NamedOrBlankNode>, predicate: NamedNode, object: impl Into<Term>) -> io::Result<()> {
+        self.serializer.serialize_triple(&Triple::new(subject, predicate, object))
+    }
+
+    /// Writes an RDF list of the given terms and returns its head.
+    fn list(&mut self, items: Vec<Term>) -> io::Result<Term> {
+        let mut tail: Term = rdf("nil").into();
+        for item in items.into_iter().rev() {
+            let cell = self.blank_node();
+            self.triple(cell.clone(), rdf("first"), item)?;
+            self.triple(cell.clone(), rdf("rest"), tail)?;
+            tail = cell.into();
+        }
+        Ok(tail)
+    }
+
+    fn individual(&mut self, individual: &Individual) -> NamedOrBlankNode {
+        match individual {
+            Individual::Named(iri) => named(iri).into(),
+            Individual::Anonymous(node_id) => self.anonymous_individual(node_id).into(),
+        }
+    }
+
+    /// Returns the blank node written for an anonymous individual, allocating one the
+    /// first time the individual is seen.
+    fn anonymous_individual(&mut self, node_id: &NodeID) -> BlankNode {
+        if let Some(node) = self.anonymous.get(node_id) {
+            return node.clone();
+        }
+        let node = self.blank_node();
+        self.anonymous.insert(node_id.clone(), node.clone());
+        node
+    }
+
+    fn annotation_value(&mut self, value: &AnnotationValue) -> Term {
+        match value {
+            AnnotationValue::IRI(iri) => named(iri).into(),
+            AnnotationValue::AnonymousIndividual(node_id) => self.anonymous_individual(node_id).into(),
+            AnnotationValue::Literal(value) => literal(value).into(),
+        }
+    }
+
+    /// Returns the named property and whether it is used inverted. Property chains
+    /// cannot be used as a predicate.
+    fn predicate(property: &ObjectPropertyExpression) -> io::Result<(NamedNode, bool)> {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(p) => Ok((named(&p.0), false)),
+            ObjectPropertyExpression::InverseObjectProperty(p) => Ok((named(&p.0), true)),
+            ObjectPropertyExpression::ObjectPropertyChain(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "property chains can only be used in SubObjectPropertyOf",
+            )),
+        }
+    }
+
+    fn property(&mut self, property: &ObjectPropertyExpression) -> io::Result<Term> {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(p) => Ok(named(&p.0).into()),
+            ObjectPropertyExpression::InverseObjectProperty(p) => {
+                let node = self.blank_node();
+                self.triple(node.clone(), owl("inverseOf"), named(&p.0))?;
+                Ok(node.into())
+            }
+            ObjectPropertyExpression::ObjectPropertyChain(chain) => self.properties(chain),
+        }
+    }
+
+    fn properties(&mut self, properties: &[ObjectPropertyExpression]) -> io::Result<Term> {
+        let items = properties.iter().map(|p| self.property(p)).collect::<io::Result<Vec<_>>>()?;
+        self.list(items)
+    }
+
+    fn class_expressions(&mut self, classes: &[ClassExpression]) -> io::Result<Term> {
+        let items = classes.iter().map(|c| self.class_expression(c)).collect::<io::Result<Vec<_>>>()?;
+        self.list(items)
+    }
+
+    fn class_expression(&mut self, expression: &ClassExpression) -> io::Result<Term> {
+        if let ClassExpression::Class(class) = expression {
+            return Ok(named(&class.0).into());
+        }
+        let node = self.blank_node();
+        match expression {
+            ClassExpression::Class(_) => unreachable!(),
+            ClassExpression::ObjectIntersectionOf(operands) | ClassExpression::ObjectUnionOf(operands) => {
+                let keyword = if matches!(expression, ClassExpression::ObjectIntersectionOf(_)) { "intersectionOf" } else { "unionOf" };
+                self.triple(node.clone(), rdf("type"), owl("Class"))?;
+                let list = self.class_expressions(operands)?;
+                self.triple(node.clone(), owl(keyword), list)?;
+            }
+            ClassExpression::ObjectComplementOf(inner) => {
+                self.triple(node.clone(), rdf("type"), owl("Class"))?;
+                let inner = self.class_expression(inner)?;
+                self.triple(node.clone(), owl("complementOf"), inner)?;
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                self.triple(node.clone(), rdf("type"), owl("Class"))?;
+                let items = individuals.iter().map(|i| self.individual(i).into()).collect();
+                let list = self.list(items)?;
+                self.triple(node.clone(), owl("oneOf"), list)?;
+            }
+            ClassExpression::ObjectSomeValuesFrom { property, filler } | ClassExpression::ObjectAllValuesFrom { property, filler } => {
+                let keyword = if matches!(expression, ClassExpression::ObjectSomeValuesFrom { .. }) { "someValuesFrom" } else { "allValuesFrom" };
+                self.restriction(&node, property)?;
+                let filler = self.class_expression(filler)?;
+                self.triple(node.clone(), owl(keyword), filler)?;
+            }
+            ClassExpression::ObjectHasValue { property, value } => {
+                self.restriction(&node, property)?;
+                let value = self.individual(value);
+                self.triple(node.clone(), owl("hasValue"), value)?;
+            }
+            ClassExpression::ObjectHasSelf(property) => {
+                self.restriction(&node, property)?;
+                let value = oxrdf::Literal::new_typed_literal("true", NamedNode::new_unchecked(format!("{}boolean", XSD)));
+                self.triple(node.clone(), owl("hasSelf"), value)?;
+            }
+            ClassExpression::ObjectMinCardinality { min: n, property, filler }
+            | ClassExpression::ObjectMaxCardinality { max: n, property, filler }
+            | ClassExpression::ObjectExactCardinality { cardinality: n, property, filler } => {
+                let keyword = match expression {
+                    ClassExpression::ObjectMinCardinality { .. } => "min",
+                    ClassExpression::ObjectMaxCardinality { .. } => "max",
+                    _ => "",
+                };
+                self.restriction(&node, property)?;
+                match filler {
+                    Some(filler) => {
+                        let predicate = if keyword.is_empty() { "qualifiedCardinality".to_string() } else { format!("{}QualifiedCardinality", keyword) };
+                        self.triple(node.clone(), owl(&predicate), non_negative_integer(*n))?;
+                        let filler = self.class_expression(filler)?;
+                        self.triple(node.clone(), owl("onClass"), filler)?;
+                    }
+                    None => {
+                        let predicate = if keyword.is_empty() { "cardinality".to_string() } else { format!("{}Cardinality", keyword) };
+                        self.triple(node.clone(), owl(&predicate), non_negative_integer(*n))?;
+                    }
+                }
+            }
+            ClassExpression::DataSomeValuesFrom { property, filler } | ClassExpression::DataAllValuesFrom { property, filler } => {
+                let keyword = if matches!(expression, ClassExpression::DataSomeValuesFrom { .. }) { "someValuesFrom" } else { "allValuesFrom" };
+                self.data_restriction(&node, property)?;
+                let filler = self.data_range(filler)?;
+                self.triple(node.clone(), owl(keyword), filler)?;
+            }
+            ClassExpression::DataHasValue { property, value } => {
+                self.data_restriction(&node, property)?;
+                self.triple(node.clone(), owl("hasValue"), literal(value))?;
+            }
+            ClassExpression::DataMinCardinality { min: n, property, filler }
+            | ClassExpression::DataMaxCardinality { max: n, property, filler }
+            | ClassExpression::DataExactCardinality { cardinality: n, property, filler } => {
+                let keyword = match expression {
+                    ClassExpression::DataMinCardinality { .. } => "min",
+                    ClassExpression::DataMaxCardinality { .. } => "max",
+                    _ => "",
+                };
+                self.data_restriction(&node, property)?;
+                match filler {
+                    Some(filler) => {
+                        let predicate = if keyword.is_empty() { "qualifiedCardinality".to_string() } else { format!("{}QualifiedCardinality", keyword) };
+                        self.triple(node.clone(), owl(&predicate), non_negative_integer(*n))?;
+                        let filler = self.data_range(filler)?;
+                        self.triple(node.clone(), owl("onDataRange"), filler)?;
+                    }
+                    None => {
+                        let predicate = if keyword.is_empty() { "cardinality".to_string() } else { format!("{}Cardinality", keyword) };
+                        self.triple(node.clone(), owl(&predicate), non_negative_integer(*n))?;
+                    }
+                }
+            }
+        }
+        Ok(node.into())
+    }
+
+    fn restriction(&mut self, node: &BlankNode, property: &ObjectPropertyExpression) -> io::Result<()> {
+        self.triple(node.clone(), rdf("type"), owl("Restriction"))?;
+        let property = self.property(property)?;
+        self.triple(node.clone(), owl("onProperty"), property)
+    }
+
+    fn data_restriction(&mut self, node: &BlankNode, property: &DataProperty) -> io::Result<()> {
+        self.triple(node.clone(), rdf("type"), owl("Restriction"))?;
+        self.triple(node.clone(), owl("onProperty"), named(&property.0))
+    }
+
+    fn data_range(&mut self, range: &DataRange) -> io::Result<Term> {
+        if let DataRange::Datatype(datatype) = range {
+            return Ok(named(&datatype.0).into());
+        }
+        let node = self.blank_node();
+        self.triple(node.clone(), rdf("type"), rdfs("Datatype"))?;
+        match range {
+            DataRange::Datatype(_) => unreachable!(),
+            DataRange::DataIntersectionOf(ranges) | DataRange::DataUnionOf(ranges) => {
+                let keyword = if matches!(range, DataRange::DataIntersectionOf(_)) { "intersectionOf" } else { "unionOf" };
+                let items = ranges.iter().map(|r| self.data_range(r)).collect::<io::Result<Vec<_>>>()?;
+                let list = self.list(items)?;
+                self.triple(node.clone(), owl(keyword), list)?;
+            }
+            DataRange::DataComplementOf(inner) => {
+                let inner = self.data_range(inner)?;
+                self.triple(node.clone(), owl("datatypeComplementOf"), inner)?;
+            }
+            DataRange::DataOneOf(literals) => {
+                let list = self.list(literals.iter().map(|l| literal(l).into()).collect())?;
+                self.triple(node.clone(), owl("oneOf"), list)?;
+            }
+            DataRange::DatatypeRestriction { datatype, restrictions } => {
+                self.triple(node.clone(), owl("onDatatype"), named(&datatype.0))?;
+                let mut facets = Vec::new();
+                for (facet, value) in restrictions {
+                    let facet_node = self.blank_node();
+                    self.triple(facet_node.clone(), named(facet), literal(value))?;
+                    facets.push(facet_node.into());
+                }
+                let list = self.list(facets)?;
+                self.triple(node.clone(), owl("withRestrictions"), list)?;
+            }
+        }
+        Ok(node.into())
+    }
+
+    /// Writes `first predicate other` for every other operand.
+    fn pairwise(&mut self, operands: Vec<Term>, predicate: &str) -> io::Result<()> {
+        let Some((first, rest)) = operands.split_first() else {
+            return Ok(());
+        };
+        let subject = term_subject(first);
+        for other in rest {
+            self.triple(subject.clone(), owl(predicate), other.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Writes a disjointness axiom: a single triple for two operands, otherwise an
+    /// `AllDisjoint*`/`AllDifferent` node with its members.
+    fn disjoint(&mut self, operands: Vec<Term>, pair_predicate: &str, all_type: &str) -> io::Result<()> {
+        if operands.len() == 2 {
+            let subject = term_subject(&operands[0]);
+            return self.triple(subject, owl(pair_predicate), operands[1].clone());
+        }
+        let node = self.blank_node();
+        self.triple(node.clone(), rdf("type"), owl(all_type))?;
+        let list = self.list(operands)?;
+        self.triple(node, owl("members"), list)
+    }
+
+    fn axiom(&mut self, axiom: &Axiom) -> io::Result<()> {
+        match axiom {
+            Axiom::Declaration(entity) => {
+                let (subject, kind) = match entity {
+                    Entity::Class(class) => (&class.0, owl("Class")),
+                    Entity::Datatype(datatype) => (&datatype.0, rdfs("Datatype")),
+                    Entity::ObjectProperty(property) => (&property.0, owl("ObjectProperty")),
+                    Entity::DataProperty(property) => (&property.0, owl("DatatypeProperty")),
+                    Entity::AnnotationProperty(iri) => (iri, owl("AnnotationProperty")),
+                    Entity::NamedIndividual(iri) => (iri, owl("NamedIndividual")),
+                };
+                self.triple(named(subject), rdf("type"), kind)
+            }
+            Axiom::Class(axiom) => self.class_axiom(axiom),
+            Axiom::ObjectProperty(axiom) => self.object_property_axiom(axiom),
+            Axiom::DataProperty(axiom) => self.data_property_axiom(axiom),
+            Axiom::Assertion(assertion) => self.assertion(assertion),
+            Axiom::Annotation(AnnotationAxiom::AnnotationAssertion { property, subject, value }) => {
+                let value = self.annotation_value(value);
+                self.triple(named(subject), named(property), value)
+            }
+        }
+    }
+
+    fn class_axiom(&mut self, axiom: &ClassAxiom) -> io::Result<()> {
+        match axiom {
+            ClassAxiom::SubClassOf { sub_class, super_class } => {
+                let sub = self.class_expression(sub_class)?;
+                let sup = self.class_expression(super_class)?;
+                self.triple(term_subject(&sub), rdfs("subClassOf"), sup)
+            }
+            ClassAxiom::EquivalentClasses { classes } => {
+                let operands = classes.iter().map(|c| self.class_expression(c)).collect::<io::Result<Vec<_>>>()?;
+                self.pairwise(operands, "equivalentClass")
+            }
+            ClassAxiom::DisjointClasses { classes } => {
+                let operands = classes.iter().map(|c| self.class_expression(c)).collect::<io::Result<Vec<_>>>()?;
+                self.disjoint(operands, "disjointWith", "AllDisjointClasses")
+            }
+            ClassAxiom::DisjointUnion { class, disjoint_classes } => {
+                let list = self.class_expressions(disjoint_classes)?;
+                self.triple(named(&class.0), owl("disjointUnionOf"), list)
+            }
+        }
+    }
+
+    fn object_property_axiom(&mut self, axiom: &ObjectPropertyAxiom) -> io::Result<()> {
+        let characteristic = |property: &ObjectPropertyExpression, class: &str| (property.clone(), class.to_string());
+        let (property, class) = match axiom {
+            ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } => {
+                let sup = self.property(super_property)?;
+                return match sub_property {
+                    ObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                        let list = self.properties(chain)?;
+                        self.triple(term_subject(&sup), owl("propertyChainAxiom"), list)
+                    }
+                    _ => {
+                        let sub = self.property(sub_property)?;
+                        self.triple(term_subject(&sub), rdfs("subPropertyOf"), sup)
+                    }
+                };
+            }
+            ObjectPropertyAxiom::EquivalentObjectProperties { properties } => {
+                let operands = properties.iter().map(|p| self.property(p)).collect::<io::Result<Vec<_>>>()?;
+                return self.pairwise(operands, "equivalentProperty");
+            }
+            ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                let operands = properties.iter().map(|p| self.property(p)).collect::<io::Result<Vec<_>>>()?;
+                return self.disjoint(operands, "propertyDisjointWith", "AllDisjointProperties");
+            }
+            ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 } => {
+                let first = self.property(prop1)?;
+                let second = self.property(prop2)?;
+                return self.triple(term_subject(&first), owl("inverseOf"), second);
+            }
+            ObjectPropertyAxiom::ObjectPropertyDomain { property, domain } => {
+                let property = self.property(property)?;
+                let domain = self.class_expression(domain)?;
+                return self.triple(term_subject(&property), rdfs("domain"), domain);
+            }
+            ObjectPropertyAxiom::ObjectPropertyRange { property, range } => {
+                let property = self.property(property)?;
+                let range = self.class_expression(range)?;
+                return self.triple(term_subject(&property), rdfs("range"), range);
+            }
+            ObjectPropertyAxiom::FunctionalObjectProperty { property } => characteristic(property, "FunctionalProperty"),
+            ObjectPropertyAxiom::InverseFunctionalObjectProperty { property } => characteristic(property, "InverseFunctionalProperty"),
+            ObjectPropertyAxiom::ReflexiveObjectProperty { property } => characteristic(property, "ReflexiveProperty"),
+            ObjectPropertyAxiom::IrreflexiveObjectProperty { property } => characteristic(property, "IrreflexiveProperty"),
+            ObjectPropertyAxiom::SymmetricObjectProperty { property } => characteristic(property, "SymmetricProperty"),
+            ObjectPropertyAxiom::AsymmetricObjectProperty { property } => characteristic(property, "AsymmetricProperty"),
+            ObjectPropertyAxiom::TransitiveObjectProperty { property } => characteristic(property, "TransitiveProperty"),
+        };
+        let property = self.property(&property)?;
+        self.triple(term_subject(&property), rdf("type"), owl(&class))
+    }
+
+    fn data_property_axiom(&mut self, axiom: &DataPropertyAxiom) -> io::Result<()> {
+        match axiom {
+            DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property } => {
+                self.triple(named(&sub_property.0), rdfs("subPropertyOf"), named(&super_property.0))
+            }
+            DataPropertyAxiom::EquivalentDataProperties { properties } => {
+                self.pairwise(properties.iter().map(|p| named(&p.0).into()).collect(), "equivalentProperty")
+            }
+            DataPropertyAxiom::DisjointDataProperties { properties } => {
+                self.disjoint(properties.iter().map(|p| named(&p.0).into()).collect(), "propertyDisjointWith", "AllDisjointProperties")
+            }
+            DataPropertyAxiom::DataPropertyDomain { property, domain } => {
+                let domain = self.class_expression(domain)?;
+                self.triple(named(&property.0), rdfs("domain"), domain)
+            }
+            DataPropertyAxiom::DataPropertyRange { property, range } => {
+                let range = self.data_range(range)?;
+                self.triple(named(&property.0), rdfs("range"), range)
+            }
+            DataPropertyAxiom::FunctionalDataProperty { property } => {
+                self.triple(named(&property.0), rdf("type"), owl("FunctionalProperty"))
+            }
+        }
+    }
+
+    fn assertion(&mut self, assertion: &Assertion) -> io::Result<()> {
+        match assertion {
+            Assertion::SameIndividual { individuals } => {
+                let operands = individuals.iter().map(|i| self.individual(i).into()).collect();
+                self.pairwise(operands, "sameAs")
+            }
+            Assertion::DifferentIndividuals { individuals } => {
+                let operands = individuals.iter().map(|i| self.individual(i).into()).collect();
+                self.disjoint(operands, "differentFrom", "AllDifferent")
+            }
+            Assertion::ClassAssertion { class, individual } => {
+                let class = self.class_expression(class)?;
+                let individual = self.individual(individual);
+                self.triple(individual, rdf("type"), class)
+            }
+            Assertion::ObjectPropertyAssertion { property, source, target } => {
+                let (predicate, inverse) = Self::predicate(property)?;
+                let (source, target) = if inverse { (target, source) } else { (source, target) };
+                let (source, target) = (self.individual(source), self.individual(target));
+                self.triple(source, predicate, target)
+            }
+            Assertion::DataPropertyAssertion { property, source, target } => {
+                let source = self.individual(source);
+                self.triple(source, named(&property.0), literal(target))
+            }
+            Assertion::NegativeObjectPropertyAssertion { property, source, target } => {
+                let property = self.property(property)?;
+                let node = self.negative_assertion(source, property)?;
+                let target = self.individual(target);
+                self.triple(node, owl("targetIndividual"), target)
+            }
+            Assertion::NegativeDataPropertyAssertion { property, source, target } => {
+                let node = self.negative_assertion(source, named(&property.0).into())?;
+                self.triple(node, owl("targetValue"), literal(target))
+            }
+            Assertion::HasKey { class, object_property_expression, data_property } => {
+                let mut keys = object_property_expression.iter().map(|p| self.property(p)).collect::<io::Result<Vec<_>>>()?;
+                keys.extend(data_property.iter().map(|p| Term::from(named(&p.0))));
+                let list = self.list(keys)?;
+                self.triple(named(&class.0), owl("hasKey"), list)
+            }
+        }
+    }
+
+    fn negative_assertion(&mut self, source: &Individual, property: Term) -> io::Result<BlankNode> {
+        let node = self.blank_node();
+        self.triple(node.clone(), rdf("type"), owl("NegativePropertyAssertion"))?;
+        let source = self.individual(source);
+        self.triple(node.clone(), owl("sourceIndividual"), source)?;
+        self.triple(node.clone(), owl("assertionProperty"), property)?;
+        Ok(node)
+    }
+}
+
+/// Converts a term produced for a class expression, property or individual into a
+/// triple subject. Those terms are always IRIs or blank nodes.
+fn term_subject(term: &Term) -> NamedOrBlankNode {
+    match term {
+        Term::NamedNode(node) => node.clone().into(),
+        Term::BlankNode(node) => node.clone().into(),
+        _ => unreachable!("class expressions, properties and individuals are never literals"),
+    }
+}
+
 /// Loads an ontology from a JSON-LD file.
 /// 
 /// # Arguments
@@ -102,7 +814,7 @@ pub fn convert_rdf_format<P: AsRef<Path>>(
 /// * `Err(Owl2RsError)` - An error if parsing fails
 pub fn load_ontology_from_jsonld<P: AsRef<Path>>(path: P) -> Result<Ontology, Owl2RsError> {
     // Open the file
-    let file = std::fs::File::open(path).map_err(|e| Owl2RsError::IoError(e))?;
+    let file = std::fs::File::open(path).map_err(Owl2RsError::IoError)?;
     let reader = BufReader::new(file);
     
     // Create a parser for JSON-LD format
@@ -141,7 +853,7 @@ pub fn load_ontology_from_jsonld<P: AsRef<Path>>(path: P) -> Result<Ontology, Ow
 /// * `Err(Owl2RsError)` - An error if parsing fails
 pub fn load_ontology_from_turtle<P: AsRef<Path>>(path: P) -> Result<Ontology, Owl2RsError> {
     // Open the file
-    let file = std::fs::File::open(path).map_err(|e| Owl2RsError::IoError(e))?;
+    let file = std::fs::File::open(path).map_err(Owl2RsError::IoError)?;
     let reader = BufReader::new(file);
     
     // Create a parser for Turtle format
@@ -191,7 +903,11 @@ pub fn load_ontology_from_rdfxml<P: AsRef<Path>>(_path: P) -> Result<Ontology, O
 
 /// Converts RDF quads to an OWL 2 ontology.
 /// 
-/// This function takes RDF quads and converts them to OWL 2 axioms.
+/// This function takes RDF quads and converts them to OWL 2 axioms, following the
+/// OWL 2 mapping from RDF graphs (see [`RdfReader`]). Graph names are ignored.
+/// Triples that do not form a recognised OWL 2 construct are skipped, and so are
+/// datatype definitions (`owl:equivalentClass` on an `rdfs:Datatype`), which the
+/// axiom model cannot represent.
 /// 
 /// # Arguments
 /// 
@@ -201,13 +917,552 @@ pub fn load_ontology_from_rdfxml<P: AsRef<Path>>(_path: P) -> Result<Ontology, O
 /// 
 /// * `Ok(Ontology)` - The constructed ontology
 /// * `Err(Owl2RsError)` - An error if conversion fails
-fn convert_rdf_to_owl2(_quads: Vec<Quad>) -> Result<Ontology, Owl2RsError> {
-    // In a full implementation, we would:
-    // 1. Process the RDF quads
-    // 2. Identify OWL 2 constructs (classes, properties, axioms, etc.)
-    // 3. Convert them to the appropriate OWL 2 data structures
-    // 4. Construct and return an Ontology
-    
-    // For now, we'll create an empty ontology as a placeholder
-    Ok(Ontology::default())
+fn convert_rdf_to_owl2(quads: Vec<Quad>) -> Result<Ontology, Owl2RsError> {
+    let triples = quads.into_iter().map(Triple::from).collect();
+    let reader = RdfReader::new(triples);
+
+    let mut ontology = Ontology::default();
+    if let Some(header) = reader.ontology_header() {
+        if let NamedOrBlankNode::NamedNode(iri) = &header {
+            ontology.ontology_iri = Some(IRI(iri.as_str().to_string()));
+        }
+        ontology.version_iri = reader.object(&header, "versionIRI")
+            .and_then(|term| match term {
+                Term::NamedNode(node) => Some(IRI(node.as_str().to_string())),
+                _ => None,
+            });
+        ontology.direct_imports = reader.objects(&header, OWL, "imports")
+            .filter_map(|term| match term {
+                Term::NamedNode(node) => Some(IRI(node.as_str().to_string())),
+                _ => None,
+            })
+            .collect();
+        ontology.annotations = reader.ontology_annotations(&header);
+    }
+    let mut seen = HashSet::new();
+    for axiom in reader.axioms() {
+        if seen.insert(axiom.clone()) {
+            ontology.axioms.push(axiom);
+        }
+    }
+    Ok(ontology)
+}
+
+/// Reads OWL 2 axioms from RDF triples, following the OWL 2 mapping from RDF graphs.
+///
+/// Blank nodes are read as the class expressions, data ranges, property expressions
+/// and lists they describe. Whether a property is an object, data or annotation
+/// property is taken from its declaration; an undeclared property is read as a data
+/// property when its value is a literal and as an object property otherwise.
+/// A blank node typed with a class, or the subject of a property assertion, is read
+/// as an anonymous individual.
+struct RdfReader {
+    triples: Vec<Triple>,
+    /// The indices in `triples` of the triples about each subject
+    by_subject: HashMap<NamedOrBlankNode, Vec<usize>>,
+    data_properties: HashSet<NamedNode>,
+    annotation_properties: HashSet<NamedNode>,
+    datatypes: HashSet<NamedNode>,
+}
+
+impl RdfReader {
+    fn new(triples: Vec<Triple>) -> Self {
+        let mut by_subject: HashMap<NamedOrBlankNode, Vec<usize>> = HashMap::new();
+        let (mut data_properties, mut annotation_properties, mut datatypes) = (HashSet::new(), HashSet::new(), HashSet::new());
+        for (index, triple) in triples.iter().enumerate() {
+            by_subject.entry(triple.subject.clone()).or_default().push(index);
+            if let (NamedOrBlankNode::NamedNode(subject), Term::NamedNode(kind)) = (&triple.subject, &triple.object)
+                && triple.predicate == rdf("type") {
+                if *kind == owl("DatatypeProperty") {
+                    data_properties.insert(subject.clone());
+                } else if *kind == owl("AnnotationProperty") {
+                    annotation_properties.insert(subject.clone());
+                } else if *kind == rdfs("Datatype") {
+                    datatypes.insert(subject.clone());
+                }
+            }
+        }
+        for builtin in ["label", "comment", "seeAlso", "isDefinedBy"] {
+            annotation_properties.insert(rdfs(builtin));
+        }
+        for builtin in ["versionInfo", "deprecated", "priorVersion", "backwardCompatibleWith", "incompatibleWith"] {
+            annotation_properties.insert(owl(builtin));
+        }
+        RdfReader { triples, by_subject, data_properties, annotation_properties, datatypes }
+    }
+
+    /// Returns the values of `namespace + name` for `subject`.
+    fn objects<'a>(&'a self, subject: &NamedOrBlankNode, namespace: &str, name: &str) -> impl Iterator<Item = &'a Term> + use<'a> {
+        let predicate = format!("{}{}", namespace, name);
+        self.by_subject.get(subject).into_iter().flatten()
+            .map(|&index| &self.triples[index])
+            .filter(move |triple| triple.predicate.as_str() == predicate)
+            .map(|triple| &triple.object)
+    }
+
+    /// Returns the first value of the OWL property `name` for `subject`.
+    fn object(&self, subject: &NamedOrBlankNode, name: &str) -> Option<&Term> {
+        self.objects(subject, OWL, name).next()
+    }
+
+    /// Returns the node typed `owl:Ontology`: the ontology's IRI, or a blank node for
+    /// an ontology without one.
+    fn ontology_header(&self) -> Option<NamedOrBlankNode> {
+        self.triples.iter().find_map(|triple| match &triple.object {
+            Term::NamedNode(kind) if triple.predicate == rdf("type") && *kind == owl("Ontology") => {
+                Some(triple.subject.clone())
+            }
+            _ => None,
+        })
+    }
+
+    /// Reads the items of the RDF list starting at `head`, or `None` if it is malformed.
+    fn list(&self, head: &Term) -> Option<Vec<Term>> {
+        let mut items = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = head.clone();
+        loop {
+            let cell = match &current {
+                Term::NamedNode(node) if *node == rdf("nil") => return Some(items),
+                Term::BlankNode(node) if visited.insert(node.clone()) => NamedOrBlankNode::from(node.clone()),
+                _ => return None,
+            };
+            items.push(self.objects(&cell, RDF, "first").next()?.clone());
+            current = self.objects(&cell, RDF, "rest").next()?.clone();
+        }
+    }
+
+    fn is_data_property(&self, term: &Term) -> bool {
+        matches!(term, Term::NamedNode(node) if self.data_properties.contains(node))
+    }
+
+    /// Checks whether a range term denotes a data range rather than a class.
+    fn is_data_range(&self, term: &Term) -> bool {
+        match term {
+            Term::NamedNode(node) => {
+                self.datatypes.contains(node)
+                    || node.as_str().starts_with(XSD)
+                    || node.as_str().starts_with(RDF)
+                    || [rdfs("Literal"), owl("real"), owl("rational")].contains(node)
+            }
+            Term::BlankNode(node) => self.objects(&node.clone().into(), RDF, "type").any(|kind| *kind == Term::from(rdfs("Datatype"))),
+            _ => false,
+        }
+    }
+
+    fn iri(term: &Term) -> Option<IRI> {
+        match term {
+            Term::NamedNode(node) => Some(IRI(node.as_str().to_string())),
+            _ => None,
+        }
+    }
+
+    fn individual(term: &Term) -> Option<Individual> {
+        match term {
+            Term::NamedNode(node) => Some(Individual::Named(IRI(node.as_str().to_string()))),
+            Term::BlankNode(node) => Some(Individual::Anonymous(NodeID(format!("_:{}", node.as_str())))),
+            _ => None,
+        }
+    }
+
+    fn literal(term: &Term) -> Option<Literal> {
+        let Term::Literal(literal) = term else {
+            return None;
+        };
+        Some(Literal {
+            value: literal.value().to_string(),
+            datatype: Datatype(IRI(literal.datatype().as_str().to_string())),
+            lang: literal.language().map(str::to_string),
+        })
+    }
+
+    fn cardinality(term: &Term) -> Option<u32> {
+        Self::literal(term)?.value.trim().parse().ok()
+    }
+
+    fn property(&self, term: &Term) -> Option<ObjectPropertyExpression> {
+        match term {
+            Term::NamedNode(node) => Some(ObjectPropertyExpression::ObjectProperty(ObjectProperty(IRI(node.as_str().to_string())))),
+            Term::BlankNode(node) => match self.object(&node.clone().into(), "inverseOf")? {
+                Term::NamedNode(inner) => Some(ObjectPropertyExpression::InverseObjectProperty(ObjectProperty(IRI(inner.as_str().to_string())))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn properties(&self, head: &Term) -> Option<Vec<ObjectPropertyExpression>> {
+        self.list(head)?.iter().map(|item| self.property(item)).collect()
+    }
+
+    fn class_expressions(&self, head: &Term) -> Option<Vec<ClassExpression>> {
+        self.list(head)?.iter().map(|item| self.class_expression(item)).collect()
+    }
+
+    fn class_expression(&self, term: &Term) -> Option<ClassExpression> {
+        let node: NamedOrBlankNode = match term {
+            Term::NamedNode(node) => return Some(ClassExpression::Class(Class(IRI(node.as_str().to_string())))),
+            Term::BlankNode(node) => node.clone().into(),
+            _ => return None,
+        };
+        if let Some(list) = self.object(&node, "intersectionOf") {
+            return self.class_expressions(list).map(ClassExpression::ObjectIntersectionOf);
+        }
+        if let Some(list) = self.object(&node, "unionOf") {
+            return self.class_expressions(list).map(ClassExpression::ObjectUnionOf);
+        }
+        if let Some(inner) = self.object(&node, "complementOf") {
+            return self.class_expression(inner).map(|inner| ClassExpression::ObjectComplementOf(Box::new(inner)));
+        }
+        if let Some(list) = self.object(&node, "oneOf") {
+            return self.list(list)?.iter().map(Self::individual).collect::<Option<Vec<_>>>().map(ClassExpression::ObjectOneOf);
+        }
+
+        let on_property = self.object(&node, "onProperty")?;
+        if self.is_data_property(on_property) {
+            return self.data_restriction(&node, DataProperty(Self::iri(on_property)?));
+        }
+        let property = self.property(on_property)?;
+        let filler = |name: &str| -> Option<Option<Box<ClassExpression>>> {
+            match self.object(&node, name) {
+                Some(filler) => Some(Some(Box::new(self.class_expression(filler)?))),
+                None => Some(None),
+            }
+        };
+        if let Some(filler) = self.object(&node, "someValuesFrom") {
+            return Some(ClassExpression::ObjectSomeValuesFrom { property, filler: Box::new(self.class_expression(filler)?) });
+        }
+        if let Some(filler) = self.object(&node, "allValuesFrom") {
+            return Some(ClassExpression::ObjectAllValuesFrom { property, filler: Box::new(self.class_expression(filler)?) });
+        }
+        if let Some(value) = self.object(&node, "hasValue") {
+            return Some(ClassExpression::ObjectHasValue { property, value: Self::individual(value)? });
+        }
+        if self.object(&node, "hasSelf").is_some() {
+            return Some(ClassExpression::ObjectHasSelf(property));
+        }
+        for (qualified, unqualified) in [("minQualifiedCardinality", "minCardinality"), ("maxQualifiedCardinality", "maxCardinality"), ("qualifiedCardinality", "cardinality")] {
+            let (n, filler) = match (self.object(&node, qualified), self.object(&node, unqualified)) {
+                (Some(n), _) => (Self::cardinality(n)?, filler("onClass")?),
+                (None, Some(n)) => (Self::cardinality(n)?, None),
+                (None, None) => continue,
+            };
+            return Some(match unqualified {
+                "minCardinality" => ClassExpression::ObjectMinCardinality { min: n, property, filler },
+                "maxCardinality" => ClassExpression::ObjectMaxCardinality { max: n, property, filler },
+                _ => ClassExpression::ObjectExactCardinality { cardinality: n, property, filler },
+            });
+        }
+        None
+    }
+
+    fn data_restriction(&self, node: &NamedOrBlankNode, property: DataProperty) -> Option<ClassExpression> {
+        if let Some(filler) = self.object(node, "someValuesFrom") {
+            return Some(ClassExpression::DataSomeValuesFrom { property, filler: self.data_range(filler)? });
+        }
+        if let Some(filler) = self.object(node, "allValuesFrom") {
+            return Some(ClassExpression::DataAllValuesFrom { property, filler: self.data_range(filler)? });
+        }
+        if let Some(value) = self.object(node, "hasValue") {
+            return Some(ClassExpression::DataHasValue { property, value: Self::literal(value)? });
+        }
+        for (qualified, unqualified) in [("minQualifiedCardinality", "minCardinality"), ("maxQualifiedCardinality", "maxCardinality"), ("qualifiedCardinality", "cardinality")] {
+            let (n, filler) = match (self.object(node, qualified), self.object(node, unqualified)) {
+                (Some(n), _) => (Self::cardinality(n)?, Some(self.data_range(self.object(node, "onDataRange")?)?)),
+                (None, Some(n)) => (Self::cardinality(n)?, None),
+                (None, None) => continue,
+            };
+            return Some(match unqualified {
+                "minCardinality" => ClassExpression::DataMinCardinality { min: n, property, filler },
+                "maxCardinality" => ClassExpression::DataMaxCardinality { max: n, property, filler },
+                _ => ClassExpression::DataExactCardinality { cardinality: n, property, filler },
+            });
+        }
+        None
+    }
+
+    fn data_range(&self, term: &Term) -> Option<DataRange> {
+        let node: NamedOrBlankNode = match term {
+            Term::NamedNode(node) => return Some(DataRange::Datatype(Datatype(IRI(node.as_str().to_string())))),
+            Term::BlankNode(node) => node.clone().into(),
+            _ => return None,
+        };
+        let ranges = |list: &Term| -> Option<Vec<DataRange>> { self.list(list)?.iter().map(|item| self.data_range(item)).collect() };
+        if let Some(list) = self.object(&node, "intersectionOf") {
+            return ranges(list).map(DataRange::DataIntersectionOf);
+        }
+        if let Some(list) = self.object(&node, "unionOf") {
+            return ranges(list).map(DataRange::DataUnionOf);
+        }
+        if let Some(inner) = self.object(&node, "datatypeComplementOf") {
+            return self.data_range(inner).map(|inner| DataRange::DataComplementOf(Box::new(inner)));
+        }
+        if let Some(list) = self.object(&node, "oneOf") {
+            return self.list(list)?.iter().map(Self::literal).collect::<Option<Vec<_>>>().map(DataRange::DataOneOf);
+        }
+        let datatype = Datatype(Self::iri(self.object(&node, "onDatatype")?)?);
+        let mut restrictions = Vec::new();
+        for facet in self.list(self.object(&node, "withRestrictions")?)? {
+            let Term::BlankNode(facet) = facet else {
+                return None;
+            };
+            let triple = &self.triples[*self.by_subject.get(&facet.into())?.first()?];
+            restrictions.push((IRI(triple.predicate.as_str().to_string()), Self::literal(&triple.object)?));
+        }
+        Some(DataRange::DatatypeRestriction { datatype, restrictions })
+    }
+
+    /// Reads the axioms of the graph, in the order of the triples that state them.
+    fn axioms(&self) -> Vec<Axiom> {
+        let header = self.ontology_header();
+        self.triples.iter()
+            .filter(|triple| Some(&triple.subject) != header.as_ref())
+            .filter_map(|triple| self.axiom(triple))
+            .collect()
+    }
+
+    /// Reads the axiom stated by `triple`, if it states one on its own or as the main
+    /// triple of a blank-node structure.
+    fn axiom(&self, triple: &Triple) -> Option<Axiom> {
+        let subject: Term = triple.subject.clone().into();
+        let object = &triple.object;
+        let data = self.is_data_property(&subject) || self.is_data_property(object);
+        if let Some(name) = triple.predicate.as_str().strip_prefix(RDFS) {
+            return match name {
+                "subPropertyOf" | "domain" | "range" if self.is_annotation_property(&subject) => None,
+                "subClassOf" => Some(Axiom::Class(ClassAxiom::SubClassOf {
+                    sub_class: self.class_expression(&subject)?,
+                    super_class: self.class_expression(object)?,
+                })),
+                "subPropertyOf" if data => Some(Axiom::DataProperty(DataPropertyAxiom::SubDataPropertyOf {
+                    sub_property: DataProperty(Self::iri(&subject)?),
+                    super_property: DataProperty(Self::iri(object)?),
+                })),
+                "subPropertyOf" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::SubObjectPropertyOf {
+                    sub_property: self.property(&subject)?,
+                    super_property: self.property(object)?,
+                })),
+                "domain" if data => Some(Axiom::DataProperty(DataPropertyAxiom::DataPropertyDomain {
+                    property: DataProperty(Self::iri(&subject)?),
+                    domain: self.class_expression(object)?,
+                })),
+                "domain" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyDomain {
+                    property: self.property(&subject)?,
+                    domain: self.class_expression(object)?,
+                })),
+                "range" if data || self.is_data_range(object) => Some(Axiom::DataProperty(DataPropertyAxiom::DataPropertyRange {
+                    property: DataProperty(Self::iri(&subject)?),
+                    range: self.data_range(object)?,
+                })),
+                "range" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::ObjectPropertyRange {
+                    property: self.property(&subject)?,
+                    range: self.class_expression(object)?,
+                })),
+                _ => self.assertion(triple),
+            };
+        }
+        if triple.predicate == rdf("type") {
+            return self.typing(triple);
+        }
+        let Some(name) = triple.predicate.as_str().strip_prefix(OWL) else {
+            return self.assertion(triple);
+        };
+        match name {
+            // A datatype definition; there is no axiom to read it into
+            "equivalentClass" if self.is_data_range(&subject) => None,
+            "equivalentClass" => Some(Axiom::Class(ClassAxiom::EquivalentClasses {
+                classes: vec![self.class_expression(&subject)?, self.class_expression(object)?],
+            })),
+            "disjointWith" => Some(Axiom::Class(ClassAxiom::DisjointClasses {
+                classes: vec![self.class_expression(&subject)?, self.class_expression(object)?],
+            })),
+            "disjointUnionOf" => Some(Axiom::Class(ClassAxiom::DisjointUnion {
+                class: Class(Self::iri(&subject)?),
+                disjoint_classes: self.class_expressions(object)?,
+            })),
+            "propertyChainAxiom" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::SubObjectPropertyOf {
+                sub_property: ObjectPropertyExpression::ObjectPropertyChain(self.properties(object)?),
+                super_property: self.property(&subject)?,
+            })),
+            "equivalentProperty" if data => Some(Axiom::DataProperty(DataPropertyAxiom::EquivalentDataProperties {
+                properties: vec![DataProperty(Self::iri(&subject)?), DataProperty(Self::iri(object)?)],
+            })),
+            "equivalentProperty" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::EquivalentObjectProperties {
+                properties: vec![self.property(&subject)?, self.property(object)?],
+            })),
+            "propertyDisjointWith" if data => Some(Axiom::DataProperty(DataPropertyAxiom::DisjointDataProperties {
+                properties: vec![DataProperty(Self::iri(&subject)?), DataProperty(Self::iri(object)?)],
+            })),
+            "propertyDisjointWith" => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::DisjointObjectProperties {
+                properties: vec![self.property(&subject)?, self.property(object)?],
+            })),
+            // On a blank node, owl:inverseOf is an inverse property expression
+            "inverseOf" if matches!(subject, Term::NamedNode(_)) => Some(Axiom::ObjectProperty(ObjectPropertyAxiom::InverseObjectProperties {
+                prop1: self.property(&subject)?,
+                prop2: self.property(object)?,
+            })),
+            "hasKey" => {
+                let (data_keys, object_keys): (Vec<Term>, Vec<Term>) = self.list(object)?.into_iter().partition(|key| self.is_data_property(key));
+                Some(Axiom::Assertion(Assertion::HasKey {
+                    class: Class(Self::iri(&subject)?),
+                    object_property_expression: object_keys.iter().map(|key| self.property(key)).collect::<Option<_>>()?,
+                    data_property: data_keys.iter().map(|key| Self::iri(key).map(DataProperty)).collect::<Option<_>>()?,
+                }))
+            }
+            "sameAs" => Some(Axiom::Assertion(Assertion::SameIndividual {
+                individuals: vec![Self::individual(&subject)?, Self::individual(object)?],
+            })),
+            "differentFrom" => Some(Axiom::Assertion(Assertion::DifferentIndividuals {
+                individuals: vec![Self::individual(&subject)?, Self::individual(object)?],
+            })),
+            _ => self.assertion(triple),
+        }
+    }
+
+    fn is_annotation_property(&self, term: &Term) -> bool {
+        matches!(term, Term::NamedNode(node) if self.annotation_properties.contains(node))
+    }
+
+    /// Reads an `rdf:type` triple: a declaration, a property characteristic, an
+    /// n-ary axiom on a blank node, or a class assertion.
+    fn typing(&self, triple: &Triple) -> Option<Axiom> {
+        let subject: Term = triple.subject.clone().into();
+        let node = &triple.subject;
+        let kind = match &triple.object {
+            Term::NamedNode(kind) => kind.as_str(),
+            Term::BlankNode(_) if matches!(subject, Term::NamedNode(_)) => {
+                return Some(Axiom::Assertion(Assertion::ClassAssertion {
+                    class: self.class_expression(&triple.object)?,
+                    individual: Self::individual(&subject)?,
+                }));
+            }
+            _ => return None,
+        };
+        let characteristic = |make: fn(ObjectPropertyExpression) -> ObjectPropertyAxiom| {
+            Some(Axiom::ObjectProperty(make(self.property(&subject)?)))
+        };
+        let declaration = |make: fn(IRI) -> Entity| Some(Axiom::Declaration(make(Self::iri(&subject)?)));
+        match kind.strip_prefix(OWL) {
+            Some("Class") => declaration(|iri| Entity::Class(Class(iri))),
+            Some("ObjectProperty") => declaration(|iri| Entity::ObjectProperty(ObjectProperty(iri))),
+            Some("DatatypeProperty") => declaration(|iri| Entity::DataProperty(DataProperty(iri))),
+            Some("AnnotationProperty") => declaration(Entity::AnnotationProperty),
+            Some("NamedIndividual") => declaration(Entity::NamedIndividual),
+            Some("FunctionalProperty") if self.is_data_property(&subject) => {
+                Some(Axiom::DataProperty(DataPropertyAxiom::FunctionalDataProperty { property: DataProperty(Self::iri(&subject)?) }))
+            }
+            Some("FunctionalProperty") => characteristic(|property| ObjectPropertyAxiom::FunctionalObjectProperty { property }),
+            Some("InverseFunctionalProperty") => characteristic(|property| ObjectPropertyAxiom::InverseFunctionalObjectProperty { property }),
+            Some("ReflexiveProperty") => characteristic(|property| ObjectPropertyAxiom::ReflexiveObjectProperty { property }),
+            Some("IrreflexiveProperty") => characteristic(|property| ObjectPropertyAxiom::IrreflexiveObjectProperty { property }),
+            Some("SymmetricProperty") => characteristic(|property| ObjectPropertyAxiom::SymmetricObjectProperty { property }),
+            Some("AsymmetricProperty") => characteristic(|property| ObjectPropertyAxiom::AsymmetricObjectProperty { property }),
+            Some("TransitiveProperty") => characteristic(|property| ObjectPropertyAxiom::TransitiveObjectProperty { property }),
+            Some("AllDisjointClasses") => Some(Axiom::Class(ClassAxiom::DisjointClasses {
+                classes: self.class_expressions(self.object(node, "members")?)?,
+            })),
+            Some("AllDisjointProperties") => {
+                let members = self.list(self.object(node, "members")?)?;
+                if members.first().is_some_and(|member| self.is_data_property(member)) {
+                    Some(Axiom::DataProperty(DataPropertyAxiom::DisjointDataProperties {
+                        properties: members.iter().map(|member| Self::iri(member).map(DataProperty)).collect::<Option<_>>()?,
+                    }))
+                } else {
+                    Some(Axiom::ObjectProperty(ObjectPropertyAxiom::DisjointObjectProperties {
+                        properties: members.iter().map(|member| self.property(member)).collect::<Option<_>>()?,
+                    }))
+                }
+            }
+            Some("AllDifferent") => {
+                let members = self.object(node, "members").or_else(|| self.object(node, "distinctMembers"))?;
+                Some(Axiom::Assertion(Assertion::DifferentIndividuals {
+                    individuals: self.list(members)?.iter().map(Self::individual).collect::<Option<_>>()?,
+                }))
+            }
+            Some("NegativePropertyAssertion") => {
+                let source = Self::individual(self.object(node, "sourceIndividual")?)?;
+                let property = self.object(node, "assertionProperty")?;
+                if let Some(target) = self.object(node, "targetValue") {
+                    Some(Axiom::Assertion(Assertion::NegativeDataPropertyAssertion {
+                        property: DataProperty(Self::iri(property)?),
+                        source,
+                        target: Self::literal(target)?,
+                    }))
+                } else {
+                    Some(Axiom::Assertion(Assertion::NegativeObjectPropertyAssertion {
+                        property: self.property(property)?,
+                        source,
+                        target: Self::individual(self.object(node, "targetIndividual")?)?,
+                    }))
+                }
+            }
+            Some("Thing" | "Nothing") => self.class_assertion(triple),
+            Some(_) => None,
+            None if kind == rdfs("Datatype").as_str() => declaration(|iri| Entity::Datatype(Datatype(iri))),
+            None if kind.starts_with(RDF) || kind.starts_with(RDFS) => None,
+            None => self.class_assertion(triple),
+        }
+    }
+
+    fn class_assertion(&self, triple: &Triple) -> Option<Axiom> {
+        Some(Axiom::Assertion(Assertion::ClassAssertion {
+            class: self.class_expression(&triple.object)?,
+            individual: Self::individual(&triple.subject.clone().into())?,
+        }))
+    }
+
+    /// Reads the annotations on the ontology header, in the order of their triples.
+    ///
+    /// Any property outside the RDF, RDFS and OWL vocabularies annotates the
+    /// ontology, whether or not it is declared as an annotation property.
+    fn ontology_annotations(&self, header: &NamedOrBlankNode) -> Vec<Annotation> {
+        self.triples.iter()
+            .filter(|triple| &triple.subject == header)
+            .filter(|triple| {
+                self.annotation_properties.contains(&triple.predicate)
+                    || ![RDF, RDFS, OWL].iter().any(|namespace| triple.predicate.as_str().starts_with(namespace))
+            })
+            .filter_map(|triple| Some(Annotation {
+                property: IRI(triple.predicate.as_str().to_string()),
+                value: Self::annotation_value(&triple.object)?,
+            }))
+            .collect()
+    }
+
+    fn annotation_value(term: &Term) -> Option<AnnotationValue> {
+        Some(match term {
+            Term::NamedNode(node) => AnnotationValue::IRI(IRI(node.as_str().to_string())),
+            Term::BlankNode(node) => AnnotationValue::AnonymousIndividual(NodeID(format!("_:{}", node.as_str()))),
+            literal => AnnotationValue::Literal(Self::literal(literal)?),
+        })
+    }
+
+    /// Reads a triple on a property outside the RDF, RDFS and OWL vocabularies (apart
+    /// from the built-in annotation properties) as a property assertion, or as an
+    /// annotation assertion about a named subject.
+    fn assertion(&self, triple: &Triple) -> Option<Axiom> {
+        let property = IRI(triple.predicate.as_str().to_string());
+        if self.annotation_properties.contains(&triple.predicate) {
+            let NamedOrBlankNode::NamedNode(subject) = &triple.subject else {
+                return None;
+            };
+            return Some(Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+                property,
+                subject: IRI(subject.as_str().to_string()),
+                value: Self::annotation_value(&triple.object)?,
+            }));
+        }
+        if [RDF, RDFS, OWL].iter().any(|namespace| property.0.starts_with(namespace)) {
+            return None;
+        }
+        let source = Self::individual(&triple.subject.clone().into())?;
+        Some(Axiom::Assertion(match &triple.object {
+            Term::Literal(_) => Assertion::DataPropertyAssertion { property: DataProperty(property), source, target: Self::literal(&triple.object)? },
+            target => Assertion::ObjectPropertyAssertion {
+                property: ObjectPropertyExpression::ObjectProperty(ObjectProperty(property)),
+                source,
+                target: Self::individual(target)?,
+            },
+        }))
+    }
 }
\ No newline at end of file
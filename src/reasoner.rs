@@ -3,10 +3,74 @@
 //! This module implements a tableau-based reasoner for OWL 2 ontologies.
 //! The reasoner can check consistency, classify classes, and realize individuals.
 
-use crate::{Class, ClassExpression, Individual, ObjectPropertyExpression, Ontology};
-use std::collections::HashMap;
+use crate::datatypes::{is_empty_data_range, literal_in_range, literals_value_equal, Membership};
+use crate::{AnnotationAxiom, Axiom, Class, ClassExpression, DataProperty, DataRange, Individual, IriNormalizer, Literal, ObjectProperty, ObjectPropertyExpression, Ontology};
+use crate::incremental::ReasoningResults;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
 use rayon::prelude::*;
 
+/// A hook that translates an annotation axiom into a logical axiom, or returns `None`
+/// to leave it alone.
+pub type AnnotationHandler = Box<dyn Fn(&AnnotationAxiom) -> Option<Axiom> + Send + Sync>;
+
+/// Configuration options for the tableau reasoner.
+#[derive(Default)]
+pub struct ReasonerConfig {
+    /// Handlers run over every annotation axiom before reasoning.
+    ///
+    /// Annotations normally have no effect on reasoning. Axioms returned by a handler
+    /// are added to the ontology, which makes it possible to reason over legacy
+    /// ontologies that encode semantics in annotations.
+    pub annotation_handlers: Vec<AnnotationHandler>,
+    /// Decides which IRIs are the same. The reasoner rewrites every IRI of its copy
+    /// of the ontology with it before reasoning, and every IRI passed to a query, so
+    /// queries may use any form the normalizer maps to the same IRI.
+    pub iri_normalizer: Option<Box<dyn IriNormalizer>>,
+    /// The most disjuncts a consistency check may retract before giving up with
+    /// [`ConsistencyResult::Unknown`]. `None`, the default, puts no limit on the
+    /// search. The scratch reasoners behind entailment queries share the limit, and a
+    /// query whose refutation gives up is not entailed.
+    pub max_backtracks: Option<usize>,
+}
+
+impl fmt::Debug for ReasonerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReasonerConfig")
+            .field("annotation_handlers", &self.annotation_handlers.len())
+            .field("iri_normalizer", &self.iri_normalizer.is_some())
+            .field("max_backtracks", &self.max_backtracks)
+            .finish()
+    }
+}
+
+/// A tableau expansion rule.
+///
+/// A rule inspects the completion graph and adds whatever the ontology forces: new
+/// concepts for a node, new successors, inequalities, and so on. The reasoner applies
+/// its rules in order, over and over, until a whole round changes nothing, so a rule
+/// only needs to make progress, not reach a fixpoint on its own.
+///
+/// The built-in rules ([`ConjunctionRule`], [`SubsumptionRule`], [`DisjunctionRule`],
+/// [`GciRule`], [`ExistentialRule`], [`UniversalRule`], [`DomainRule`], [`RangeRule`],
+/// [`ExactCardinalityRule`], [`MinCardinalityRule`], [`MaxCardinalityRule`] and
+/// [`HasKeyRule`]) implement this
+/// trait; custom rules can be added with [`TableauReasoner::add_rule`].
+pub trait ExpansionRule: fmt::Debug + Send + Sync {
+    /// Applies the rule once, returning `true` if the graph was changed.
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool;
+
+    /// Returns `true` if the rule commits to one of several possible choices, as the
+    /// disjunction rule does.
+    ///
+    /// Consistency checking skips such rules and instead branches over every disjunct
+    /// of an `ObjectUnionOf`, backtracking when a branch clashes.
+    fn is_nondeterministic(&self) -> bool {
+        false
+    }
+}
+
 /// Represents a node in the completion graph of the tableau algorithm.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Node {
@@ -18,28 +82,143 @@ pub struct Node {
     pub roles: Vec<(ObjectPropertyExpression, Individual)>,
 }
 
+/// An anonymous successor created by the existential rule that other nodes may share.
+///
+/// A witness for `ObjectSomeValuesFrom(property, filler)` is only reused by a source
+/// whose universal restrictions are exactly `constraints` when the witness is shared.
+/// Sources can still gain different restrictions later, so a clash-free graph with
+/// shared witnesses is a model, but a clash in one is not proof of inconsistency:
+/// [`TableauReasoner::check_consistency`] then searches again without sharing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Witness {
+    /// The property of the existential restriction
+    pub property: ObjectPropertyExpression,
+    /// The filler of the existential restriction
+    pub filler: ClassExpression,
+    /// The sorted universal restrictions of the source that created the witness
+    pub constraints: Vec<ClassExpression>,
+    /// The witness individual
+    pub individual: Individual,
+}
+
 /// Represents the completion graph in the tableau algorithm.
+///
+/// Nodes are stored in creation order and are never reordered or removed.
+/// The expansion rules visit them in that order, so for a fixed ontology the
+/// fresh individuals (`_:fresh{n}` by default) are created and numbered
+/// identically on every run.
+///
+/// Individuals are compared as given. [`TableauReasoner`] normalizes the named
+/// individuals of its ontology and of its queries before they reach the graph, so
+/// different encodings of the same IRI resolve to a single node.
 #[derive(Debug, Clone)]
 pub struct CompletionGraph {
-    /// The nodes in the graph
+    /// The nodes in the graph, in creation order
     pub nodes: Vec<Node>,
     /// The next unique identifier for creating fresh individuals
     pub next_fresh_id: u32,
+    /// The value `next_fresh_id` starts from and is reset to
+    pub fresh_id_seed: u32,
+    /// The prefix of fresh individual node IDs, followed by their number
+    pub fresh_prefix: String,
+    /// Pairs of individuals known to be different
+    pub inequalities: Vec<(Individual, Individual)>,
+    /// Pairs of individuals whose nodes were merged because they are the same
+    pub identities: Vec<(Individual, Individual)>,
+    /// Existential successors that can be shared between sources
+    pub witnesses: Vec<Witness>,
+    /// Whether the existential rule may reuse a witness of another source
+    pub share_witnesses: bool,
+    /// Maps object property expressions to the equivalent expression their edges are
+    /// stored under; an expression without an entry is its own representative
+    pub role_representatives: HashMap<ObjectPropertyExpression, ObjectPropertyExpression>,
+    /// The edges into each individual, as the property they are stored under and the
+    /// index of their source node, kept in step with the nodes' `roles`
+    incoming: HashMap<Individual, Vec<(ObjectPropertyExpression, usize)>>,
+    /// The individuals known to be different from each individual, kept in step with
+    /// `inequalities`
+    distinct: HashMap<Individual, HashSet<Individual>>,
+    /// The ontology's told class inclusions, indexed by [`TableauReasoner::initialize`]
+    /// so [`SubsumptionRule`] and [`GciRule`] need not collect them on every application
+    inclusions: Option<Arc<ToldInclusions>>,
+}
+
+impl Default for CompletionGraph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CompletionGraph {
+    /// The prefix of fresh individual node IDs unless configured otherwise.
+    pub const DEFAULT_FRESH_PREFIX: &'static str = "_:fresh";
+
     /// Creates a new empty completion graph.
     pub fn new() -> Self {
         CompletionGraph {
             nodes: Vec::new(),
             next_fresh_id: 0,
+            fresh_id_seed: 0,
+            fresh_prefix: Self::DEFAULT_FRESH_PREFIX.to_string(),
+            inequalities: Vec::new(),
+            identities: Vec::new(),
+            witnesses: Vec::new(),
+            share_witnesses: true,
+            role_representatives: HashMap::new(),
+            incoming: HashMap::new(),
+            distinct: HashMap::new(),
+            inclusions: None,
+        }
+    }
+
+    /// Creates a new empty completion graph whose fresh individuals are numbered
+    /// starting after `seed` (the first one is `_:fresh{seed + 1}`).
+    pub fn with_fresh_id_seed(seed: u32) -> Self {
+        CompletionGraph {
+            nodes: Vec::new(),
+            next_fresh_id: seed,
+            fresh_id_seed: seed,
+            fresh_prefix: Self::DEFAULT_FRESH_PREFIX.to_string(),
+            inequalities: Vec::new(),
+            identities: Vec::new(),
+            witnesses: Vec::new(),
+            share_witnesses: true,
+            role_representatives: HashMap::new(),
+            incoming: HashMap::new(),
+            distinct: HashMap::new(),
+            inclusions: None,
+        }
+    }
+
+    /// Creates a new empty completion graph whose fresh individuals are named
+    /// `{prefix}{n}` instead of `_:fresh{n}`, e.g. `_:witness1` for the prefix `_:witness`.
+    pub fn with_fresh_prefix(prefix: &str) -> Self {
+        CompletionGraph {
+            fresh_prefix: prefix.to_string(),
+            ..Self::new()
         }
     }
 
+    /// Resets fresh individual numbering back to the configured seed.
+    pub fn reset_fresh_ids(&mut self) {
+        self.next_fresh_id = self.fresh_id_seed;
+    }
+
+    /// Removes all nodes and resets fresh individual numbering.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.incoming.clear();
+        self.inequalities.clear();
+        self.distinct.clear();
+        self.identities.clear();
+        self.witnesses.clear();
+        self.reset_fresh_ids();
+    }
+
     /// Adds a new node to the graph representing an individual.
     pub fn add_node(&mut self, individual: Individual) -> &mut Node {
         self.nodes.push(Node {
-            individual: individual.clone(),
+            individual,
             concepts: Vec::new(),
             roles: Vec::new(),
         });
@@ -63,29 +242,268 @@ impl CompletionGraph {
         }
     }
 
-    /// Adds a role assertion to the graph.
+    /// Adds a role assertion to the graph, stored under the representative of its
+    /// property. Nothing is added if the graph already holds the edge, possibly as
+    /// its mirror on the inverse property (see [`role_targets`](Self::role_targets)).
     pub fn add_role(&mut self, source: &Individual, role: ObjectPropertyExpression, target: Individual) {
-        let node = self.get_or_create_node(source);
-        let role_assertion = (role, target.clone());
-        if !node.roles.contains(&role_assertion) {
-            node.roles.push(role_assertion);
+        let source_index = match self.nodes.iter().position(|n| &n.individual == source) {
+            Some(index) => index,
+            None => {
+                self.add_node(source.clone());
+                self.nodes.len() - 1
+            }
+        };
+        if !self.role_targets(source_index, &role).contains(&target) {
+            self.push_role(source_index, &role, target);
+        }
+    }
+
+    /// Appends an edge to a node under the representative of its property, without
+    /// checking for duplicates.
+    fn push_role(&mut self, node_index: usize, property: &ObjectPropertyExpression, target: Individual) {
+        let property = self.representative(property);
+        self.incoming.entry(target.clone()).or_default().push((property.clone(), node_index));
+        self.nodes[node_index].roles.push((property, target));
+    }
+
+    /// Returns the expression that edges on `property` are stored under.
+    pub fn representative(&self, property: &ObjectPropertyExpression) -> ObjectPropertyExpression {
+        self.role_representatives.get(property).cloned().unwrap_or_else(|| property.clone())
+    }
+
+    /// Returns the R-successors of a node, without duplicates. When the node is a
+    /// named individual this includes the named individuals holding an edge on the
+    /// inverse of R back to it, since only one of two such mirrored edges is stored.
+    pub fn role_targets(&self, node_index: usize, property: &ObjectPropertyExpression) -> Vec<Individual> {
+        let property = self.representative(property);
+        let node = &self.nodes[node_index];
+        let mut targets: Vec<Individual> = Vec::new();
+        for (p, target) in &node.roles {
+            if *p == property && !targets.contains(target) {
+                targets.push(target.clone());
+            }
+        }
+        if let (Individual::Named(_), Some(inverse)) = (&node.individual, inverse_of(&property)) {
+            let inverse = self.representative(&inverse);
+            for (p, source_index) in self.incoming.get(&node.individual).into_iter().flatten() {
+                let other = &self.nodes[*source_index].individual;
+                if *p == inverse && matches!(other, Individual::Named(_)) && !targets.contains(other) {
+                    targets.push(other.clone());
+                }
+            }
+        }
+        targets
+    }
+
+    /// Rewrites every edge under the representative of its property and drops the
+    /// edges that became duplicates, including one of each pair of mirrored edges
+    /// between named individuals, such as `hasChild(a, b)` and `hasParent(b, a)` for
+    /// inverse properties. Returns the number of edges removed.
+    pub fn normalize_roles(&mut self) -> usize {
+        let before: usize = self.nodes.iter().map(|node| node.roles.len()).sum();
+        let roles: Vec<Vec<(ObjectPropertyExpression, Individual)>> = self.nodes.iter_mut()
+            .map(|node| std::mem::take(&mut node.roles))
+            .collect();
+        self.incoming.clear();
+        for (node_index, node_roles) in roles.into_iter().enumerate() {
+            for (property, target) in node_roles {
+                if !self.role_targets(node_index, &property).contains(&target) {
+                    self.push_role(node_index, &property, target);
+                }
+            }
+        }
+        before - self.nodes.iter().map(|node| node.roles.len()).sum::<usize>()
+    }
+
+    /// Records that two individuals are different.
+    pub fn add_inequality(&mut self, a: &Individual, b: &Individual) {
+        if !self.are_distinct(a, b) {
+            self.inequalities.push((a.clone(), b.clone()));
+            self.distinct.entry(a.clone()).or_default().insert(b.clone());
+            self.distinct.entry(b.clone()).or_default().insert(a.clone());
+        }
+    }
+
+    /// Checks whether two individuals are known to be different.
+    pub fn are_distinct(&self, a: &Individual, b: &Individual) -> bool {
+        self.distinct.get(a).is_some_and(|others| others.contains(b))
+    }
+
+    /// Merges two nodes that denote the same individual: each receives the concepts,
+    /// roles and inequalities of the other. Both nodes are kept, so either name can
+    /// still be queried. The pair is recorded in `identities`, where a merge of two
+    /// individuals known to be different shows up as a clash. Returns `true` if the
+    /// graph changed.
+    pub fn merge_nodes(&mut self, a: usize, b: usize) -> bool {
+        let mut changed = false;
+        let pair = (self.nodes[a].individual.clone(), self.nodes[b].individual.clone());
+        if pair.0 != pair.1 && !self.identities.contains(&pair) {
+            self.identities.push(pair);
+            changed = true;
+        }
+        for (from, to) in [(a, b), (b, a)] {
+            for concept in self.nodes[from].concepts.clone() {
+                if !self.nodes[to].concepts.contains(&concept) {
+                    self.nodes[to].concepts.push(concept);
+                    changed = true;
+                }
+            }
+            for (property, target) in self.nodes[from].roles.clone() {
+                if !self.nodes[to].roles.iter().any(|(p, t)| *p == property && *t == target) {
+                    self.push_role(to, &property, target);
+                    changed = true;
+                }
+            }
+            let (from_individual, to_individual) = (self.nodes[from].individual.clone(), self.nodes[to].individual.clone());
+            let different: Vec<Individual> = self.distinct.get(&from_individual)
+                .map(|others| others.iter().cloned().collect())
+                .unwrap_or_default();
+            for other in different {
+                if other != to_individual && !self.are_distinct(&to_individual, &other) {
+                    self.add_inequality(&to_individual, &other);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Checks whether the graph shows directly that a node is an instance of
+    /// `expression`: the node holds the expression itself, or its structure does (all
+    /// conjuncts, one disjunct, an R-successor in the filler, the R-successor named by
+    /// `ObjectHasValue`, a self loop, or membership in an `ObjectOneOf`).
+    pub fn is_known_instance(&self, node_index: usize, expression: &ClassExpression) -> bool {
+        let node = &self.nodes[node_index];
+        if node.concepts.contains(expression) {
+            return true;
+        }
+        match expression {
+            ClassExpression::Class(class) => class.is_thing(),
+            ClassExpression::ObjectIntersectionOf(operands) => operands.iter().all(|operand| self.is_known_instance(node_index, operand)),
+            ClassExpression::ObjectUnionOf(operands) => operands.iter().any(|operand| self.is_known_instance(node_index, operand)),
+            ClassExpression::ObjectSomeValuesFrom { property, filler } => self.role_targets(node_index, property).iter().any(|target| {
+                self.nodes.iter().position(|n| &n.individual == target)
+                    .is_some_and(|target_index| self.is_known_instance(target_index, filler))
+            }),
+            ClassExpression::ObjectHasValue { property, value } => self.role_targets(node_index, property).contains(value),
+            ClassExpression::ObjectHasSelf(property) => self.role_targets(node_index, property).contains(&node.individual),
+            ClassExpression::ObjectOneOf(individuals) => individuals.contains(&node.individual),
+            _ => false,
+        }
+    }
+
+    /// Returns the R-successors of a node that are instances of `filler`, without
+    /// duplicates. Every successor is an instance of owl:Thing.
+    pub fn successors_in(&self, node_index: usize, property: &ObjectPropertyExpression, filler: &ClassExpression) -> Vec<Individual> {
+        let is_thing = matches!(filler, ClassExpression::Class(class) if class.is_thing());
+        self.role_targets(node_index, property).into_iter()
+            .filter(|target| is_thing || self.nodes.iter().any(|n| &n.individual == target && n.concepts.contains(filler)))
+            .collect()
+    }
+
+    /// Returns the told class inclusions of `ontology`, taking the index built by
+    /// [`TableauReasoner::initialize`] if there is one.
+    fn told_inclusions(&self, ontology: &Ontology) -> Arc<ToldInclusions> {
+        self.inclusions.clone().unwrap_or_else(|| Arc::new(ToldInclusions::new(ontology)))
+    }
+
+    /// Checks whether some witness is the successor of more than one node.
+    pub fn has_shared_witness(&self) -> bool {
+        self.witnesses.iter().any(|witness| {
+            self.nodes.iter()
+                .filter(|node| node.roles.iter().any(|(_, target)| *target == witness.individual))
+                .nth(1)
+                .is_some()
+        })
+    }
+
+    /// Checks whether a node is blocked by subset blocking: it is anonymous and one of
+    /// its anonymous tree ancestors holds every concept the node holds. A blocked node
+    /// needs no successors of its own, since the model can reuse those of its blocker.
+    ///
+    /// The parent of a node is the source of the first edge into it, which for a
+    /// generated node is the node whose restriction created it. Ancestors are followed
+    /// up to the named individual at the root of the tree, so the cost is linear in
+    /// the depth of the node.
+    ///
+    /// Named individuals are never blocked, and they never block other nodes.
+    pub fn is_blocked(&self, node_index: usize) -> bool {
+        let node = &self.nodes[node_index];
+        if !matches!(node.individual, Individual::Anonymous(_)) {
+            return false;
         }
+
+        let mut visited = HashSet::from([node_index]);
+        let mut current = node_index;
+        while let Some(&(_, parent)) = self.incoming.get(&self.nodes[current].individual).and_then(|edges| edges.first()) {
+            let ancestor = &self.nodes[parent];
+            if !visited.insert(parent) || !matches!(ancestor.individual, Individual::Anonymous(_)) {
+                return false;
+            }
+            if node.concepts.iter().all(|concept| ancestor.concepts.contains(concept)) {
+                return true;
+            }
+            current = parent;
+        }
+        false
     }
 
     /// Generates a fresh individual (used in existential expansion rules).
+    ///
+    /// Numbers already taken by a node in the graph, such as an asserted anonymous
+    /// individual that happens to share the prefix, are skipped.
     pub fn fresh_individual(&mut self) -> Individual {
-        self.next_fresh_id += 1;
-        Individual::Anonymous(crate::NodeID(format!("_:fresh{}", self.next_fresh_id)))
+        loop {
+            self.next_fresh_id += 1;
+            let individual = Individual::Anonymous(crate::NodeID(format!("{}{}", self.fresh_prefix, self.next_fresh_id)));
+            if !self.nodes.iter().any(|node| node.individual == individual) {
+                return individual;
+            }
+        }
+    }
+}
+
+/// Renders a completion graph in Graphviz DOT format.
+///
+/// Each node is labelled with its individual (named individuals by IRI, fresh ones by
+/// node ID) above the concepts it holds, in Functional-Style Syntax. Each role is an
+/// edge labelled with its property. Nodes are numbered in creation order.
+pub fn completion_graph_to_dot(graph: &CompletionGraph) -> String {
+    let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut dot = String::from("digraph completion_graph {\n");
+    for (index, node) in graph.nodes.iter().enumerate() {
+        let individual = match &node.individual {
+            Individual::Named(iri) => format!("<{}>", iri.0),
+            Individual::Anonymous(node_id) => node_id.0.clone(),
+        };
+        let lines: Vec<String> = std::iter::once(individual)
+            .chain(node.concepts.iter().map(crate::parser::fss_class_expression))
+            .map(|line| escape(&line))
+            .collect();
+        dot.push_str(&format!("  n{} [label=\"{}\"];\n", index, lines.join("\\n")));
+    }
+    for (index, node) in graph.nodes.iter().enumerate() {
+        for (property, target) in &node.roles {
+            if let Some(target_index) = graph.nodes.iter().position(|n| &n.individual == target) {
+                let label = crate::parser::fss_object_property_expression(property);
+                dot.push_str(&format!("  n{} -> n{} [label=\"{}\"];\n", index, target_index, escape(&label)));
+            }
+        }
     }
+    dot.push_str("}\n");
+    dot
 }
 
 /// Represents the types of an individual.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct IndividualTypes {
     /// The most specific classes that the individual belongs to
     pub most_specific: Vec<Class>,
     /// All classes that the individual belongs to (including superclasses)
     pub all: Vec<Class>,
+    /// The classes the individual is asserted to belong to with a `ClassAssertion`
+    /// of a named class; every other class in `all` was inferred
+    pub asserted: Vec<Class>,
 }
 
 impl IndividualTypes {
@@ -94,17 +512,32 @@ impl IndividualTypes {
         IndividualTypes {
             most_specific: Vec::new(),
             all: Vec::new(),
+            asserted: Vec::new(),
         }
     }
+
+    /// Returns the classes in `all` that were inferred rather than asserted.
+    pub fn inferred(&self) -> Vec<Class> {
+        self.all.iter().filter(|class| !self.asserted.contains(class)).cloned().collect()
+    }
 }
 
 /// Represents the class hierarchy computed by the reasoner.
-#[derive(Debug, Clone)]
+///
+/// The subclass and superclass maps hold the direct (transitively reduced) relation
+/// between classes that are not equivalent: D is a direct superclass of C if C ⊏ D and
+/// there is no E with C ⊏ E ⊏ D. owl:Thing is the top and owl:Nothing the bottom of
+/// every non-empty hierarchy, and unsatisfiable classes are equivalent to owl:Nothing.
+/// Equivalent classes have the same direct superclasses and subclasses. The lists in
+/// all three maps are sorted, and classes with an empty list have no entry.
+#[derive(Debug, Clone, Default)]
 pub struct ClassHierarchy {
     /// Maps each class to its direct subclasses
     pub subclasses: HashMap<Class, Vec<Class>>,
     /// Maps each class to its direct superclasses
     pub superclasses: HashMap<Class, Vec<Class>>,
+    /// Maps each class to the other classes equivalent to it
+    pub equivalent_classes: HashMap<Class, Vec<Class>>,
 }
 
 impl ClassHierarchy {
@@ -113,10 +546,128 @@ impl ClassHierarchy {
         ClassHierarchy {
             subclasses: HashMap::new(),
             superclasses: HashMap::new(),
+            equivalent_classes: HashMap::new(),
+        }
+    }
+
+    /// Builds the hierarchy from the subsumptions `(C, D)`, meaning C ⊑ D, found
+    /// between `classes`. Pairs in both directions make the classes equivalent; the
+    /// rest are reduced to the direct relation, with owl:Thing added at the top and
+    /// owl:Nothing at the bottom.
+    fn from_subsumptions(classes: &[Class], subsumptions: Vec<(Class, Class)>) -> Self {
+        use std::collections::BTreeSet;
+
+        let mut hierarchy = ClassHierarchy::new();
+        if classes.is_empty() {
+            return hierarchy;
+        }
+        let mut all: BTreeSet<Class> = classes.iter().cloned().collect();
+        all.insert(Class::thing());
+        all.insert(Class::nothing());
+
+        let mut supers: HashMap<&Class, BTreeSet<&Class>> = all.iter().map(|class| (class, BTreeSet::new())).collect();
+        for (sub, sup) in &subsumptions {
+            if sub != sup {
+                supers.get_mut(sub).unwrap().insert(all.get(sup).unwrap());
+            }
+        }
+        // Every class is below owl:Thing and above owl:Nothing
+        let (thing, nothing) = (all.get(&Class::thing()).unwrap(), all.get(&Class::nothing()).unwrap());
+        for class in &all {
+            if class != thing {
+                supers.get_mut(class).unwrap().insert(thing);
+            }
+            if class != nothing {
+                supers.get_mut(nothing).unwrap().insert(class);
+            }
+        }
+
+        let equivalent = |a: &Class, b: &Class| supers[a].contains(b) && supers[b].contains(a);
+        let strict: HashMap<&Class, Vec<&Class>> = supers.iter()
+            .map(|(&class, sups)| (class, sups.iter().copied().filter(|sup| !equivalent(class, sup)).collect()))
+            .collect();
+        for (&class, sups) in &supers {
+            let equivalents: Vec<Class> = sups.iter().filter(|sup| equivalent(class, sup)).map(|sup| (*sup).clone()).collect();
+            if !equivalents.is_empty() {
+                hierarchy.equivalent_classes.insert(class.clone(), equivalents);
+            }
+            for &sup in &strict[class] {
+                let indirect = strict[class].iter().any(|&between| between != sup && strict[between].contains(&sup));
+                if !indirect {
+                    hierarchy.superclasses.entry(class.clone()).or_default().push(sup.clone());
+                    hierarchy.subclasses.entry(sup.clone()).or_default().push(class.clone());
+                }
+            }
+        }
+        for classes in hierarchy.superclasses.values_mut().chain(hierarchy.subclasses.values_mut()) {
+            classes.sort();
+        }
+        hierarchy
+    }
+
+    /// Returns all strict superclasses of `class`, sorted: its direct superclasses,
+    /// theirs, and so on up to owl:Thing. Equivalent classes are not included.
+    pub fn ancestors(&self, class: &Class) -> Vec<Class> {
+        Self::closure(class, &self.superclasses)
+    }
+
+    /// Returns all strict subclasses of `class`, sorted: its direct subclasses, theirs,
+    /// and so on down to owl:Nothing. Equivalent classes are not included.
+    pub fn descendants(&self, class: &Class) -> Vec<Class> {
+        Self::closure(class, &self.subclasses)
+    }
+
+    /// Collects everything reachable from `class` through `edges`.
+    fn closure(class: &Class, edges: &HashMap<Class, Vec<Class>>) -> Vec<Class> {
+        let mut reached: Vec<Class> = Vec::new();
+        let mut pending: Vec<&Class> = vec![class];
+        while let Some(current) = pending.pop() {
+            for next in edges.get(current).into_iter().flatten() {
+                if !reached.contains(next) {
+                    reached.push(next.clone());
+                    pending.push(next);
+                }
+            }
         }
+        reached.sort();
+        reached
+    }
+
+    /// Builds the hierarchy as a tree rooted at owl:Thing.
+    ///
+    /// Each class appears under its direct superclasses, so a class with several of
+    /// them appears under each. Equivalent classes appear side by side. owl:Nothing
+    /// and the classes equivalent to it (the unsatisfiable classes) are left out.
+    /// Children are sorted.
+    pub fn to_tree(&self) -> ClassTreeNode {
+        let unsatisfiable = |class: &Class| {
+            class.is_nothing() || self.equivalent_classes.get(class).is_some_and(|equivalents| equivalents.iter().any(Class::is_nothing))
+        };
+        let build = |class: &Class| -> ClassTreeNode {
+            fn build_node(class: &Class, hierarchy: &ClassHierarchy, unsatisfiable: &dyn Fn(&Class) -> bool) -> ClassTreeNode {
+                ClassTreeNode {
+                    class: class.clone(),
+                    children: hierarchy.subclasses.get(class).into_iter().flatten()
+                        .filter(|sub| !unsatisfiable(sub))
+                        .map(|sub| build_node(sub, hierarchy, unsatisfiable))
+                        .collect(),
+                }
+            }
+            build_node(class, self, &unsatisfiable)
+        };
+        build(&Class::thing())
     }
 }
 
+/// A class and its direct subclasses, as built by [`ClassHierarchy::to_tree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassTreeNode {
+    /// The class at this node
+    pub class: Class,
+    /// The direct subclasses of the class, sorted
+    pub children: Vec<ClassTreeNode>,
+}
+
 /// Represents a step in the derivation of an entailment.
 #[derive(Debug, Clone)]
 pub struct DerivationStep {
@@ -130,6 +681,173 @@ pub struct DerivationStep {
     pub axioms: Vec<crate::Axiom>,
 }
 
+/// The size of the completion graph built while checking consistency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelSummary {
+    /// The number of nodes (named and fresh individuals)
+    pub nodes: usize,
+    /// The number of role edges between nodes
+    pub edges: usize,
+}
+
+/// Counters describing the work done by the most recent saturation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReasonerStats {
+    /// The number of times an expansion rule changed the completion graph
+    pub rule_applications: usize,
+    /// The number of rounds of rule application, including the final round in which
+    /// nothing changed
+    pub saturation_rounds: usize,
+    /// Whether the saturation resumed from the graph of an earlier check instead of
+    /// starting from scratch
+    pub reused_graph: bool,
+    /// The number of times a disjunct led to a clash and the next one was tried
+    pub backtracks: usize,
+    /// Whether the search stopped at [`ReasonerConfig::max_backtracks`]
+    pub backtrack_limit_reached: bool,
+    /// Whether a clash was found below the maximum depth of nested choices, where
+    /// only the first disjunct is tried
+    pub branch_depth_limit_reached: bool,
+    /// Whether a clash was found in a graph with a shared existential witness, so the
+    /// search was repeated without sharing (see [`Witness`])
+    pub unshared_retry: bool,
+}
+
+/// A contradiction found in the completion graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clash {
+    /// The individual is an instance of `owl:Nothing`.
+    Nothing {
+        individual: Individual,
+    },
+    /// The individual is an instance of both `class` and its complement.
+    Complement {
+        individual: Individual,
+        class: ClassExpression,
+    },
+    /// The individual has more pairwise distinct successors than the max
+    /// cardinality `restriction` allows.
+    MaxCardinality {
+        individual: Individual,
+        restriction: ClassExpression,
+    },
+    /// The individuals are asserted or inferred to be the same, but are known to be
+    /// different.
+    SameAndDifferent {
+        individuals: (Individual, Individual),
+    },
+    /// The source and target are connected by two properties declared disjoint.
+    DisjointProperties {
+        source: Individual,
+        target: Individual,
+        properties: (ObjectPropertyExpression, ObjectPropertyExpression),
+    },
+    /// A data property value lies outside one of the property's ranges, or outside
+    /// the data range a `DataSomeValuesFrom` requires of a functional property.
+    DataRange {
+        individual: Individual,
+        property: DataProperty,
+        value: Literal,
+        range: DataRange,
+    },
+    /// A functional data property has two different values for the individual.
+    FunctionalDataProperty {
+        individual: Individual,
+        property: DataProperty,
+        values: (Literal, Literal),
+    },
+    /// The individual has a value for `owl:bottomDataProperty` (or one of its
+    /// sub-properties), which holds for no individual.
+    BottomDataProperty {
+        individual: Individual,
+        value: Literal,
+    },
+    /// The individual needs a value of the property, but the property's range, or the
+    /// range a `DataSomeValuesFrom` requires, admits no value. Every individual needs
+    /// values of `owl:topDataProperty`.
+    EmptyDataRange {
+        individual: Individual,
+        property: DataProperty,
+        range: DataRange,
+    },
+    /// The source and target are connected by the property, contradicting a
+    /// `NegativeObjectPropertyAssertion`.
+    NegativePropertyAssertion {
+        source: Individual,
+        target: Individual,
+        property: ObjectPropertyExpression,
+    },
+}
+
+/// The outcome of a consistency check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsistencyResult {
+    /// The ontology is consistent; a model of the given size was found.
+    Consistent { model_summary: ModelSummary },
+    /// The ontology is inconsistent because of the given clash.
+    Inconsistent { clash: Clash },
+    /// The search gave up after the given number of backtracks, either at
+    /// [`ReasonerConfig::max_backtracks`] or because a clash lay too deep in nested
+    /// choices for every disjunct to be tried; the ontology may be either.
+    Unknown { backtracks: usize },
+}
+
+impl ConsistencyResult {
+    /// Returns whether the ontology is consistent, or an error for an `Unknown` result.
+    pub fn is_consistent(&self) -> Result<bool, ConsistencyUnknown> {
+        match self {
+            ConsistencyResult::Consistent { .. } => Ok(true),
+            ConsistencyResult::Inconsistent { .. } => Ok(false),
+            ConsistencyResult::Unknown { backtracks } => Err(ConsistencyUnknown { backtracks: *backtracks }),
+        }
+    }
+
+    /// Returns `true` if the result is `Inconsistent`. Refutation checks use this, so
+    /// an `Unknown` result never counts as a proof.
+    pub fn is_inconsistent(&self) -> bool {
+        matches!(self, ConsistencyResult::Inconsistent { .. })
+    }
+}
+
+/// The error returned when consistency checking gave up (see
+/// [`ConsistencyResult::Unknown`]), so an answer that depends on it cannot be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("consistency could not be decided after {backtracks} backtracks")]
+pub struct ConsistencyUnknown {
+    /// The number of backtracks made before giving up.
+    pub backtracks: usize,
+}
+
+/// The answer to an entailment query.
+///
+/// The reasoner does not implement every OWL 2 construct. `True` means refutation
+/// found a clash, which is a proof only as far as the rules involved are exact, and
+/// the absence of a clash only shows non-entailment when every construct involved is
+/// fully supported; otherwise the answer is `Unknown`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Entailment {
+    /// The axiom is entailed.
+    True,
+    /// The axiom is not entailed.
+    False,
+    /// The reasoner cannot decide whether the axiom is entailed.
+    Unknown,
+}
+
+/// The number of nested disjunctions consistency checking branches over. Deeper
+/// disjunctions take their first disjunct without backtracking, which bounds the
+/// search; a clash found there makes the result `Unknown`.
+const MAX_BRANCH_DEPTH: usize = 64;
+
+/// A decision to satisfy the disjunction `union` held by `individual` with its
+/// disjunct at `index`, made while enumerating models.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct DisjunctChoice {
+    individual: Individual,
+    union: ClassExpression,
+    index: usize,
+}
+
 /// The main tableau reasoner.
 #[derive(Debug)]
 pub struct TableauReasoner {
@@ -141,21 +859,201 @@ pub struct TableauReasoner {
     pub previous_results: Option<ReasoningResults>,
     /// Tracks derivation steps for explanation generation
     pub derivation_tracker: Vec<DerivationStep>,
+    /// The configuration this reasoner was created with
+    pub config: ReasonerConfig,
+    /// The expansion rules applied during saturation, in order: the built-in rules
+    /// followed by any added with [`TableauReasoner::add_rule`]
+    pub rules: Vec<Arc<dyn ExpansionRule>>,
+    /// Statistics for the most recent consistency check
+    pub stats: ReasonerStats,
+    /// The axioms the completion graph was last saturated for by an incremental check
+    saturated_axioms: Option<Vec<Axiom>>,
+    /// The edges of the completion graph on named properties at the last check for
+    /// negative property assertions, with their closure, reused while the edges stay
+    /// the same; cleared whenever the graph is initialized
+    property_closure: Option<(HashSet<NamedEdge>, HashSet<NamedEdge>)>,
+    /// The preprocessed TBox and role hierarchy, kept while the ontology's revision
+    /// stays the same
+    preprocessed: Option<Preprocessed>,
+}
+
+/// The ontology-wide structures the completion graph is initialized with, built once
+/// per revision of the ontology and shared with scratch reasoners.
+#[derive(Debug, Clone)]
+struct Preprocessed {
+    /// The revision of the ontology these were built for
+    revision: u64,
+    /// The told class inclusions, absorbed into named-class unfoldings where the
+    /// subclass is named and in negation normal form otherwise
+    inclusions: Arc<ToldInclusions>,
+    /// The representatives of the equivalence classes of the role hierarchy
+    role_representatives: Arc<HashMap<ObjectPropertyExpression, ObjectPropertyExpression>>,
 }
 
 impl TableauReasoner {
     /// Creates a new tableau reasoner for the given ontology.
     pub fn new(ontology: Ontology) -> Self {
+        Self::with_config(ontology, ReasonerConfig::default())
+    }
+
+    /// Creates a new tableau reasoner with the given configuration.
+    ///
+    /// The configured IRI normalizer and annotation handlers are applied immediately:
+    /// every IRI of the reasoner's copy of the ontology is normalized, and every axiom
+    /// the handlers produce is added to it. The IRIs of named individuals are then put
+    /// in normal form (see [`IRI::normalized`](crate::IRI::normalized)), and so are
+    /// the individuals passed to queries, so different encodings of the same IRI
+    /// denote a single individual.
+    pub fn with_config(mut ontology: Ontology, config: ReasonerConfig) -> Self {
+        if let Some(normalizer) = &config.iri_normalizer {
+            ontology.normalize_iris(normalizer.as_ref());
+        }
+        if !config.annotation_handlers.is_empty() {
+            let mut translated = Vec::new();
+            for axiom in &ontology.axioms {
+                if let Axiom::Annotation(annotation) = axiom {
+                    for handler in &config.annotation_handlers {
+                        if let Some(new_axiom) = handler(annotation)
+                            && !ontology.axioms.contains(&new_axiom)
+                            && !translated.contains(&new_axiom) {
+                                translated.push(new_axiom);
+                            }
+                    }
+                }
+            }
+            ontology.axioms.extend(translated);
+        }
+        ontology.normalize_individuals();
+        Self::with_normalized_ontology(ontology, config)
+    }
+
+    /// Creates a reasoner for an ontology that [`with_config`](Self::with_config) has
+    /// already prepared, such as a copy of another reasoner's ontology.
+    fn with_normalized_ontology(ontology: Ontology, config: ReasonerConfig) -> Self {
         TableauReasoner {
             ontology,
             graph: CompletionGraph::new(),
             previous_results: None,
             derivation_tracker: Vec::new(),
+            config,
+            rules: vec![
+                Arc::new(ConjunctionRule),
+                Arc::new(SubsumptionRule),
+                Arc::new(GciRule),
+                Arc::new(DisjunctionRule),
+                Arc::new(ExistentialRule),
+                Arc::new(UniversalRule),
+                Arc::new(DomainRule),
+                Arc::new(RangeRule),
+                Arc::new(ExactCardinalityRule),
+                Arc::new(MinCardinalityRule),
+                Arc::new(MaxCardinalityRule),
+                Arc::new(HasKeyRule),
+            ],
+            stats: ReasonerStats::default(),
+            saturated_axioms: None,
+            property_closure: None,
+            preprocessed: None,
+        }
+    }
+
+    /// Registers a custom expansion rule, applied after the built-in rules in every
+    /// round of saturation.
+    ///
+    /// The rule is shared with the temporary reasoners used for subsumption and
+    /// satisfiability tests, so it takes part in classification too.
+    pub fn add_rule<R: ExpansionRule + 'static>(&mut self, rule: R) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Creates a reasoner over a copy of this ontology with the same expansion rules
+    /// and backtrack limit and an empty completion graph, for tests such as
+    /// subsumption checks.
+    fn scratch_reasoner(&self) -> TableauReasoner {
+        let config = ReasonerConfig { max_backtracks: self.config.max_backtracks, ..ReasonerConfig::default() };
+        let mut reasoner = TableauReasoner::with_normalized_ontology(self.ontology.clone(), config);
+        reasoner.rules = self.rules.clone();
+        reasoner.preprocessed = self.preprocessed.clone();
+        reasoner
+    }
+
+    /// Returns a scratch reasoner for refuting a query, starting from the current
+    /// completion graph when that holds only consequences of the ontology.
+    fn refutation_reasoner(&self) -> TableauReasoner {
+        let mut reasoner = self.scratch_reasoner();
+        if !self.graph_has_choices() {
+            reasoner.graph = self.graph.clone();
+        }
+        reasoner
+    }
+
+    /// Rewrites the IRIs of query arguments the way [`with_config`](Self::with_config)
+    /// rewrote the ontology.
+    fn query_iris(&self) -> QueryIris<'_> {
+        QueryIris(self.config.iri_normalizer.as_deref())
+    }
+
+    fn query_class(&self, class: &Class) -> Class {
+        let mut class = class.clone();
+        crate::IriMap::iri(&mut self.query_iris(), &mut class.0);
+        class
+    }
+
+    fn query_individual(&self, individual: &Individual) -> Individual {
+        let mut individual = individual.clone();
+        if let Individual::Named(iri) = &mut individual {
+            crate::IriMap::individual(&mut self.query_iris(), iri);
         }
+        individual
+    }
+
+    fn query_property(&self, property: &ObjectPropertyExpression) -> ObjectPropertyExpression {
+        let mut property = property.clone();
+        crate::map_property_iris(&mut property, &mut self.query_iris());
+        property
+    }
+
+    fn query_expression(&self, expression: &ClassExpression) -> ClassExpression {
+        let mut expression = expression.clone();
+        crate::map_class_expression_iris(&mut expression, &mut self.query_iris());
+        expression
+    }
+
+    fn query_axiom(&self, axiom: &Axiom) -> Axiom {
+        let mut axiom = axiom.clone();
+        crate::map_axiom_iris(&mut axiom, &mut self.query_iris());
+        axiom
+    }
+
+    /// Checks whether the completion graph may reflect a choice of disjunct, in which
+    /// case the concepts it holds are not necessarily entailed.
+    fn graph_has_choices(&self) -> bool {
+        self.graph.nodes.iter().any(|node| node.concepts.iter().any(|concept| matches!(concept, ClassExpression::ObjectUnionOf(_))))
+    }
+
+    /// Discards the completion graph so the next reasoning call starts from scratch.
+    ///
+    /// Fresh individual numbering is reset as well, so reasoning again over the same
+    /// ontology produces identically named fresh individuals.
+    pub fn reset(&mut self) {
+        self.graph.clear();
+        self.saturated_axioms = None;
     }
 
     /// Initializes the completion graph with the assertions from the ontology.
+    ///
+    /// Edges are stored once per equivalence class of properties (see
+    /// [`CompletionGraph::normalize_roles`]), so asserting the same edge through
+    /// equivalent or inverse properties does not grow the graph. The told class
+    /// inclusions are indexed here too, once per revision of the ontology rather than
+    /// once per rule application (see [`TableauReasoner::prepare`]).
     pub fn initialize(&mut self) {
+        self.property_closure = None;
+        let preprocessed = self.preprocessed().clone();
+        self.graph.inclusions = Some(preprocessed.inclusions);
+        self.graph.role_representatives = (*preprocessed.role_representatives).clone();
+        self.graph.normalize_roles();
+
         // Add all individuals mentioned in assertions to the graph
         for axiom in &self.ontology.axioms {
             match axiom {
@@ -177,9 +1075,11 @@ impl TableauReasoner {
                         }
                     }
                     crate::Assertion::DifferentIndividuals { individuals } => {
-                        // For now, we just ensure all individuals exist in the graph
-                        for individual in individuals {
+                        for (i, individual) in individuals.iter().enumerate() {
                             self.graph.get_or_create_node(individual);
+                            for other in &individuals[i + 1..] {
+                                self.graph.add_inequality(individual, other);
+                            }
                         }
                     }
                     crate::Assertion::NegativeObjectPropertyAssertion { property: _, source, target: _ } => {
@@ -189,8 +1089,7 @@ impl TableauReasoner {
                         self.graph.get_or_create_node(source);
                     }
                     crate::Assertion::HasKey { class: _, object_property_expression: _, data_property: _ } => {
-                        // For now, we just ensure the individual exists in the graph
-                        // In a full implementation, we would handle the HasKey constraint
+                        // Keys identify individuals, which is done by HasKeyRule during expansion
                     }
                 },
                 _ => {
@@ -201,52 +1100,274 @@ impl TableauReasoner {
     }
 
     /// Checks if the ontology is consistent (satisfiable).
-    pub fn is_consistent(&mut self) -> bool {
+    ///
+    /// Returns an error if the search gave up (see [`ConsistencyResult::Unknown`]).
+    pub fn is_consistent(&mut self) -> Result<bool, ConsistencyUnknown> {
+        self.check_consistency().is_consistent()
+    }
+
+    /// Checks if the ontology is consistent, reporting either a summary of the model
+    /// that was built or the clash that was found.
+    ///
+    /// Disjunctions are handled by backtracking: each disjunct of an `ObjectUnionOf`
+    /// is tried on its own copy of the completion graph, and the ontology is only
+    /// inconsistent if every branch clashes. The reported clash is the one from the
+    /// first disjunct. On success the completion graph is the model that was found.
+    ///
+    /// If a clash involved a graph with a shared existential witness (see [`Witness`]),
+    /// the search is repeated from the initial graph with sharing disabled, since the
+    /// clash may be an artifact of the sharing.
+    ///
+    /// When the search gives up, the completion graph is put back to its state before
+    /// expansion, since the branch it was abandoned in may already hold a clash.
+    pub fn check_consistency(&mut self) -> ConsistencyResult {
         // Initialize the completion graph
         self.initialize();
+        self.stats = ReasonerStats::default();
+
+        let initial = self.graph.clone();
+        let mut clash = self.expand(0);
+        if clash.is_some() && self.stats.unshared_retry && !self.stats.backtrack_limit_reached {
+            self.graph = initial.clone();
+            self.graph.share_witnesses = false;
+            clash = self.expand(0);
+            self.graph.share_witnesses = true;
+        }
+
+        // Put each node's concepts in canonical order so results do not depend on the
+        // order in which rules happened to add them
+        for node in &mut self.graph.nodes {
+            node.concepts.sort();
+        }
         
-        // Apply tableau expansion rules until saturation
+        let gave_up = self.stats.backtrack_limit_reached || (clash.is_some() && self.stats.branch_depth_limit_reached);
+        if gave_up {
+            self.graph = initial;
+        }
+        match clash {
+            _ if gave_up => ConsistencyResult::Unknown { backtracks: self.stats.backtracks },
+            Some(clash) => ConsistencyResult::Inconsistent { clash },
+            None => ConsistencyResult::Consistent {
+                model_summary: ModelSummary {
+                    nodes: self.graph.nodes.len(),
+                    edges: self.graph.nodes.iter().map(|node| node.roles.len()).sum(),
+                },
+            },
+        }
+    }
+    
+    /// Saturates the completion graph, then branches over the first disjunction none
+    /// of whose disjuncts is held yet. Returns the clash if no branch leads to a
+    /// clash-free graph; otherwise the graph is left at the clash-free branch.
+    ///
+    /// Beyond [`MAX_BRANCH_DEPTH`] nested choices only the first disjunct is tried, and
+    /// a clash there is recorded in [`ReasonerStats::branch_depth_limit_reached`]. Once
+    /// [`ReasonerConfig::max_backtracks`] disjuncts have been retracted, no further
+    /// disjunct is tried anywhere and the search unwinds with the clashes found so far.
+    fn expand(&mut self, depth: usize) -> Option<Clash> {
+        self.saturate();
+        if let Some(clash) = self.find_clash() {
+            if self.graph.share_witnesses && self.graph.has_shared_witness() {
+                self.stats.unshared_retry = true;
+            }
+            return Some(clash);
+        }
+
+        let undecided = self.graph.nodes.iter().enumerate().find_map(|(node_index, node)| {
+            node.concepts.iter().find_map(|concept| match concept {
+                ClassExpression::ObjectUnionOf(disjuncts)
+                    if !disjuncts.is_empty() && !disjuncts.iter().any(|disjunct| node.concepts.contains(disjunct)) =>
+                    Some((node_index, disjuncts.clone())),
+                _ => None,
+            })
+        });
+        let (node_index, disjuncts) = undecided?;
+
+        let disjuncts_len = disjuncts.len();
+        let branches = if depth < MAX_BRANCH_DEPTH { disjuncts_len } else { 1 };
+        let saved = self.graph.clone();
+        let mut first_clash = None;
+        for (index, disjunct) in disjuncts.into_iter().take(branches).enumerate() {
+            if index > 0 {
+                if self.config.max_backtracks.is_some_and(|max| self.stats.backtracks >= max) {
+                    self.stats.backtrack_limit_reached = true;
+                    break;
+                }
+                self.graph = saved.clone();
+                self.stats.backtracks += 1;
+            }
+            self.graph.nodes[node_index].concepts.push(disjunct);
+            let clash = self.expand(depth + 1)?;
+            first_clash.get_or_insert(clash);
+        }
+        if branches < disjuncts_len {
+            self.stats.branch_depth_limit_reached = true;
+        }
+        first_clash
+    }
+
+    /// Applies the deterministic expansion rules until saturation. Rules run in a
+    /// fixed order, so a rule may enable another that already ran this round (e.g. the
+    /// universal rule on a successor the existential rule just created); the loop only
+    /// stops after a full round in which no rule changed the graph.
+    fn saturate(&mut self) {
         let mut new_added = true;
         while new_added {
             new_added = false;
-            
-            // Apply all rules
-            if self.apply_conjunction_rule() {
-                new_added = true;
+            self.stats.saturation_rounds += 1;
+            for rule in self.rules.iter().filter(|rule| !rule.is_nondeterministic()) {
+                if rule.apply(&mut self.graph, &self.ontology) {
+                    self.stats.rule_applications += 1;
+                    new_added = true;
+                }
             }
-            
-            if self.apply_disjunction_rule() {
-                new_added = true;
+        }
+    }
+
+    /// Enumerates up to `max` distinct clash-free completion graphs (models).
+    ///
+    /// Where [`TableauReasoner::check_consistency`] stops at the first model, this
+    /// search keeps backtracking over the choice of disjunct: after each model it
+    /// revisits every disjunction that was decided by default and tries the other
+    /// disjuncts, re-saturating from the initial graph with those choices forced.
+    /// Branches that end in a clash are not models, and graphs identical to one already
+    /// found are skipped. Models are returned in the order they are found, starting
+    /// with the one that always takes the first disjunct if that is clash-free; an
+    /// inconsistent ontology has none.
+    ///
+    /// The completion graph is left cleared afterwards.
+    pub fn find_models(&mut self, max: usize) -> Vec<CompletionGraph> {
+        self.reset();
+        self.initialize();
+        let initial = self.graph.clone();
+
+        let mut models: Vec<CompletionGraph> = Vec::new();
+        let mut visited: Vec<Vec<DisjunctChoice>> = vec![Vec::new()];
+        let mut pending: Vec<Vec<DisjunctChoice>> = vec![Vec::new()];
+        while models.len() < max {
+            let Some(choices) = pending.pop() else {
+                break;
+            };
+            self.graph = initial.clone();
+            self.saturate_with_choices(&choices);
+
+            // Each disjunction decided by default is a choice point for the other
+            // disjuncts, whether or not the default choice led to a clash
+            for node in &self.graph.nodes {
+                for concept in &node.concepts {
+                    let ClassExpression::ObjectUnionOf(disjuncts) = concept else {
+                        continue;
+                    };
+                    if choices.iter().any(|choice| choice.individual == node.individual && choice.union == *concept) {
+                        continue;
+                    }
+                    for index in 1..disjuncts.len() {
+                        let mut branch = choices.clone();
+                        branch.push(DisjunctChoice { individual: node.individual.clone(), union: concept.clone(), index });
+                        branch.sort();
+                        if !visited.contains(&branch) {
+                            visited.push(branch.clone());
+                            pending.push(branch);
+                        }
+                    }
+                }
             }
-            
-            if self.apply_existential_rule() {
-                new_added = true;
+
+            if self.find_clash().is_none() && !models.iter().any(|model| model.nodes == self.graph.nodes) {
+                models.push(self.graph.clone());
             }
-            
-            if self.apply_universal_rule() {
-                new_added = true;
+        }
+
+        self.reset();
+        models
+    }
+
+    /// Saturates the completion graph like [`TableauReasoner::check_consistency`], but
+    /// adds each forced disjunct as soon as its node holds the disjunction, before the
+    /// disjunction rule can decide it by default.
+    fn saturate_with_choices(&mut self, choices: &[DisjunctChoice]) {
+        let mut new_added = true;
+        while new_added {
+            new_added = false;
+            for rule in &self.rules {
+                for choice in choices {
+                    if let Some(node) = self.graph.nodes.iter_mut()
+                        .find(|node| node.individual == choice.individual && node.concepts.contains(&choice.union))
+                        && let ClassExpression::ObjectUnionOf(disjuncts) = &choice.union
+                        && !node.concepts.contains(&disjuncts[choice.index]) {
+                            node.concepts.push(disjuncts[choice.index].clone());
+                            new_added = true;
+                        }
+                }
+                if rule.apply(&mut self.graph, &self.ontology) {
+                    new_added = true;
+                }
             }
         }
-        
-        // Check for clashes
-        // A clash occurs when an individual is both an instance of a class and its complement
-        // For simplicity, we'll just check for direct clashes in the current implementation
-        !self.has_clash()
+        for node in &mut self.graph.nodes {
+            node.concepts.sort();
+        }
     }
-    
+
     /// Computes the class hierarchy for the ontology.
-    pub fn classify(&mut self) -> ClassHierarchy {
+    ///
+    /// Returns an error if consistency checking gave up.
+    pub fn classify(&mut self) -> Result<ClassHierarchy, ConsistencyUnknown> {
         // First check consistency
-        if !self.is_consistent() {
+        if !self.is_consistent()? {
             // Return an empty hierarchy for inconsistent ontologies
-            return ClassHierarchy::new();
+            return Ok(ClassHierarchy::new());
         }
-        
-        // Initialize the class hierarchy
-        let mut hierarchy = ClassHierarchy::new();
-        
-        // Extract all classes from the ontology
-        let classes = self.extract_classes();
+
+        Ok(self.compute_class_hierarchy())
+    }
+
+    /// Computes the class hierarchy like [`TableauReasoner::classify`], calling
+    /// `on_progress(completed_pairs, total_pairs)` as the pairwise subsumption tests
+    /// complete.
+    ///
+    /// Pairs are tested one class at a time (the tests for a class still run in
+    /// parallel), and the callback is invoked after each class, so the completed count
+    /// increases monotonically and ends at the total. It is not invoked for an
+    /// inconsistent ontology, nor when consistency checking gives up, which is an error.
+    pub fn classify_with_progress(&mut self, mut on_progress: impl FnMut(usize, usize)) -> Result<ClassHierarchy, ConsistencyUnknown> {
+        if !self.is_consistent()? {
+            return Ok(ClassHierarchy::new());
+        }
+
+        let classes = self.hierarchy_classes();
+        let total = classes.len() * classes.len().saturating_sub(1);
+        let mut subsumptions = Vec::new();
+        let mut completed = 0;
+        for class_c in &classes {
+            let superclasses: Vec<_> = classes
+                .par_iter()
+                .filter(|class_d| class_c != *class_d && self.is_subsumed_by(class_c, class_d))
+                .map(|class_d| (class_c.clone(), class_d.clone()))
+                .collect();
+            subsumptions.extend(superclasses);
+            completed += classes.len() - 1;
+            on_progress(completed, total);
+        }
+        Ok(ClassHierarchy::from_subsumptions(&classes, subsumptions))
+    }
+
+    /// The classes the hierarchy is computed over: all classes of the ontology and
+    /// owl:Nothing. owl:Nothing is always included: it is subsumed by every class, and
+    /// every unsatisfiable class subsumes it in turn, so unsatisfiable classes end up
+    /// equivalent to it.
+    fn hierarchy_classes(&self) -> Vec<Class> {
+        let mut classes = self.extract_classes();
+        if !classes.is_empty() && !classes.iter().any(Class::is_nothing) {
+            classes.push(Class::nothing());
+        }
+        classes
+    }
+
+    /// Computes the class hierarchy, assuming the ontology is already known to be
+    /// consistent.
+    pub(crate) fn compute_class_hierarchy(&self) -> ClassHierarchy {
+        let classes = self.hierarchy_classes();
         
         // For each pair of classes (C, D), check if C is subsumed by D
         // This is done by checking if C ⊓ ¬D is unsatisfiable
@@ -267,25 +1388,132 @@ impl TableauReasoner {
             })
             .collect();
         
-        // Process the subsumption results to build the hierarchy
-        for (class_c, class_d) in subsumption_results {
-            // Add D as a superclass of C
-            hierarchy.superclasses.entry(class_c.clone()).or_insert_with(Vec::new).push(class_d.clone());
-            // Add C as a subclass of D
-            hierarchy.subclasses.entry(class_d.clone()).or_insert_with(Vec::new).push(class_c.clone());
-        }
-        
-        hierarchy
+        ClassHierarchy::from_subsumptions(&classes, subsumption_results)
     }
     
+    /// Finds the axioms that are entailed by the rest of the ontology, so removing
+    /// any one of them on its own changes nothing.
+    ///
+    /// Each candidate is removed and the remaining ontology is tested for entailment of
+    /// it. Currently only `SubClassOf` axioms are considered: `C ⊑ D` is entailed if a
+    /// test individual in `C ⊓ ¬D` is inconsistent with the other axioms. Tautologies
+    /// such as `C ⊑ C` are always reported. Axioms are returned in ontology order.
+    pub fn redundant_axioms(&self) -> Vec<Axiom> {
+        let candidates: Vec<usize> = self.ontology.axioms.iter()
+            .enumerate()
+            .filter(|(_, axiom)| matches!(axiom, Axiom::Class(crate::ClassAxiom::SubClassOf { .. })))
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut redundant: Vec<usize> = candidates
+            .into_par_iter()
+            .filter(|&index| {
+                let Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class, super_class }) = &self.ontology.axioms[index] else {
+                    return false;
+                };
+                let mut temp_reasoner = self.scratch_reasoner();
+                temp_reasoner.ontology.remove_axiom(index);
+                let individual = Individual::Anonymous(crate::NodeID("_:test".to_string()));
+                temp_reasoner.graph.add_concept(&individual, ClassExpression::ObjectIntersectionOf(vec![
+                    sub_class.clone(),
+                    negate(super_class),
+                ]));
+                temp_reasoner.check_consistency().is_inconsistent()
+            })
+            .collect();
+        redundant.sort();
+        redundant.into_iter().map(|index| self.ontology.axioms[index].clone()).collect()
+    }
+
+    /// Checks whether two object properties are equivalent, i.e. each is a told or
+    /// inferred sub-property of the other (via SubObjectPropertyOf,
+    /// EquivalentObjectProperties and InverseObjectProperties).
+    pub fn are_properties_equivalent(&self, p: &ObjectPropertyExpression, q: &ObjectPropertyExpression) -> bool {
+        let (p, q) = (&self.query_property(p), &self.query_property(q));
+        p == q || (self.told_super_properties(p).contains(q) && self.told_super_properties(q).contains(p))
+    }
+
+    /// Checks whether two object properties are disjoint.
+    ///
+    /// Disjointness is inherited by sub-properties: `p` and `q` are disjoint if some
+    /// super-property of `p` and some super-property of `q` are declared disjoint,
+    /// either directly or through their inverses.
+    pub fn are_properties_disjoint(&self, p: &ObjectPropertyExpression, q: &ObjectPropertyExpression) -> bool {
+        let (p, q) = (&self.query_property(p), &self.query_property(q));
+        let p_supers = self.told_super_properties(p);
+        let q_supers = self.told_super_properties(q);
+        let p_inverses: Vec<ObjectPropertyExpression> = p_supers.iter().filter_map(inverse_of).collect();
+        let q_inverses: Vec<ObjectPropertyExpression> = q_supers.iter().filter_map(inverse_of).collect();
+
+        self.ontology.axioms.iter().any(|axiom| match axiom {
+            Axiom::ObjectProperty(crate::ObjectPropertyAxiom::DisjointObjectProperties { properties }) => {
+                properties.iter().enumerate().any(|(i, r)| {
+                    properties.iter().enumerate().any(|(j, s)| {
+                        i != j
+                            && ((p_supers.contains(r) && q_supers.contains(s))
+                                || (p_inverses.contains(r) && q_inverses.contains(s)))
+                    })
+                })
+            }
+            _ => false,
+        })
+    }
+
+    /// Finds all classes that are provably equivalent to `class` (mutual subsumption),
+    /// sorted by IRI. The class itself is not included. Returns an error if consistency
+    /// checking gave up.
+    pub fn equivalent_classes(&mut self, class: &Class) -> Result<Vec<Class>, ConsistencyUnknown> {
+        let class = self.query_class(class);
+        Ok(self.classify()?.equivalent_classes.remove(&class).unwrap_or_default())
+    }
+
+    /// Finds the least common subsumers of two classes: the most specific named
+    /// classes that subsume both, sorted by IRI.
+    ///
+    /// The subsumer sets of `a` and `b` (each class with its equivalents and ancestors
+    /// in the computed hierarchy) are intersected; a common subsumer is kept unless
+    /// another one is strictly more specific. Every minimal common subsumer is
+    /// returned, so the result can hold several incomparable classes as well as
+    /// equivalent ones. Classes with no more specific common subsumer have owl:Thing.
+    /// Returns an error if consistency checking gave up.
+    pub fn least_common_subsumer(&mut self, a: &Class, b: &Class) -> Result<Vec<Class>, ConsistencyUnknown> {
+        let (a, b) = (&self.query_class(a), &self.query_class(b));
+        let hierarchy = self.classify()?;
+        let subsumers = |class: &Class| -> Vec<Class> {
+            let mut subsumers = vec![class.clone()];
+            subsumers.extend(hierarchy.equivalent_classes.get(class).into_iter().flatten().cloned());
+            subsumers.extend(hierarchy.ancestors(class));
+            subsumers
+        };
+        let subsumes = |sup: &Class, sub: &Class| subsumers(sub).contains(sup);
+
+        let b_subsumers = subsumers(b);
+        let common: Vec<Class> = subsumers(a).into_iter().filter(|class| b_subsumers.contains(class)).collect();
+        let mut least: Vec<Class> = common.iter()
+            .filter(|candidate| !common.iter().any(|other| subsumes(candidate, other) && !subsumes(other, candidate)))
+            .cloned()
+            .collect();
+        least.sort();
+        least.dedup();
+        Ok(least)
+    }
+
     /// Finds the most specific types for all individuals in the ontology.
-    pub fn realize(&mut self) -> HashMap<Individual, IndividualTypes> {
+    ///
+    /// Returns an error if consistency checking gave up.
+    pub fn realize(&mut self) -> Result<HashMap<Individual, IndividualTypes>, ConsistencyUnknown> {
         // First check consistency
-        if !self.is_consistent() {
+        if !self.is_consistent()? {
             // Return an empty map for inconsistent ontologies
-            return HashMap::new();
+            return Ok(HashMap::new());
         }
-        
+
+        Ok(self.compute_individual_types())
+    }
+
+    /// Finds the most specific types of every individual, assuming the completion
+    /// graph has already been saturated without a clash.
+    pub(crate) fn compute_individual_types(&self) -> HashMap<Individual, IndividualTypes> {
         // Initialize the result map
         let mut individual_types = HashMap::new();
         
@@ -303,6 +1531,231 @@ impl TableauReasoner {
         
         individual_types
     }
+
+    /// Finds the named classes that can have no instances.
+    ///
+    /// Every class is unsatisfiable in an inconsistent ontology; callers should check
+    /// consistency first.
+    pub fn unsatisfiable_classes(&self) -> Vec<Class> {
+        let mut unsatisfiable: Vec<Class> = self.extract_classes()
+            .into_par_iter()
+            .filter(|class| !class.is_nothing() && !self.is_satisfiable(class))
+            .collect();
+        unsatisfiable.sort();
+        unsatisfiable
+    }
+
+    /// Checks the asserted facts for direct contradictions, ignoring the TBox.
+    ///
+    /// The completion graph is built from the assertions alone (class and property
+    /// assertions, `SameIndividual` and `DifferentIndividuals`) and checked for clashes
+    /// without applying any expansion rule, so only contradictions stated in the data
+    /// itself are found, such as `A` and `ObjectComplementOf(A)` on one individual. An
+    /// ontology that passes may still be inconsistent because of its TBox.
+    pub fn check_abox_only(&self) -> bool {
+        let (_, abox) = self.ontology.split_tbox_abox();
+        let mut temp_reasoner = TableauReasoner::new(abox);
+        temp_reasoner.initialize();
+        temp_reasoner.find_clash().is_none()
+    }
+
+    /// Checks the satisfiability of each class expression with respect to the TBox,
+    /// returning one result per expression, in order.
+    ///
+    /// The TBox (see [`Ontology::split_tbox_abox`]) is extracted once into a single
+    /// scratch reasoner, and each expression is tested by asserting it for a test
+    /// individual on a cleared completion graph. Unlike the per-class checks used by
+    /// classification, the ontology is not cloned for every test, and assertions about
+    /// named individuals play no part. An expression is only reported unsatisfiable if
+    /// its test clashes, so one whose test gives up counts as satisfiable.
+    pub fn batch_satisfiable(&self, expressions: &[ClassExpression]) -> Vec<bool> {
+        let (tbox, _) = self.ontology.split_tbox_abox();
+        let config = ReasonerConfig { max_backtracks: self.config.max_backtracks, ..ReasonerConfig::default() };
+        let mut temp_reasoner = TableauReasoner::with_normalized_ontology(tbox, config);
+        temp_reasoner.rules = self.rules.clone();
+
+        let individual = Individual::Anonymous(crate::NodeID("_:test".to_string()));
+        expressions.iter()
+            .map(|expression| {
+                temp_reasoner.reset();
+                temp_reasoner.graph.add_concept(&individual, self.query_expression(expression));
+                !temp_reasoner.check_consistency().is_inconsistent()
+            })
+            .collect()
+    }
+
+    /// Finds every pair of named classes that are disjoint, whether asserted with
+    /// `DisjointClasses` or entailed, e.g. inherited by a subclass.
+    ///
+    /// A pair is disjoint when the intersection of its classes is unsatisfiable; all
+    /// intersections are tested with [`batch_satisfiable`](Self::batch_satisfiable).
+    /// Unsatisfiable classes are left out, since they are trivially disjoint from
+    /// every class, as are owl:Thing and owl:Nothing. Each pair is listed once, with
+    /// the smaller class first, and the pairs are sorted.
+    pub fn all_disjoint_pairs(&self) -> Vec<(Class, Class)> {
+        let mut classes: Vec<Class> = self.extract_classes().into_iter()
+            .filter(|class| !class.is_thing() && !class.is_nothing())
+            .collect();
+        classes.sort();
+        let named: Vec<ClassExpression> = classes.iter().cloned().map(ClassExpression::Class).collect();
+        let satisfiable: Vec<Class> = classes.into_iter()
+            .zip(self.batch_satisfiable(&named))
+            .filter_map(|(class, satisfiable)| satisfiable.then_some(class))
+            .collect();
+
+        let pairs: Vec<(Class, Class)> = satisfiable.iter().enumerate()
+            .flat_map(|(i, a)| satisfiable[i + 1..].iter().map(move |b| (a.clone(), b.clone())))
+            .collect();
+        let intersections: Vec<ClassExpression> = pairs.iter()
+            .map(|(a, b)| ClassExpression::ObjectIntersectionOf(vec![ClassExpression::Class(a.clone()), ClassExpression::Class(b.clone())]))
+            .collect();
+        pairs.into_iter()
+            .zip(self.batch_satisfiable(&intersections))
+            .filter_map(|(pair, satisfiable)| (!satisfiable).then_some(pair))
+            .collect()
+    }
+
+    /// Checks whether a class can have an instance, by asserting it for a test
+    /// individual and checking consistency. A test that gives up counts as satisfiable.
+    fn is_satisfiable(&self, class: &Class) -> bool {
+        let mut temp_reasoner = self.scratch_reasoner();
+        let individual = Individual::Anonymous(crate::NodeID("_:test".to_string()));
+        temp_reasoner.graph.add_concept(&individual, ClassExpression::Class(class.clone()));
+        !temp_reasoner.check_consistency().is_inconsistent()
+    }
+
+    /// Checks consistency, reusing the completion graph of the previous incremental
+    /// check when possible. Returns an error if the search gave up.
+    pub fn is_consistent_incremental(&mut self) -> Result<bool, ConsistencyUnknown> {
+        self.check_consistency_incremental().is_consistent()
+    }
+
+    /// Checks consistency, resuming saturation from the previous incremental check's
+    /// completion graph when the ontology has only grown since.
+    ///
+    /// When every axiom the graph was built for is still present (in the same order,
+    /// with new axioms appended) the saturated graph is reused: the new assertions are
+    /// added to it and only the rules they enable fire. Reuse is only attempted for a
+    /// graph that holds nothing but consequences of the ontology, so a graph that chose
+    /// a disjunct or shares an existential witness is discarded, as is the graph after
+    /// any other change, such as a removal.
+    /// Those cases reason from scratch. [`TableauReasoner::stats`] records which path
+    /// was taken.
+    pub fn check_consistency_incremental(&mut self) -> ConsistencyResult {
+        let additive = self.saturated_axioms.as_ref()
+            .is_some_and(|previous| self.ontology.axioms.starts_with(previous))
+            && !self.graph_has_choices()
+            && !self.graph.has_shared_witness();
+        if !additive {
+            self.reset();
+        }
+        let result = self.check_consistency();
+        self.stats.reused_graph = additive;
+        self.saturated_axioms = Some(self.ontology.axioms.clone());
+        result
+    }
+
+    /// Computes the class hierarchy, reusing the previous result if the ontology has not changed.
+    pub fn classify_incremental(&mut self) -> Result<ClassHierarchy, ConsistencyUnknown> {
+        Ok(self.previous_results_for_current_revision()?.class_hierarchy.clone())
+    }
+
+    /// Realizes all individuals, reusing the previous result if the ontology has not changed.
+    pub fn realize_incremental(&mut self) -> Result<HashMap<Individual, IndividualTypes>, ConsistencyUnknown> {
+        Ok(self.previous_results_for_current_revision()?.individual_types.clone())
+    }
+
+    /// Does the one-time work for answering queries up front and stores it for the
+    /// current revision of the ontology.
+    ///
+    /// The TBox is preprocessed: told inclusions with a named subclass are absorbed
+    /// into unfoldings of that class, the others are put in negation normal form,
+    /// and the role hierarchy is closed to find equivalent properties. Then
+    /// consistency is checked, the ontology classified and its individuals realized.
+    ///
+    /// Until the revision changes, every completion graph, including those of the
+    /// satisfiability tests behind queries, starts from the stored preprocessing;
+    /// [`TableauReasoner::is_subclass_of`] answers from the stored hierarchy, and the
+    /// instance checks reuse the stored consistency result instead of saturating the
+    /// completion graph again. Calling it again without changes does nothing. If
+    /// consistency checking gives up, the preprocessing is kept but no results are
+    /// stored, and the error is returned.
+    pub fn prepare(&mut self) -> Result<(), ConsistencyUnknown> {
+        self.preprocessed();
+        self.previous_results_for_current_revision().map(|_| ())
+    }
+
+    /// Returns the preprocessed TBox and role hierarchy, rebuilding them if the
+    /// ontology's revision has moved on since they were built.
+    fn preprocessed(&mut self) -> &Preprocessed {
+        let revision = self.ontology.change_tracker.revision;
+        if self.preprocessed.as_ref().is_none_or(|preprocessed| preprocessed.revision != revision) {
+            self.preprocessed = Some(Preprocessed {
+                revision,
+                inclusions: Arc::new(ToldInclusions::new(&self.ontology)),
+                role_representatives: Arc::new(role_representatives(&self.ontology)),
+            });
+        }
+        self.preprocessed.as_ref().unwrap()
+    }
+
+    /// Checks whether `sub_class` is subsumed by `super_class`.
+    ///
+    /// After [`TableauReasoner::prepare`] this is a lookup in the stored hierarchy for
+    /// classes of the ontology; otherwise, or for other classes, `C ⊓ ¬D` is tested
+    /// for satisfiability.
+    pub fn is_subclass_of(&self, sub_class: &Class, super_class: &Class) -> bool {
+        let (sub_class, super_class) = (&self.query_class(sub_class), &self.query_class(super_class));
+        if let Some(results) = self.current_results() {
+            let hierarchy = &results.class_hierarchy;
+            let known = |class: &Class| hierarchy.superclasses.contains_key(class) || hierarchy.subclasses.contains_key(class);
+            if known(sub_class) && known(super_class) {
+                return sub_class == super_class
+                    || hierarchy.ancestors(sub_class).contains(super_class)
+                    || hierarchy.equivalent_classes.get(sub_class).is_some_and(|equivalents| equivalents.contains(super_class));
+            }
+        }
+        sub_class == super_class || self.is_subsumed_by(sub_class, super_class)
+    }
+
+    /// Returns the stored reasoning results if they were computed for the current
+    /// revision of the ontology.
+    fn current_results(&self) -> Option<&ReasoningResults> {
+        self.previous_results.as_ref().filter(|results| results.revision == self.ontology.change_tracker.revision)
+    }
+
+    /// Checks whether the ontology is known to be inconsistent for a query, taking the
+    /// stored result if there is one for the current revision. A check that gives up
+    /// does not count as inconsistent, so the query's own refutation decides.
+    fn is_inconsistent_for_query(&mut self) -> bool {
+        match self.current_results() {
+            Some(results) => !results.is_consistent,
+            None => self.check_consistency().is_inconsistent(),
+        }
+    }
+
+    /// Returns the stored reasoning results, recomputing them if the ontology
+    /// revision has moved on since they were computed.
+    fn previous_results_for_current_revision(&mut self) -> Result<&ReasoningResults, ConsistencyUnknown> {
+        let revision = self.ontology.change_tracker.revision;
+        if self.current_results().is_none() {
+            // Classify and realize from the graph just saturated, rather than through
+            // classify and realize, which would each check consistency again
+            let is_consistent = self.is_consistent_incremental()?;
+            let (class_hierarchy, individual_types) = if is_consistent {
+                (self.compute_class_hierarchy(), self.compute_individual_types())
+            } else {
+                (ClassHierarchy::new(), HashMap::new())
+            };
+            self.previous_results = Some(ReasoningResults {
+                class_hierarchy,
+                individual_types,
+                is_consistent,
+                revision,
+            });
+        }
+        Ok(self.previous_results.as_ref().unwrap())
+    }
     
     /// Finds the types of a specific individual.
     fn find_individual_types(&self, individual: &Individual, _classes: &[Class]) -> IndividualTypes {
@@ -325,6 +1778,14 @@ impl TableauReasoner {
             // For now, we'll just use the directly asserted classes as the most specific
             types.most_specific = types.all.clone();
         }
+
+        for axiom in &self.ontology.axioms {
+            if let Axiom::Assertion(crate::Assertion::ClassAssertion { class: ClassExpression::Class(class), individual: asserted_individual }) = axiom
+                && asserted_individual == individual
+                && !types.asserted.contains(class) {
+                    types.asserted.push(class.clone());
+                }
+        }
         
         types
     }
@@ -332,20 +1793,22 @@ impl TableauReasoner {
     /// Checks if an individual is an instance of a class.
     /// This is done by checking if the ontology entails that the individual is an instance of the class.
     pub fn is_instance_of(&mut self, individual: &Individual, class: &Class) -> bool {
+        let (individual, class) = (&self.query_individual(individual), &self.query_class(class));
+
         // First check consistency
-        if !self.is_consistent() {
+        if self.is_inconsistent_for_query() {
             // Return false for inconsistent ontologies
             return false;
         }
         
         // Check if the individual is directly asserted to be an instance of the class
-        if let Some(node) = self.graph.nodes.iter().find(|n| &n.individual == individual) {
+        if let Some(node) = self.graph.nodes.iter().find(|n| &n.individual == individual)
+            && !self.graph_has_choices() {
             for concept in &node.concepts {
-                if let ClassExpression::Class(c) = concept {
-                    if c == class {
+                if let ClassExpression::Class(c) = concept
+                    && c == class {
                         return true;
                     }
-                }
             }
         }
         
@@ -355,10 +1818,7 @@ impl TableauReasoner {
         // 3. Check if this extended ontology is inconsistent
         // 4. If it is inconsistent, then the individual must be an instance of the class
         
-        let mut temp_reasoner = TableauReasoner::new(self.ontology.clone());
-        
-        // Copy the existing graph state
-        temp_reasoner.graph = self.graph.clone();
+        let mut temp_reasoner = self.refutation_reasoner();
         
         // Add the assertion that the individual is an instance of ¬class
         let negated_class = ClassExpression::ObjectComplementOf(Box::new(ClassExpression::Class(class.clone())));
@@ -366,9 +1826,377 @@ impl TableauReasoner {
         
         // Check if this leads to inconsistency
         // If the extended ontology is inconsistent, then the individual must be an instance of the class
-        !temp_reasoner.is_consistent()
+        temp_reasoner.check_consistency().is_inconsistent()
     }
     
+    /// Checks if an individual is an instance of a (possibly complex) class expression.
+    ///
+    /// This is done by asserting the individual into the negation of the expression and
+    /// checking whether that makes the ontology inconsistent. Intersections are checked
+    /// conjunct by conjunct, so each refutation involves a single negated conjunct.
+    pub fn is_instance_of_expression(&mut self, individual: &Individual, expression: &ClassExpression) -> bool {
+        if self.is_inconsistent_for_query() {
+            return false;
+        }
+        self.entails_membership(&mut None, &self.query_individual(individual), &self.query_expression(expression))
+    }
+
+    /// Checks whether the ontology entails an axiom.
+    ///
+    /// Supported axioms are `SubClassOf(C D)` (`C ⊓ ¬D` is unsatisfiable),
+    /// `EquivalentClasses` (every operand subsumes the first and vice versa),
+    /// `DisjointClasses` (every pairwise intersection is unsatisfiable),
+    /// `ClassAssertion` and `ObjectPropertyAssertion` (adding the corresponding
+    /// `NegativeObjectPropertyAssertion` makes the ontology inconsistent). Any other
+    /// axiom is reported as not entailed.
+    pub fn entails(&mut self, axiom: &Axiom) -> bool {
+        match &self.query_axiom(axiom) {
+            Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class, super_class }) => self.entails_subsumption(sub_class, super_class),
+            Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes }) => match classes.split_first() {
+                Some((first, rest)) => rest.iter().all(|other| self.entails_subsumption(first, other) && self.entails_subsumption(other, first)),
+                None => true,
+            },
+            Axiom::Class(crate::ClassAxiom::DisjointClasses { classes }) => classes.iter().enumerate().all(|(i, class)| {
+                classes[i + 1..].iter().all(|other| self.is_unsatisfiable_expression(&ClassExpression::ObjectIntersectionOf(vec![class.clone(), other.clone()])))
+            }),
+            Axiom::Assertion(crate::Assertion::ClassAssertion { class, individual }) => self.is_instance_of_expression(individual, class),
+            Axiom::Assertion(crate::Assertion::ObjectPropertyAssertion { property, source, target }) => {
+                let mut temp_reasoner = self.scratch_reasoner();
+                temp_reasoner.ontology.axioms.push(Axiom::Assertion(crate::Assertion::NegativeObjectPropertyAssertion {
+                    property: property.clone(),
+                    source: source.clone(),
+                    target: target.clone(),
+                }));
+                temp_reasoner.check_consistency().is_inconsistent()
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks whether the ontology entails an axiom, answering `Unknown` rather than
+    /// `False` when the query or the ontology uses a construct the reasoner cannot
+    /// decide.
+    ///
+    /// The supported axioms are those of [`entails`](Self::entails); any other axiom
+    /// is `Unknown`. Undecided constructs are `ObjectHasValue`, `ObjectOneOf`,
+    /// `ObjectHasSelf`, qualified maximum and exact cardinalities, negated cardinality
+    /// restrictions, restrictions on inverse properties, general class inclusions with
+    /// a complex subclass, object property characteristics and chains, axioms relating
+    /// inverse properties, data property domains, `DisjointDataProperties` and
+    /// `NegativeDataPropertyAssertion`.
+    pub fn check_entailment(&mut self, axiom: &Axiom) -> Entailment {
+        let query: Vec<ClassExpression> = match axiom {
+            Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class, super_class }) => vec![sub_class.clone(), negate(super_class)],
+            Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes } | crate::ClassAxiom::DisjointClasses { classes }) => {
+                classes.iter().flat_map(|class| [class.clone(), negate(class)]).collect()
+            }
+            Axiom::Assertion(crate::Assertion::ClassAssertion { class, .. }) => vec![negate(class)],
+            Axiom::Assertion(crate::Assertion::ObjectPropertyAssertion { .. }) => Vec::new(),
+            _ => return Entailment::Unknown,
+        };
+        if self.entails(axiom) {
+            Entailment::True
+        } else if query.iter().all(is_decided_expression) && self.ontology.axioms.iter().all(is_decided_axiom) {
+            Entailment::False
+        } else {
+            Entailment::Unknown
+        }
+    }
+
+    /// Checks whether `sub ⊑ sup` follows from the ontology.
+    fn entails_subsumption(&self, sub: &ClassExpression, sup: &ClassExpression) -> bool {
+        sub == sup || self.is_unsatisfiable_expression(&ClassExpression::ObjectIntersectionOf(vec![sub.clone(), negate(sup)]))
+    }
+
+    /// Checks whether a class expression has no instances, by asserting it for a
+    /// test individual and checking consistency.
+    fn is_unsatisfiable_expression(&self, expression: &ClassExpression) -> bool {
+        let mut temp_reasoner = self.scratch_reasoner();
+        let individual = Individual::Anonymous(crate::NodeID("_:test".to_string()));
+        temp_reasoner.graph.add_concept(&individual, expression.clone());
+        temp_reasoner.check_consistency().is_inconsistent()
+    }
+
+    /// Finds all named individuals that are instances of a named class.
+    ///
+    /// Each named individual in the completion graph is checked with the same
+    /// entailment test as [`is_instance_of`](Self::is_instance_of), so inferred types
+    /// (through subsumption, property domains and ranges) are included as well as
+    /// asserted ones. The result is empty for inconsistent ontologies, and an error if
+    /// consistency checking gave up.
+    pub fn instances_of(&mut self, class: &Class) -> Result<Vec<Individual>, ConsistencyUnknown> {
+        self.get_instances_of_expression(&ClassExpression::Class(class.clone()))
+    }
+
+    /// Computes the object property assertions that are entailed but not asserted.
+    ///
+    /// The edges of the saturated completion graph are closed under the told property
+    /// hierarchy (including inverses), symmetric and transitive properties and property
+    /// chains, and every edge between individuals of the ontology that is not already
+    /// asserted is returned as an `ObjectPropertyAssertion` on a named property. Edges
+    /// to individuals introduced by the reasoner are left out. The result is sorted,
+    /// empty for inconsistent ontologies, and an error if consistency checking gave up.
+    pub fn materialize_object_properties(&mut self) -> Result<Vec<crate::Assertion>, ConsistencyUnknown> {
+        if !self.is_consistent()? {
+            return Ok(Vec::new());
+        }
+
+        let edges = self.close_edges(self.graph_edges());
+        let asserted: HashSet<NamedEdge> = self.ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::Assertion(crate::Assertion::ObjectPropertyAssertion { property, source, target }) => named_edge(property, source, target),
+                _ => None,
+            })
+            .collect();
+        let is_fresh = |individual: &Individual| {
+            matches!(individual, Individual::Anonymous(crate::NodeID(id)) if id.starts_with(&self.graph.fresh_prefix))
+        };
+        let mut inferred: Vec<NamedEdge> = edges.into_iter()
+            .filter(|edge| !asserted.contains(edge) && !is_fresh(&edge.1) && !is_fresh(&edge.2))
+            .collect();
+        inferred.sort();
+        Ok(inferred.into_iter()
+            .map(|(property, source, target)| crate::Assertion::ObjectPropertyAssertion {
+                property: ObjectPropertyExpression::ObjectProperty(property),
+                source,
+                target,
+            })
+            .collect())
+    }
+
+    /// Collects the edges of the completion graph, rewritten onto named properties.
+    fn graph_edges(&self) -> HashSet<NamedEdge> {
+        self.graph.nodes.iter()
+            .flat_map(|node| node.roles.iter().filter_map(|(role, target)| named_edge(role, &node.individual, target)))
+            .collect()
+    }
+
+    /// Closes a set of edges under the told property hierarchy (including inverses),
+    /// symmetric and transitive properties and property chains.
+    ///
+    /// The closure is computed semi-naively: each round only joins the edges derived
+    /// in the previous round with the edges known so far, looked up in an index by
+    /// property and endpoint.
+    fn close_edges(&self, edges: HashSet<NamedEdge>) -> HashSet<NamedEdge> {
+        let mut symmetric = HashSet::new();
+        let mut transitive = HashSet::new();
+        let mut chains = Vec::new();
+        for axiom in &self.ontology.axioms {
+            match axiom {
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::SymmetricObjectProperty { property }) => symmetric.extend(named_property(property)),
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::TransitiveObjectProperty { property }) => transitive.extend(named_property(property)),
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::SubObjectPropertyOf {
+                    sub_property: ObjectPropertyExpression::ObjectPropertyChain(chain),
+                    super_property,
+                }) => chains.push((chain.clone(), super_property.clone())),
+                _ => {}
+            }
+        }
+
+        let mut index = EdgeIndex::default();
+        let mut delta: Vec<NamedEdge> = edges.into_iter().filter(|edge| index.insert(edge)).collect();
+        let mut supers: HashMap<ObjectProperty, Vec<ObjectPropertyExpression>> = HashMap::new();
+        while !delta.is_empty() {
+            let mut derived = Vec::new();
+            for (property, source, target) in &delta {
+                let closure = supers.entry(property.clone())
+                    .or_insert_with(|| told_super_properties(&self.ontology, &ObjectPropertyExpression::ObjectProperty(property.clone())));
+                derived.extend(closure.iter().filter_map(|sup| named_edge(sup, source, target)));
+                if symmetric.contains(property) {
+                    derived.push((property.clone(), target.clone(), source.clone()));
+                }
+                if transitive.contains(property) {
+                    let forward = ObjectPropertyExpression::ObjectProperty(property.clone());
+                    let backward = ObjectPropertyExpression::InverseObjectProperty(property.clone());
+                    derived.extend(index.targets(&forward, target).into_iter().map(|next| (property.clone(), source.clone(), next)));
+                    derived.extend(index.targets(&backward, source).into_iter().map(|previous| (property.clone(), previous, target.clone())));
+                }
+                // Every chain step the edge can take, walked back to the start of the
+                // chain and on to its end
+                for (chain, super_property) in &chains {
+                    for (position, step) in chain.iter().enumerate() {
+                        let (from, to) = match step {
+                            ObjectPropertyExpression::ObjectProperty(p) if p == property => (source, target),
+                            ObjectPropertyExpression::InverseObjectProperty(p) if p == property => (target, source),
+                            _ => continue,
+                        };
+                        let mut starts: HashSet<Individual> = HashSet::from([from.clone()]);
+                        for step in chain[..position].iter().rev() {
+                            let Some(inverse) = inverse_of(step) else { starts.clear(); break };
+                            starts = starts.iter().flat_map(|individual| index.targets(&inverse, individual)).collect();
+                        }
+                        let mut ends: HashSet<Individual> = HashSet::from([to.clone()]);
+                        for step in &chain[position + 1..] {
+                            ends = ends.iter().flat_map(|individual| index.targets(step, individual)).collect();
+                        }
+                        for start in &starts {
+                            derived.extend(ends.iter().filter_map(|end| named_edge(super_property, start, end)));
+                        }
+                    }
+                }
+            }
+            delta = derived.into_iter().filter(|edge| index.insert(edge)).collect();
+        }
+        index.edges
+    }
+
+    /// Finds all named individuals that are instances of the given class expression.
+    ///
+    /// Individuals are returned in the order they appear in the completion graph. The
+    /// result is empty for inconsistent ontologies, and an error if consistency
+    /// checking gave up.
+    pub fn get_instances_of_expression(&mut self, expression: &ClassExpression) -> Result<Vec<Individual>, ConsistencyUnknown> {
+        if !self.is_consistent()? {
+            return Ok(Vec::new());
+        }
+        let expression = &self.query_expression(expression);
+        let named: Vec<Individual> = self.graph.nodes.iter()
+            .filter(|node| matches!(node.individual, Individual::Named(_)))
+            .map(|node| node.individual.clone())
+            .collect();
+        let mut scratch = None;
+        Ok(named.into_iter()
+            .filter(|individual| self.entails_membership(&mut scratch, individual, expression))
+            .collect())
+    }
+
+    /// Finds the direct named subclasses of a class expression.
+    ///
+    /// A named class `C` is a direct subclass of `expression` if `C ⊑ expression` and
+    /// there is no other named class `D` with `C ⊏ D ⊑ expression`. Classes equivalent
+    /// to the expression and unsatisfiable classes are not reported. The result is
+    /// sorted, empty for inconsistent ontologies, and an error if consistency checking
+    /// gave up.
+    pub fn direct_subclasses_of_expression(&mut self, expression: &ClassExpression) -> Result<Vec<Class>, ConsistencyUnknown> {
+        if !self.is_consistent()? {
+            return Ok(Vec::new());
+        }
+        let expression = &self.query_expression(expression);
+
+        let mut classes = self.extract_classes();
+        classes.sort();
+        classes.dedup();
+        let subclasses: Vec<Class> = classes
+            .into_par_iter()
+            .filter(|class| {
+                let named = ClassExpression::Class(class.clone());
+                self.entails_subsumption(&named, expression)
+                    && !self.entails_subsumption(expression, &named)
+                    && self.is_satisfiable(class)
+            })
+            .collect();
+
+        Ok(subclasses.iter()
+            .filter(|&class| {
+                !subclasses.iter().any(|other| {
+                    other != class && self.is_subsumed_by(class, other) && !self.is_subsumed_by(other, class)
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Checks membership on the already saturated graph by refuting the negation.
+    ///
+    /// `scratch` holds the refutation reasoner and its starting graph. It is created on
+    /// the first refutation and reset to that graph before each later one, so callers
+    /// checking several individuals or conjuncts copy the ontology only once.
+    fn entails_membership(&self, scratch: &mut Option<(TableauReasoner, CompletionGraph)>, individual: &Individual, expression: &ClassExpression) -> bool {
+        if let ClassExpression::ObjectIntersectionOf(conjuncts) = expression {
+            return conjuncts.iter().all(|conjunct| self.entails_membership(scratch, individual, conjunct));
+        }
+        if let Some(node) = self.graph.nodes.iter().find(|n| &n.individual == individual)
+            && node.concepts.contains(expression)
+            && !self.graph_has_choices() {
+                return true;
+            }
+
+        let (temp_reasoner, start) = scratch.get_or_insert_with(|| {
+            let reasoner = self.refutation_reasoner();
+            let start = reasoner.graph.clone();
+            (reasoner, start)
+        });
+        temp_reasoner.graph = start.clone();
+        temp_reasoner.graph.add_concept(individual, negate(expression));
+        temp_reasoner.check_consistency().is_inconsistent()
+    }
+
+    /// Finds a chain of told axioms that justifies `sub_class ⊑ super_class`.
+    ///
+    /// Follows SubClassOf and EquivalentClasses axioms between named classes and returns
+    /// the indices (into `ontology.axioms`) of the axioms along the shortest chain, or
+    /// `None` if there is no such chain. A class trivially subsumes itself with an empty
+    /// justification.
+    ///
+    /// Only told edges are followed: a subsumption that is entailed but not reachable
+    /// through told axioms between named classes (for example one that relies on an
+    /// intersection, a restriction or a domain axiom) yields `None`.
+    pub fn subsumption_justification(&self, sub_class: &Class, super_class: &Class) -> Option<Vec<usize>> {
+        use std::collections::VecDeque;
+
+        let (sub_class, super_class) = (&self.query_class(sub_class), &self.query_class(super_class));
+        // Told edges between named classes, labelled with the axiom that states them
+        let mut edges: HashMap<&Class, Vec<(&Class, usize)>> = HashMap::new();
+        for (index, axiom) in self.ontology.axioms.iter().enumerate() {
+            match axiom {
+                crate::Axiom::Class(crate::ClassAxiom::SubClassOf {
+                    sub_class: ClassExpression::Class(sub),
+                    super_class: ClassExpression::Class(sup),
+                }) => {
+                    edges.entry(sub).or_default().push((sup, index));
+                }
+                crate::Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes }) => {
+                    let named: Vec<&Class> = classes.iter()
+                        .filter_map(|c| if let ClassExpression::Class(class) = c { Some(class) } else { None })
+                        .collect();
+                    for a in &named {
+                        for b in &named {
+                            if a != b {
+                                edges.entry(a).or_default().push((b, index));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Breadth-first search, remembering how each class was reached
+        let mut reached_by: HashMap<&Class, Option<(&Class, usize)>> = HashMap::new();
+        reached_by.insert(sub_class, None);
+        let mut queue = VecDeque::from([sub_class]);
+        while let Some(current) = queue.pop_front() {
+            if current == super_class {
+                let mut chain = Vec::new();
+                let mut class = current;
+                while let Some(Some((previous, index))) = reached_by.get(class) {
+                    chain.push(*index);
+                    class = previous;
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+            for (next, index) in edges.get(current).into_iter().flatten() {
+                if !reached_by.contains_key(next) {
+                    reached_by.insert(next, Some((current, *index)));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Reports the axioms justifying `sub_class ⊑ super_class` together with the IRI of the
+    /// imported ontology each one came from (`None` for axioms asserted locally).
+    ///
+    /// Returns `None` if no justification is found.
+    pub fn subsumption_provenance(&self, sub_class: &Class, super_class: &Class) -> Option<Vec<(crate::Axiom, Option<crate::IRI>)>> {
+        let justification = self.subsumption_justification(sub_class, super_class)?;
+        Some(justification.into_iter()
+            .map(|index| (self.ontology.axioms[index].clone(), self.ontology.axiom_source(index).cloned()))
+            .collect())
+    }
+
     /// Extracts all classes mentioned in the ontology.
     fn extract_classes(&self) -> Vec<Class> {
         use std::collections::HashSet;
@@ -414,21 +2242,17 @@ impl TableauReasoner {
                     }
                 }
                 crate::Axiom::DataProperty(data_property_axiom) => {
-                    match data_property_axiom {
-                        crate::DataPropertyAxiom::DataPropertyDomain { property: _, domain } => {
-                            self.extract_classes_from_expression(domain, &mut classes);
-                        }
-                        _ => {}
+                    if let crate::DataPropertyAxiom::DataPropertyDomain { property: _, domain } = data_property_axiom {
+                        self.extract_classes_from_expression(domain, &mut classes);
                     }
                 }
                 crate::Axiom::Assertion(assertion) => {
-                    match assertion {
-                        crate::Assertion::ClassAssertion { class, individual: _ } => {
-                            self.extract_classes_from_expression(class, &mut classes);
-                        }
-                        _ => {}
+                    if let crate::Assertion::ClassAssertion { class, individual: _ } = assertion {
+                        self.extract_classes_from_expression(class, &mut classes);
                     }
                 }
+                crate::Axiom::Declaration(crate::Entity::Class(class)) => classes.push(class.clone()),
+                crate::Axiom::Declaration(_) | crate::Axiom::Annotation(_) => {}
             }
         }
         
@@ -470,20 +2294,14 @@ impl TableauReasoner {
             ClassExpression::ObjectAllValuesFrom { property: _, filler } => {
                 self.extract_classes_from_expression(filler, classes);
             }
-            ClassExpression::ObjectMinCardinality { property: _, filler, .. } => {
-                if let Some(filler_expr) = filler {
-                    self.extract_classes_from_expression(filler_expr, classes);
-                }
+            ClassExpression::ObjectMinCardinality { property: _, filler: Some(filler_expr), .. } => {
+                self.extract_classes_from_expression(filler_expr, classes);
             }
-            ClassExpression::ObjectMaxCardinality { property: _, filler, .. } => {
-                if let Some(filler_expr) = filler {
-                    self.extract_classes_from_expression(filler_expr, classes);
-                }
+            ClassExpression::ObjectMaxCardinality { property: _, filler: Some(filler_expr), .. } => {
+                self.extract_classes_from_expression(filler_expr, classes);
             }
-            ClassExpression::ObjectExactCardinality { property: _, filler, .. } => {
-                if let Some(filler_expr) = filler {
-                    self.extract_classes_from_expression(filler_expr, classes);
-                }
+            ClassExpression::ObjectExactCardinality { property: _, filler: Some(filler_expr), .. } => {
+                self.extract_classes_from_expression(filler_expr, classes);
             }
             _ => {}
         }
@@ -493,7 +2311,7 @@ impl TableauReasoner {
     /// This is done by checking if C ⊓ ¬D is unsatisfiable.
     fn is_subsumed_by(&self, class_c: &Class, class_d: &Class) -> bool {
         // Create a temporary reasoner for this subsumption check
-        let mut temp_reasoner = TableauReasoner::new(self.ontology.clone());
+        let mut temp_reasoner = self.scratch_reasoner();
         
         // Add a nominal individual that is an instance of C and not D
         let individual = Individual::Anonymous(crate::NodeID("_:test".to_string()));
@@ -505,134 +2323,690 @@ impl TableauReasoner {
         temp_reasoner.graph.add_concept(&individual, intersection_expr);
         
         // Check if this is consistent - if not, then C is subsumed by D
-        !temp_reasoner.is_consistent()
+        temp_reasoner.check_consistency().is_inconsistent()
     }
     
-    /// Checks if there are any clashes in the completion graph.
+    /// Returns the first clash in the completion graph, if any.
     /// A clash occurs when an individual is both an instance of a class and its complement.
-    fn has_clash(&self) -> bool {
+    fn find_clash(&mut self) -> Option<Clash> {
         // For now, we'll implement a simple clash detection
         // In a more complete implementation, we would need to handle more complex cases
         
         for node in &self.graph.nodes {
             for concept in &node.concepts {
+                if matches!(concept, ClassExpression::Class(class) if class.is_nothing()) {
+                    return Some(Clash::Nothing { individual: node.individual.clone() });
+                }
                 if let ClassExpression::ObjectComplementOf(complement) = concept {
                     // Check if the node also has the complemented concept
                     if node.concepts.contains(complement) {
-                        return true; // Clash found
+                        return Some(Clash::Complement {
+                            individual: node.individual.clone(),
+                            class: (**complement).clone(),
+                        });
                     }
                 }
             }
         }
         
-        false // No clash found
+        if let Some(clash) = self.find_same_and_different_clash()
+            .or_else(|| self.find_max_cardinality_clash())
+            .or_else(|| self.find_disjoint_property_clash()) {
+            return Some(clash);
+        }
+        self.find_negative_property_clash()
+            .or_else(|| self.find_data_range_clash())
     }
-    
-    /// Applies the conjunction rule to the completion graph.
-    /// If an individual is an instance of ObjectIntersectionOf(C1, C2, ..., Cn),
-    /// then it is also an instance of each of C1, C2, ..., Cn.
-    pub fn apply_conjunction_rule(&mut self) -> bool {
-        let mut new_concepts_added = true;
-        let mut any_added = false;
-        while new_concepts_added {
-            new_concepts_added = false;
-            
-            // Clone the current nodes to avoid borrowing issues
-            let nodes_clone = self.graph.nodes.clone();
-            
-            for node in &nodes_clone {
-                let individual = &node.individual;
-                for concept in &node.concepts {
-                    if let ClassExpression::ObjectIntersectionOf(conjuncts) = concept {
-                        for conjunct in conjuncts {
-                            // Check if this concept is already in the node
-                            let node_mut = self.graph.get_or_create_node(individual);
-                            if !node_mut.concepts.contains(conjunct) {
-                                node_mut.concepts.push(conjunct.clone());
-                                new_concepts_added = true;
-                                any_added = true;
-                            }
-                        }
+
+    /// Looks for a `NegativeObjectPropertyAssertion` whose edge is present in the
+    /// graph, directly or through the property hierarchy, inverses, symmetry,
+    /// transitivity or property chains.
+    ///
+    /// The closure of the graph's edges is kept between checks and only recomputed
+    /// when the edges changed, so branches of the search that only add concepts reuse
+    /// it.
+    fn find_negative_property_clash(&mut self) -> Option<Clash> {
+        if !self.ontology.axioms.iter().any(|axiom| matches!(axiom, Axiom::Assertion(crate::Assertion::NegativeObjectPropertyAssertion { .. }))) {
+            return None;
+        }
+        let base = self.graph_edges();
+        if self.property_closure.as_ref().is_none_or(|(cached, _)| *cached != base) {
+            let closure = self.close_edges(base.clone());
+            self.property_closure = Some((base, closure));
+        }
+        let edges = &self.property_closure.as_ref()?.1;
+        self.ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::Assertion(crate::Assertion::NegativeObjectPropertyAssertion { property, source, target }) => Some((property, source, target)),
+                _ => None,
+            })
+            .find(|(property, source, target)| named_edge(property, source, target).is_some_and(|edge| edges.contains(&edge)))
+            .map(|(property, source, target)| Clash::NegativePropertyAssertion {
+                source: source.clone(),
+                target: target.clone(),
+                property: property.clone(),
+            })
+    }
+
+    /// Looks for two individuals asserted with `SameIndividual`, or whose nodes were
+    /// merged, that are also known to be different.
+    fn find_same_and_different_clash(&self) -> Option<Clash> {
+        if let Some((a, b)) = self.graph.identities.iter().find(|(a, b)| self.graph.are_distinct(a, b)) {
+            return Some(Clash::SameAndDifferent { individuals: (a.clone(), b.clone()) });
+        }
+        for axiom in &self.ontology.axioms {
+            if let Axiom::Assertion(crate::Assertion::SameIndividual { individuals }) = axiom {
+                for (i, a) in individuals.iter().enumerate() {
+                    if let Some(b) = individuals[i + 1..].iter().find(|b| self.graph.are_distinct(a, b)) {
+                        return Some(Clash::SameAndDifferent { individuals: (a.clone(), b.clone()) });
                     }
                 }
             }
         }
-        any_added
+        None
     }
-    
-    /// Applies the disjunction rule to the completion graph.
-    /// If an individual is an instance of ObjectUnionOf(C1, C2, ..., Cn),
-    /// then we nondeterministically choose one of C1, C2, ..., Cn to add to the individual's concepts.
-    /// For simplicity, we choose the first one.
-    pub fn apply_disjunction_rule(&mut self) -> bool {
-        let mut new_concept_added = false;
-        
-        // Clone the current nodes to avoid borrowing issues
-        let nodes_clone = self.graph.nodes.clone();
-        
-        for node in &nodes_clone {
-            let individual = &node.individual;
-            for concept in &node.concepts {
-                if let ClassExpression::ObjectUnionOf(disjuncts) = concept {
-                    if !disjuncts.is_empty() {
-                        // Choose the first disjunct
-                        let first_disjunct = &disjuncts[0];
-                        
-                        // Check if this concept is already in the node
-                        let node_mut = self.graph.get_or_create_node(individual);
-                        if !node_mut.concepts.contains(first_disjunct) {
-                            node_mut.concepts.push(first_disjunct.clone());
-                            new_concept_added = true;
+
+    /// Looks for an ordered pair of individuals connected by two properties that are
+    /// declared disjoint. Edges are closed under the told property hierarchy
+    /// (SubObjectPropertyOf and EquivalentObjectProperties), so inherited edges count.
+    fn find_disjoint_property_clash(&self) -> Option<Clash> {
+        use std::collections::HashSet;
+
+        let disjoint_sets: Vec<&Vec<ObjectPropertyExpression>> = self.ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::DisjointObjectProperties { properties }) => Some(properties),
+                _ => None,
+            })
+            .collect();
+        if disjoint_sets.is_empty() {
+            return None;
+        }
+
+        // The properties (and inherited super-properties) holding between each ordered
+        // pair, with the pairs kept in the order they were first seen
+        let mut pairs: Vec<(&Individual, &Individual)> = Vec::new();
+        let mut pair_properties: HashMap<(&Individual, &Individual), HashSet<ObjectPropertyExpression>> = HashMap::new();
+        let mut closures: HashMap<&ObjectPropertyExpression, Vec<ObjectPropertyExpression>> = HashMap::new();
+        for node in &self.graph.nodes {
+            for (property, target) in &node.roles {
+                let closure = closures.entry(property).or_insert_with(|| self.told_super_properties(property));
+                for held in closure.iter() {
+                    let mut edges = vec![((&node.individual, target), held.clone())];
+                    if let Some(inverse) = inverse_of(held) {
+                        edges.push(((target, &node.individual), inverse));
+                    }
+                    for (pair, held) in edges {
+                        if !pair_properties.contains_key(&pair) {
+                            pairs.push(pair);
                         }
+                        pair_properties.entry(pair).or_default().insert(held);
                     }
                 }
             }
         }
-        
-        new_concept_added
-    }
-    
-    /// Applies the existential rule to the completion graph.
-    /// If an individual is an instance of ObjectSomeValuesFrom(R, C),
-    /// then there must exist another individual y such that:
+
+        for (source, target) in pairs {
+            let held = &pair_properties[&(source, target)];
+            for properties in &disjoint_sets {
+                for (i, r) in properties.iter().enumerate() {
+                    if !held.contains(r) {
+                        continue;
+                    }
+                    if let Some(s) = properties[i + 1..].iter().find(|s| *s != r && held.contains(*s)) {
+                        return Some(Clash::DisjointProperties {
+                            source: source.clone(),
+                            target: target.clone(),
+                            properties: (r.clone(), s.clone()),
+                        });
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Looks for a data property value that lies outside one of the property's
+    /// declared ranges, a value of `owl:bottomDataProperty`, or a functional data
+    /// property with two different values. Values are closed under the told data
+    /// property hierarchy, and a range membership that cannot be decided is no clash.
+    ///
+    /// A node holding `DataSomeValuesFrom(P R)` clashes when no value can satisfy it:
+    /// R is empty, or R is a `DataOneOf` whose literals all lie outside P's ranges.
+    /// When P is functional, its asserted value must also lie in R.
+    fn find_data_range_clash(&self) -> Option<Clash> {
+        let ranges = self.ontology.data_property_ranges();
+        let top = DataProperty::top();
+        if let (Some(range), Some(node)) = (ranges.get(&top).and_then(|ranges| ranges.iter().find(|range| is_empty_data_range(range))), self.graph.nodes.first()) {
+            return Some(Clash::EmptyDataRange {
+                individual: node.individual.clone(),
+                property: top,
+                range: range.clone(),
+            });
+        }
+
+        let values = self.data_property_values();
+        if let Some((individual, _, value)) = values.iter().find(|(_, property, _)| property.is_bottom()) {
+            return Some(Clash::BottomDataProperty {
+                individual: individual.clone(),
+                value: value.clone(),
+            });
+        }
+        for (individual, property, value) in &values {
+            if let Some(range) = ranges.get(property).and_then(|ranges| ranges.iter().find(|range| is_empty_data_range(range))) {
+                return Some(Clash::EmptyDataRange {
+                    individual: individual.clone(),
+                    property: property.clone(),
+                    range: range.clone(),
+                });
+            }
+            if let Some(range) = ranges.get(property).and_then(|ranges| ranges.iter().find(|range| literal_in_range(value, range) == Membership::NonMember)) {
+                return Some(Clash::DataRange {
+                    individual: individual.clone(),
+                    property: property.clone(),
+                    value: value.clone(),
+                    range: range.clone(),
+                });
+            }
+        }
+        let functional: HashSet<&DataProperty> = self.ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::DataProperty(crate::DataPropertyAxiom::FunctionalDataProperty { property }) => Some(property),
+                _ => None,
+            })
+            .collect();
+        // Value equality is transitive, so each value only needs comparing with the
+        // first value of the same individual and property
+        let mut first_values: HashMap<(&Individual, &DataProperty), &Literal> = HashMap::new();
+        for (individual, property, second) in &values {
+            if !functional.contains(property) {
+                continue;
+            }
+            let first = *first_values.entry((individual, property)).or_insert(second);
+            if !literals_value_equal(first, second) {
+                return Some(Clash::FunctionalDataProperty {
+                    individual: individual.clone(),
+                    property: property.clone(),
+                    values: (first.clone(), second.clone()),
+                });
+            }
+        }
+
+        for node in &self.graph.nodes {
+            for concept in &node.concepts {
+                let ClassExpression::DataSomeValuesFrom { property, filler } = concept else {
+                    continue;
+                };
+                let property_ranges = ranges.get(property).map(Vec::as_slice).unwrap_or_default();
+                let excluded = |literal: &Literal| property_ranges.iter().any(|range| literal_in_range(literal, range) == Membership::NonMember);
+                if is_empty_data_range(filler) || matches!(filler, DataRange::DataOneOf(literals) if literals.iter().all(excluded)) {
+                    return Some(Clash::EmptyDataRange {
+                        individual: node.individual.clone(),
+                        property: property.clone(),
+                        range: filler.clone(),
+                    });
+                }
+                // The asserted value of a functional property is the only value there is
+                if let Some(value) = first_values.get(&(&node.individual, property))
+                    && literal_in_range(value, filler) == Membership::NonMember {
+                        return Some(Clash::DataRange {
+                            individual: node.individual.clone(),
+                            property: property.clone(),
+                            value: (*value).clone(),
+                            range: filler.clone(),
+                        });
+                    }
+            }
+        }
+        None
+    }
+
+    /// Collects the asserted data property values, closed under the told data
+    /// property hierarchy: a value of a property is also a value of each of its
+    /// super-properties and equivalent properties.
+    fn data_property_values(&self) -> Vec<(Individual, DataProperty, Literal)> {
+        let mut closures: HashMap<&DataProperty, Vec<DataProperty>> = HashMap::new();
+        let mut values = Vec::new();
+        let mut seen = HashSet::new();
+        for axiom in &self.ontology.axioms {
+            if let Axiom::Assertion(crate::Assertion::DataPropertyAssertion { property, source, target }) = axiom {
+                let closure = closures.entry(property).or_insert_with(|| self.told_super_data_properties(property));
+                for held in closure.iter() {
+                    let value = (source.clone(), held.clone(), target.clone());
+                    if seen.insert(value.clone()) {
+                        values.push(value);
+                    }
+                }
+            }
+        }
+        values
+    }
+
+    /// Returns `property` together with all its told super-properties
+    /// (SubDataPropertyOf and EquivalentDataProperties) and `owl:topDataProperty`,
+    /// which every data property is a sub-property of.
+    fn told_super_data_properties(&self, property: &DataProperty) -> Vec<DataProperty> {
+        let mut result = vec![property.clone()];
+        let mut index = 0;
+        while index < result.len() {
+            let current = result[index].clone();
+            for axiom in &self.ontology.axioms {
+                let supers: Vec<DataProperty> = match axiom {
+                    Axiom::DataProperty(crate::DataPropertyAxiom::SubDataPropertyOf { sub_property, super_property })
+                        if *sub_property == current => vec![super_property.clone()],
+                    Axiom::DataProperty(crate::DataPropertyAxiom::EquivalentDataProperties { properties })
+                        if properties.contains(&current) => properties.clone(),
+                    _ => Vec::new(),
+                };
+                for sup in supers {
+                    if !result.contains(&sup) {
+                        result.push(sup);
+                    }
+                }
+            }
+            index += 1;
+        }
+        if !result.iter().any(DataProperty::is_top) {
+            result.push(DataProperty::top());
+        }
+        result
+    }
+
+    /// Returns `property` together with all its told super-properties.
+    fn told_super_properties(&self, property: &ObjectPropertyExpression) -> Vec<ObjectPropertyExpression> {
+        told_super_properties(&self.ontology, property)
+    }
+    
+    /// Applies the conjunction rule to the completion graph.
+    /// If an individual is an instance of ObjectIntersectionOf(C1, C2, ..., Cn),
+    /// then it is also an instance of each of C1, C2, ..., Cn.
+    pub fn apply_conjunction_rule(&mut self) -> bool {
+        ConjunctionRule.apply(&mut self.graph, &self.ontology)
+    }
+    
+    /// Applies the disjunction rule to the completion graph.
+    /// If an individual is an instance of ObjectUnionOf(C1, C2, ..., Cn), one of
+    /// C1, C2, ..., Cn is added to the individual's concepts.
+    ///
+    /// This commits to the first disjunct, the first branch consistency checking
+    /// would try; [`TableauReasoner::check_consistency`] branches over all of them
+    /// and backtracks when a branch clashes.
+    pub fn apply_disjunction_rule(&mut self) -> bool {
+        DisjunctionRule.apply(&mut self.graph, &self.ontology)
+    }
+    
+    /// Applies the existential rule to the completion graph.
+    /// If an individual is an instance of ObjectSomeValuesFrom(R, C),
+    /// then there must exist another individual y such that:
     /// 1. The first individual is connected to y via role R
     /// 2. y is an instance of C
+    ///
+    /// Blocked nodes are skipped.
     pub fn apply_existential_rule(&mut self) -> bool {
-        let mut new_assertion_added = false;
+        ExistentialRule.apply(&mut self.graph, &self.ontology)
+    }
+    
+    /// Applies the universal rule to the completion graph.
+    /// If an individual is an instance of ObjectAllValuesFrom(R, C),
+    /// then for every individual y such that the first individual is connected to y via role R,
+    /// y must be an instance of C.
+    pub fn apply_universal_rule(&mut self) -> bool {
+        UniversalRule.apply(&mut self.graph, &self.ontology)
+    }
+
+    /// Applies the domain rule to the completion graph.
+    /// If a node has an R-successor and R (or a told super-property) has the domain C,
+    /// then the node is an instance of C.
+    pub fn apply_domain_rule(&mut self) -> bool {
+        DomainRule.apply(&mut self.graph, &self.ontology)
+    }
+
+    /// Applies the min-cardinality rule to the completion graph.
+    /// If an individual is an instance of ObjectMinCardinality(n R C) and has fewer than n
+    /// R-successors that are instances of C, fresh R-successors in C are created until
+    /// there are n. An unqualified restriction (no filler) uses owl:Thing as C.
+    pub fn apply_min_cardinality_rule(&mut self) -> bool {
+        MinCardinalityRule.apply(&mut self.graph, &self.ontology)
+    }
+
+    /// Applies the max-cardinality rule to the completion graph.
+    /// If an individual is an instance of ObjectMaxCardinality(n R C) and has n pairwise
+    /// distinct R-successors in C, every other R-successor in C that can only be the
+    /// same as one of them is merged with it.
+    pub fn apply_max_cardinality_rule(&mut self) -> bool {
+        MaxCardinalityRule.apply(&mut self.graph, &self.ontology)
+    }
+
+    /// Applies the exact-cardinality rule to the completion graph.
+    /// If an individual is an instance of ObjectExactCardinality(n R C), then it is also
+    /// an instance of ObjectMinCardinality(n R C) and ObjectMaxCardinality(n R C).
+    pub fn apply_exact_cardinality_rule(&mut self) -> bool {
+        ExactCardinalityRule.apply(&mut self.graph, &self.ontology)
+    }
+
+    /// Looks for a node whose `ObjectMaxCardinality(n R C)` is exceeded by more than
+    /// `n` R-successors in C that are known to be pairwise distinct. All subsets of the
+    /// successors are considered (see [`has_distinct_subset`]).
+    fn find_max_cardinality_clash(&self) -> Option<Clash> {
+        for (node_index, node) in self.graph.nodes.iter().enumerate() {
+            for concept in &node.concepts {
+                if let ClassExpression::ObjectMaxCardinality { max, property, filler } = concept {
+                    let successors = self.graph.successors_in(node_index, property, &filler_or_thing(filler));
+                    let successors: Vec<&Individual> = successors.iter().collect();
+                    if has_distinct_subset(&self.graph, &successors, *max as usize + 1) {
+                        return Some(Clash::MaxCardinality {
+                            individual: node.individual.clone(),
+                            restriction: concept.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The conjunction rule.
+/// If an individual is an instance of ObjectIntersectionOf(C1, C2, ..., Cn),
+/// then it is also an instance of each of C1, C2, ..., Cn.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConjunctionRule;
+
+impl ExpansionRule for ConjunctionRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut new_concepts_added = true;
+        let mut any_added = false;
+        while new_concepts_added {
+            new_concepts_added = false;
+            
+            // Clone the current nodes to avoid borrowing issues
+            let nodes_clone = graph.nodes.clone();
+            
+            for node in &nodes_clone {
+                let individual = &node.individual;
+                for concept in &node.concepts {
+                    if let ClassExpression::ObjectIntersectionOf(conjuncts) = concept {
+                        for conjunct in conjuncts {
+                            // Check if this concept is already in the node
+                            let node_mut = graph.get_or_create_node(individual);
+                            if !node_mut.concepts.contains(conjunct) {
+                                node_mut.concepts.push(conjunct.clone());
+                                new_concepts_added = true;
+                                any_added = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        any_added
+    }
+}
+
+/// Collects the class inclusions `C ⊑ D` stated by the ontology's class axioms.
+///
+/// `SubClassOf` and `EquivalentClasses` are taken as written. `DisjointClasses(C1 ... Cn)`
+/// becomes `Ci ⊑ ¬Cj` for every pair, and `DisjointUnion(C, D1 ... Dn)` becomes
+/// `C ≡ ObjectUnionOf(D1 ... Dn)` plus the pairwise disjointness of the Di.
+fn told_inclusions(ontology: &Ontology) -> Vec<(ClassExpression, ClassExpression)> {
+    fn pairwise(classes: &[ClassExpression], inclusions: &mut Vec<(ClassExpression, ClassExpression)>, map: impl Fn(&ClassExpression) -> ClassExpression) {
+        for (i, sub_class) in classes.iter().enumerate() {
+            for (j, other) in classes.iter().enumerate() {
+                if i != j && sub_class != other {
+                    inclusions.push((sub_class.clone(), map(other)));
+                }
+            }
+        }
+    }
+
+    let mut inclusions = Vec::new();
+    for axiom in &ontology.axioms {
+        match axiom {
+            Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class, super_class }) => {
+                inclusions.push((sub_class.clone(), super_class.clone()));
+            }
+            Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes }) => {
+                pairwise(classes, &mut inclusions, ClassExpression::clone);
+            }
+            Axiom::Class(crate::ClassAxiom::DisjointClasses { classes }) => {
+                pairwise(classes, &mut inclusions, negate);
+            }
+            Axiom::Class(crate::ClassAxiom::DisjointUnion { class, disjoint_classes }) => {
+                let union = ClassExpression::ObjectUnionOf(disjoint_classes.clone());
+                inclusions.push((ClassExpression::Class(class.clone()), union.clone()));
+                inclusions.push((union, ClassExpression::Class(class.clone())));
+                for member in disjoint_classes {
+                    inclusions.push((member.clone(), ClassExpression::Class(class.clone())));
+                }
+                pairwise(disjoint_classes, &mut inclusions, negate);
+            }
+            _ => {}
+        }
+    }
+    inclusions
+}
+
+/// The told class inclusions of an ontology, split the way [`SubsumptionRule`] and
+/// [`GciRule`] use them.
+#[derive(Debug, Default)]
+struct ToldInclusions {
+    /// The told superclasses of each named class
+    named: HashMap<Class, Vec<ClassExpression>>,
+    /// The inclusions with a complex subclass, in negation normal form. `¬A ⊑ D` for a
+    /// named class A is internalized as `owl:Thing ⊑ A ⊔ D`.
+    general: Vec<(ClassExpression, ClassExpression)>,
+}
+
+impl ToldInclusions {
+    fn new(ontology: &Ontology) -> Self {
+        let mut inclusions = ToldInclusions::default();
+        for (sub_class, super_class) in told_inclusions(ontology) {
+            match sub_class {
+                ClassExpression::Class(sub) => inclusions.named.entry(sub).or_default().push(super_class),
+                ClassExpression::ObjectComplementOf(inner) => inclusions.general.push(match negate(&inner) {
+                    ClassExpression::ObjectComplementOf(named) if matches!(*named, ClassExpression::Class(_)) => {
+                        (ClassExpression::Class(Class::thing()), ClassExpression::ObjectUnionOf(vec![*named, super_class]))
+                    }
+                    normalized => (normalized, super_class),
+                }),
+                sub_class => inclusions.general.push((sub_class, super_class)),
+            }
+        }
+        inclusions
+    }
+}
+
+/// The told subsumption rule.
+/// If an individual is an instance of a named class A and the ontology contains
+/// SubClassOf(A, C) or EquivalentClasses(A, C, ...), then it is also an instance of C.
+/// `DisjointClasses(A, B, ...)` contributes `SubClassOf(A, ObjectComplementOf(B))`,
+/// and `DisjointUnion` is read as the matching equivalence plus disjointness.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubsumptionRule;
+
+impl ExpansionRule for SubsumptionRule {
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool {
+        let inclusions = graph.told_inclusions(ontology);
+        let told = &inclusions.named;
+        if told.is_empty() {
+            return false;
+        }
+
+        let mut new_concept_added = false;
+        for node in &mut graph.nodes {
+            let mut index = 0;
+            // Newly added named classes are unfolded in the same pass
+            while index < node.concepts.len() {
+                if let ClassExpression::Class(class) = &node.concepts[index]
+                    && let Some(super_classes) = told.get(class) {
+                        for super_class in super_classes {
+                            if !node.concepts.contains(super_class) {
+                                node.concepts.push(super_class.clone());
+                                new_concept_added = true;
+                            }
+                        }
+                    }
+                index += 1;
+            }
+        }
+        new_concept_added
+    }
+}
+
+/// The general concept inclusion rule.
+/// If the ontology contains SubClassOf(C, D) where C is not a named class (a general
+/// concept inclusion, e.g. `∃R.A ⊑ B`), or EquivalentClasses(C, D, ...) with such a
+/// C, then every individual that is known to be an instance of C is also an instance
+/// of D. Complex members of `DisjointClasses` and `DisjointUnion` are handled the
+/// same way as in [`SubsumptionRule`].
+///
+/// An individual is known to be an instance of C when the completion graph shows it
+/// directly: it holds C itself, or, for example, it has an R-successor in A for
+/// `C = ∃R.A`. No case analysis is done, so the rule never adds D unsoundly.
+///
+/// C is first brought into negation normal form. When it is then a negated named
+/// class, `¬A ⊑ D` is internalized instead: every individual gets `A ⊔ D`, and the
+/// disjunction is decided by the tableau's branching like any other.
+///
+/// Other GCIs are only matched against what the graph shows, which is an
+/// approximation: an individual that is an instance of C only by entailment (say,
+/// through a disjunction every branch of which implies C) does not receive D, so some
+/// consequences of such GCIs are missed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GciRule;
+
+impl ExpansionRule for GciRule {
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool {
+        let inclusions = graph.told_inclusions(ontology);
+
+        let mut new_concept_added = false;
+        for node_index in 0..graph.nodes.len() {
+            for (sub_class, super_class) in &inclusions.general {
+                if !graph.nodes[node_index].concepts.contains(super_class) && graph.is_known_instance(node_index, sub_class) {
+                    graph.nodes[node_index].concepts.push(super_class.clone());
+                    new_concept_added = true;
+                }
+            }
+        }
+        new_concept_added
+    }
+}
+
+/// The disjunction rule.
+/// If an individual is an instance of ObjectUnionOf(C1, C2, ..., Cn) and of none of
+/// C1, C2, ..., Cn, then one of them is added to the individual's concepts.
+///
+/// The rule is nondeterministic: [`TableauReasoner::check_consistency`] does not apply
+/// it but branches over every disjunct in turn, backtracking to the next one when a
+/// branch clashes, and [`TableauReasoner::find_models`] searches over all the choices.
+/// Applied directly, the rule takes the first disjunct, which is the first branch.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisjunctionRule;
+
+impl ExpansionRule for DisjunctionRule {
+    fn is_nondeterministic(&self) -> bool {
+        true
+    }
+
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut new_concept_added = false;
         
         // Clone the current nodes to avoid borrowing issues
-        let nodes_clone = self.graph.nodes.clone();
+        let nodes_clone = graph.nodes.clone();
         
         for node in &nodes_clone {
+            let individual = &node.individual;
+            for concept in &node.concepts {
+                if let ClassExpression::ObjectUnionOf(disjuncts) = concept
+                    && !disjuncts.is_empty()
+                    && !disjuncts.iter().any(|disjunct| node.concepts.contains(disjunct)) {
+                        // Choose the first disjunct
+                        let first_disjunct = &disjuncts[0];
+                        
+                        // Check if this concept is already in the node
+                        let node_mut = graph.get_or_create_node(individual);
+                        if !node_mut.concepts.contains(first_disjunct) {
+                            node_mut.concepts.push(first_disjunct.clone());
+                            new_concept_added = true;
+                        }
+                    }
+            }
+        }
+        
+        new_concept_added
+    }
+}
+
+/// The existential rule.
+/// If an individual is an instance of ObjectSomeValuesFrom(R, C),
+/// then there must exist another individual y such that:
+/// 1. The first individual is connected to y via role R
+/// 2. y is an instance of C
+///
+/// An existing R-successor only counts as y if it already holds C; otherwise y is a
+/// fresh successor, or a shared witness for the same restriction (see [`Witness`]).
+///
+/// Blocked nodes (see [`CompletionGraph::is_blocked`]) are skipped, which keeps
+/// expansion finite for cyclic axioms such as `SubClassOf(A, ObjectSomeValuesFrom(r, A))`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExistentialRule;
+
+impl ExpansionRule for ExistentialRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut new_assertion_added = false;
+        
+        // Clone the current nodes to avoid borrowing issues
+        let nodes_clone = graph.nodes.clone();
+        
+        for (index, node) in nodes_clone.iter().enumerate() {
+            if graph.is_blocked(index) {
+                continue;
+            }
             let individual = &node.individual;
             for concept in &node.concepts {
                 if let ClassExpression::ObjectSomeValuesFrom { property, filler } = concept {
-                    // Check if there's already a role assertion for this property from this individual
                     // We need to find the index of the node to avoid borrowing issues
-                    let node_index = self.graph.nodes.iter().position(|n| &n.individual == individual).unwrap();
-                    let existing_target = self.graph.nodes[node_index].roles.iter().find(|(p, _)| p == property).map(|(_, target)| target.clone());
+                    let node_index = graph.nodes.iter().position(|n| &n.individual == individual).unwrap();
+                    // The restriction is satisfied by an R-successor that already holds the
+                    // filler. Other successors, named or created for other restrictions,
+                    // are left alone: giving them the filler could merge successors that
+                    // must stay apart, as in ∃R.A ⊓ ∃R.¬A.
+                    let satisfied = !graph.successors_in(node_index, property, filler).is_empty();
                     
-                    if let Some(target) = existing_target {
-                        // There's already a target for this role, ensure it has the filler concept
-                        // Find the target node index
-                        if let Some(target_index) = self.graph.nodes.iter().position(|n| &n.individual == &target) {
-                            if !self.graph.nodes[target_index].concepts.contains(filler) {
-                                self.graph.nodes[target_index].concepts.push((**filler).clone());
-                                new_assertion_added = true;
-                            }
+                    if !satisfied {
+                        // Reuse a witness created for the same restriction by a source with
+                        // the same universal restrictions, if there is one
+                        let mut constraints: Vec<ClassExpression> = node.concepts.iter()
+                            .filter(|c| matches!(c, ClassExpression::ObjectAllValuesFrom { .. }))
+                            .cloned()
+                            .collect();
+                        constraints.sort();
+                        constraints.dedup();
+                        let shared = graph.witnesses.iter()
+                            .filter(|_| graph.share_witnesses)
+                            .find(|w| &w.property == property && w.filler == **filler && w.constraints == constraints)
+                            .map(|w| w.individual.clone());
+
+                        if let Some(witness) = shared {
+                            graph.push_role(node_index, property, witness);
+                        } else {
+                            // Create a fresh individual as the target
+                            let fresh_individual = graph.fresh_individual();
+                            graph.push_role(node_index, property, fresh_individual.clone());
+
+                            // Add the filler concept to the fresh individual
+                            graph.nodes.push(Node {
+                                individual: fresh_individual.clone(),
+                                concepts: vec![(**filler).clone()],
+                                roles: vec![],
+                            });
+                            graph.witnesses.push(Witness {
+                                property: property.clone(),
+                                filler: (**filler).clone(),
+                                constraints,
+                                individual: fresh_individual,
+                            });
                         }
-                    } else {
-                        // Create a fresh individual as the target
-                        let fresh_individual = self.graph.fresh_individual();
-                        self.graph.nodes[node_index].roles.push((property.clone(), fresh_individual.clone()));
-                        
-                        // Add the filler concept to the fresh individual
-                        self.graph.nodes.push(Node {
-                            individual: fresh_individual.clone(),
-                            concepts: vec![(**filler).clone()],
-                            roles: vec![],
-                        });
-                        
+
                         new_assertion_added = true;
                     }
                 }
@@ -641,16 +3015,21 @@ impl TableauReasoner {
         
         new_assertion_added
     }
-    
-    /// Applies the universal rule to the completion graph.
-    /// If an individual is an instance of ObjectAllValuesFrom(R, C),
-    /// then for every individual y such that the first individual is connected to y via role R,
-    /// y must be an instance of C.
-    pub fn apply_universal_rule(&mut self) -> bool {
+}
+
+/// The universal rule.
+/// If an individual is an instance of ObjectAllValuesFrom(R, C),
+/// then for every individual y such that the first individual is connected to y via role R,
+/// y must be an instance of C.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniversalRule;
+
+impl ExpansionRule for UniversalRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
         let mut new_concept_added = false;
         
         // Clone the current nodes to avoid borrowing issues
-        let nodes_clone = self.graph.nodes.clone();
+        let nodes_clone = graph.nodes.clone();
         
         for node in &nodes_clone {
             let individual = &node.individual;
@@ -658,19 +3037,16 @@ impl TableauReasoner {
                 if let ClassExpression::ObjectAllValuesFrom { property, filler } = concept {
                     // Find all role assertions for this property from this individual
                     // We need to find the index of the node to avoid borrowing issues
-                    if let Some(node_index) = self.graph.nodes.iter().position(|n| &n.individual == individual) {
-                        let role_assertions: Vec<_> = self.graph.nodes[node_index].roles.iter()
-                            .filter(|(p, _)| p == property)
-                            .map(|(_, target)| target.clone())
-                            .collect();
+                    if let Some(node_index) = graph.nodes.iter().position(|n| &n.individual == individual) {
+                        let role_assertions = graph.role_targets(node_index, property);
                         
-                        // For each target, ensure it has the filler concept
+                        // For each target, ensure it has the filler concept. The target of an
+                        // asserted role may not have a node yet, so create it if needed.
                         for target in role_assertions {
-                            if let Some(target_index) = self.graph.nodes.iter().position(|n| &n.individual == &target) {
-                                if !self.graph.nodes[target_index].concepts.contains(filler) {
-                                    self.graph.nodes[target_index].concepts.push((**filler).clone());
-                                    new_concept_added = true;
-                                }
+                            let target_node = graph.get_or_create_node(&target);
+                            if !target_node.concepts.contains(filler) {
+                                target_node.concepts.push((**filler).clone());
+                                new_concept_added = true;
                             }
                         }
                     }
@@ -682,45 +3058,615 @@ impl TableauReasoner {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{Class, Individual};
+/// The domain rule.
+/// If a node has an R-successor and the ontology contains ObjectPropertyDomain(S, C)
+/// for R or one of its told super-properties S, then the node is an instance of C. A
+/// domain of `ObjectInverseOf(S)` is a range of S and is applied to the targets of
+/// S-edges instead. Inverse properties declared with `InverseObjectProperties` are
+/// followed the same way.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DomainRule;
 
-    #[test]
-    fn test_completion_graph_creation() {
-        let graph = CompletionGraph::new();
-        assert_eq!(graph.nodes.len(), 0);
-        assert_eq!(graph.next_fresh_id, 0);
-    }
+impl ExpansionRule for DomainRule {
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool {
+        let domains: Vec<(&ObjectPropertyExpression, &ClassExpression)> = ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::ObjectPropertyDomain { property, domain }) => Some((property, domain)),
+                _ => None,
+            })
+            .collect();
+        if domains.is_empty() {
+            return false;
+        }
 
-    #[test]
-    fn test_add_node() {
-        let mut graph = CompletionGraph::new();
-        let individual = Individual::Named(crate::IRI("http://example.com/individual1".to_string()));
-        let node = graph.add_node(individual.clone());
-        assert_eq!(node.individual, individual);
-        assert_eq!(node.concepts.len(), 0);
-        assert_eq!(node.roles.len(), 0);
-        assert_eq!(graph.nodes.len(), 1);
-    }
+        // Collect (individual, concept) pairs first; targets may not have nodes yet
+        let mut closures: HashMap<&ObjectPropertyExpression, Vec<ObjectPropertyExpression>> = HashMap::new();
+        let mut additions: Vec<(Individual, ClassExpression)> = Vec::new();
+        for node in &graph.nodes {
+            for (property, target) in &node.roles {
+                let closure = closures.entry(property).or_insert_with(|| told_super_properties(ontology, property));
+                for (restricted, domain) in &domains {
+                    if closure.contains(restricted) {
+                        additions.push((node.individual.clone(), (*domain).clone()));
+                    } else if closure.iter().any(|held| inverse_of(held).as_ref() == Some(*restricted)) {
+                        additions.push((target.clone(), (*domain).clone()));
+                    }
+                }
+            }
+        }
 
-    #[test]
-    fn test_get_or_create_node() {
-        let mut graph = CompletionGraph::new();
-        let individual = Individual::Named(crate::IRI("http://example.com/individual1".to_string()));
-        
-        // First call should create a new node
-        {
-            let node1 = graph.get_or_create_node(&individual);
-            assert_eq!(node1.individual, individual);
+        let mut new_concept_added = false;
+        for (individual, domain) in additions {
+            let node = graph.get_or_create_node(&individual);
+            if !node.concepts.contains(&domain) {
+                node.concepts.push(domain);
+                new_concept_added = true;
+            }
         }
-        assert_eq!(graph.nodes.len(), 1);
-        
-        // Second call should return the same node
-        {
-            let node2 = graph.get_or_create_node(&individual);
-            assert_eq!(node2.individual, individual);
+        new_concept_added
+    }
+}
+
+/// The range rule.
+/// If a node has an R-successor y and the ontology contains ObjectPropertyRange(S, C)
+/// for R or one of its told super-properties S, then y is an instance of C. A range of
+/// `ObjectInverseOf(S)` is a domain of S and is applied to the sources of S-edges instead.
+/// The rule applies to every edge, including those to fresh successors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RangeRule;
+
+impl ExpansionRule for RangeRule {
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool {
+        let ranges: Vec<(&ObjectPropertyExpression, &ClassExpression)> = ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::ObjectPropertyRange { property, range }) => Some((property, range)),
+                _ => None,
+            })
+            .collect();
+        if ranges.is_empty() {
+            return false;
+        }
+
+        // Collect (individual, concept) pairs first; targets may not have nodes yet
+        let mut closures: HashMap<&ObjectPropertyExpression, Vec<ObjectPropertyExpression>> = HashMap::new();
+        let mut additions: Vec<(Individual, ClassExpression)> = Vec::new();
+        for node in &graph.nodes {
+            for (property, target) in &node.roles {
+                let closure = closures.entry(property).or_insert_with(|| told_super_properties(ontology, property));
+                for (ranged, range) in &ranges {
+                    if closure.contains(ranged) {
+                        additions.push((target.clone(), (*range).clone()));
+                    } else if closure.iter().any(|held| inverse_of(held).as_ref() == Some(*ranged)) {
+                        additions.push((node.individual.clone(), (*range).clone()));
+                    }
+                }
+            }
+        }
+
+        let mut new_concept_added = false;
+        for (individual, range) in additions {
+            let node = graph.get_or_create_node(&individual);
+            if !node.concepts.contains(&range) {
+                node.concepts.push(range);
+                new_concept_added = true;
+            }
+        }
+        new_concept_added
+    }
+}
+
+/// The exact-cardinality rule.
+/// If an individual is an instance of ObjectExactCardinality(n R C), then it is also
+/// an instance of ObjectMinCardinality(n R C) and ObjectMaxCardinality(n R C). The
+/// min-cardinality rule then creates pairwise distinct successors, which the max
+/// cardinality check counts against the same bound.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExactCardinalityRule;
+
+impl ExpansionRule for ExactCardinalityRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut new_concept_added = false;
+        for node in &mut graph.nodes {
+            let bounds: Vec<ClassExpression> = node.concepts.iter()
+                .filter_map(|concept| match concept {
+                    ClassExpression::ObjectExactCardinality { cardinality, property, filler } => Some([
+                        ClassExpression::ObjectMinCardinality { min: *cardinality, property: property.clone(), filler: filler.clone() },
+                        ClassExpression::ObjectMaxCardinality { max: *cardinality, property: property.clone(), filler: filler.clone() },
+                    ]),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            for bound in bounds {
+                if !node.concepts.contains(&bound) {
+                    node.concepts.push(bound);
+                    new_concept_added = true;
+                }
+            }
+        }
+        new_concept_added
+    }
+}
+
+/// The min-cardinality rule.
+/// If an individual is an instance of ObjectMinCardinality(n R C) and has fewer than n
+/// R-successors that are instances of C, fresh R-successors in C are created until
+/// there are n. An unqualified restriction (no filler) uses owl:Thing as C.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinCardinalityRule;
+
+impl ExpansionRule for MinCardinalityRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut new_successor_added = false;
+        
+        // Nodes created by this rule are visited in the same pass, in creation order
+        let mut node_index = 0;
+        while node_index < graph.nodes.len() {
+            let concepts = graph.nodes[node_index].concepts.clone();
+            for concept in &concepts {
+                if let ClassExpression::ObjectMinCardinality { min, property, filler } = concept {
+                    let filler = filler_or_thing(filler);
+                    let mut successors = graph.successors_in(node_index, property, &filler);
+                    for _ in successors.len()..*min as usize {
+                        let fresh_individual = graph.fresh_individual();
+                        // The generated successors must stay pairwise distinct, or a max
+                        // cardinality restriction could count them as one
+                        for successor in &successors {
+                            graph.add_inequality(successor, &fresh_individual);
+                        }
+                        successors.push(fresh_individual.clone());
+                        graph.push_role(node_index, property, fresh_individual.clone());
+                        graph.nodes.push(Node {
+                            individual: fresh_individual,
+                            concepts: vec![filler.clone()],
+                            roles: vec![],
+                        });
+                        new_successor_added = true;
+                    }
+                }
+            }
+            node_index += 1;
+        }
+        
+        new_successor_added
+    }
+}
+
+/// The max-cardinality rule.
+/// If an individual is an instance of ObjectMaxCardinality(n R C) and has n pairwise
+/// distinct R-successors in C, any further R-successor in C must be the same as one of
+/// them. When it is known to be different from all but one, the two are merged (see
+/// [`CompletionGraph::merge_nodes`]). Every set of n distinct successors is considered,
+/// so the search is exponential in the number of successors in the worst case. Other
+/// identifications would be a guess and are not made; successors that are not known
+/// to be distinct are simply counted once when looking for a max-cardinality clash.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxCardinalityRule;
+
+impl ExpansionRule for MaxCardinalityRule {
+    fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+        let mut merged = false;
+        for node_index in 0..graph.nodes.len() {
+            let concepts = graph.nodes[node_index].concepts.clone();
+            for concept in &concepts {
+                let ClassExpression::ObjectMaxCardinality { max, property, filler } = concept else {
+                    continue;
+                };
+                if *max == 0 {
+                    continue;
+                }
+                let successors = graph.successors_in(node_index, property, &filler_or_thing(filler));
+
+                // `successor` is the same as `same` if, together with n - 1 successors
+                // different from both, they would make n + 1 successors in which only
+                // that pair is not known to be distinct
+                let mut merges = Vec::new();
+                for (position, successor) in successors.iter().enumerate() {
+                    for same in &successors[position + 1..] {
+                        if same == successor || graph.are_distinct(same, successor) {
+                            continue;
+                        }
+                        let others: Vec<&Individual> = successors.iter()
+                            .filter(|other| graph.are_distinct(other, successor) && graph.are_distinct(other, same))
+                            .collect();
+                        if has_distinct_subset(graph, &others, *max as usize - 1) {
+                            merges.push((same.clone(), successor.clone()));
+                        }
+                    }
+                }
+                for (a, b) in merges {
+                    // Role targets need not have a node of their own yet
+                    graph.get_or_create_node(&a);
+                    graph.get_or_create_node(&b);
+                    let index_of = |individual: &Individual| graph.nodes.iter().position(|node| node.individual == *individual).unwrap();
+                    let (a, b) = (index_of(&a), index_of(&b));
+                    merged |= graph.merge_nodes(a, b);
+                }
+            }
+        }
+        merged
+    }
+}
+
+/// The key rule.
+/// If `HasKey(C (P1 ... Pm) (D1 ... Dn))` is asserted and two named individuals are
+/// instances of C that, for every Pi, have a common named Pi-successor and, for every
+/// Dj, a common Dj value, then they are the same individual and their nodes are merged
+/// (see [`CompletionGraph::merge_nodes`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HasKeyRule;
+
+impl ExpansionRule for HasKeyRule {
+    fn apply(&self, graph: &mut CompletionGraph, ontology: &Ontology) -> bool {
+        let mut merged = false;
+        for axiom in &ontology.axioms {
+            let Axiom::Assertion(crate::Assertion::HasKey { class, object_property_expression, data_property }) = axiom else {
+                continue;
+            };
+            if object_property_expression.is_empty() && data_property.is_empty() {
+                continue;
+            }
+            let class = ClassExpression::Class(class.clone());
+            let members: Vec<usize> = graph.nodes.iter()
+                .enumerate()
+                .filter(|(_, node)| matches!(node.individual, Individual::Named(_)) && node.concepts.contains(&class))
+                .map(|(index, _)| index)
+                .collect();
+            for (position, &x) in members.iter().enumerate() {
+                for &y in &members[position + 1..] {
+                    if shares_key(graph, ontology, x, y, object_property_expression, data_property) {
+                        merged |= graph.merge_nodes(x, y);
+                    }
+                }
+            }
+        }
+        merged
+    }
+}
+
+/// Checks whether the nodes `x` and `y` agree on every key property: a common named
+/// successor for each object property and an asserted value for each data property
+/// that is equal by value (see [`literals_value_equal`]) for both.
+fn shares_key(
+    graph: &CompletionGraph,
+    ontology: &Ontology,
+    x: usize,
+    y: usize,
+    object_properties: &[ObjectPropertyExpression],
+    data_properties: &[DataProperty],
+) -> bool {
+    let (x_node, y_node) = (&graph.nodes[x], &graph.nodes[y]);
+    let y_targets: Vec<Vec<Individual>> = object_properties.iter().map(|key| graph.role_targets(y, key)).collect();
+    let values = |individual: &Individual, key: &DataProperty| -> Vec<&Literal> {
+        ontology.axioms.iter()
+            .filter_map(|axiom| match axiom {
+                Axiom::Assertion(crate::Assertion::DataPropertyAssertion { property, source, target })
+                    if property == key && source == individual => Some(target),
+                _ => None,
+            })
+            .collect()
+    };
+
+    object_properties.iter().zip(&y_targets).all(|(key, y_targets)| {
+        graph.role_targets(x, key).iter().any(|target| matches!(target, Individual::Named(_)) && y_targets.contains(target))
+    }) && data_properties.iter().all(|key| {
+        let y_values = values(&y_node.individual, key);
+        values(&x_node.individual, key).iter().any(|value| y_values.iter().any(|other| literals_value_equal(value, other)))
+    })
+}
+
+/// Returns `property` together with all its told super-properties in `ontology`.
+/// `InverseObjectProperties(R, S)` makes R equivalent to `ObjectInverseOf(S)`.
+fn told_super_properties(ontology: &Ontology, property: &ObjectPropertyExpression) -> Vec<ObjectPropertyExpression> {
+    let mut result = vec![property.clone()];
+    let mut index = 0;
+    while index < result.len() {
+        let current = result[index].clone();
+        for axiom in &ontology.axioms {
+            let supers: Vec<ObjectPropertyExpression> = match axiom {
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property }) => {
+                    if *sub_property == current {
+                        vec![super_property.clone()]
+                    } else if inverse_of(sub_property).as_ref() == Some(&current) {
+                        inverse_of(super_property).into_iter().collect()
+                    } else {
+                        Vec::new()
+                    }
+                }
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::EquivalentObjectProperties { properties })
+                    if properties.contains(&current) => properties.clone(),
+                Axiom::ObjectProperty(crate::ObjectPropertyAxiom::InverseObjectProperties { prop1, prop2 }) => {
+                    [(prop1, prop2), (prop2, prop1)].into_iter()
+                        .filter_map(|(first, second)| {
+                            if *first == current {
+                                inverse_of(second)
+                            } else if inverse_of(first).as_ref() == Some(&current) {
+                                Some(second.clone())
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                }
+                _ => Vec::new(),
+            };
+            for sup in supers {
+                if !result.contains(&sup) {
+                    result.push(sup);
+                }
+            }
+        }
+        index += 1;
+    }
+    result
+}
+
+/// Maps each object property of `ontology`, and its inverse, to the least expression
+/// it is equivalent to according to the told property hierarchy, preferring named
+/// properties over inverses. Expressions that are their own representative are left out.
+fn role_representatives(ontology: &Ontology) -> HashMap<ObjectPropertyExpression, ObjectPropertyExpression> {
+    let mut expressions: Vec<ObjectPropertyExpression> = ontology.signature().into_iter()
+        .filter_map(|entity| match entity {
+            crate::Entity::ObjectProperty(property) => Some(property),
+            _ => None,
+        })
+        .flat_map(|property| [
+            ObjectPropertyExpression::ObjectProperty(property.clone()),
+            ObjectPropertyExpression::InverseObjectProperty(property),
+        ])
+        .collect();
+    expressions.sort();
+    let closures: HashMap<&ObjectPropertyExpression, Vec<ObjectPropertyExpression>> = expressions.iter()
+        .map(|expression| (expression, told_super_properties(ontology, expression)))
+        .collect();
+
+    let mut representatives = HashMap::new();
+    for expression in &expressions {
+        let representative = closures[expression].iter()
+            .filter(|other| closures.get(other).is_some_and(|supers| supers.contains(expression)))
+            .min_by_key(|other| (matches!(other, ObjectPropertyExpression::InverseObjectProperty(_)), *other));
+        if let Some(representative) = representative.filter(|representative| *representative != expression) {
+            representatives.insert(expression.clone(), representative.clone());
+        }
+    }
+    representatives
+}
+
+/// Returns the named property of a property expression, if it is not a chain.
+fn named_property(property: &ObjectPropertyExpression) -> Option<ObjectProperty> {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) | ObjectPropertyExpression::InverseObjectProperty(p) => Some(p.clone()),
+        ObjectPropertyExpression::ObjectPropertyChain(_) => None,
+    }
+}
+
+/// Rewrites IRIs with an optional normalizer and puts the IRIs of named individuals
+/// in normal form, as [`TableauReasoner::with_config`] does for the ontology.
+struct QueryIris<'a>(Option<&'a dyn IriNormalizer>);
+
+impl crate::IriMap for QueryIris<'_> {
+    fn iri(&mut self, iri: &mut crate::IRI) {
+        if let Some(normalizer) = self.0 {
+            *iri = normalizer.normalize(iri);
+        }
+    }
+
+    fn individual(&mut self, iri: &mut crate::IRI) {
+        self.iri(iri);
+        *iri = iri.normalized();
+    }
+}
+
+/// An edge `property(source, target)` on a named property.
+type NamedEdge = (ObjectProperty, Individual, Individual);
+
+/// Rewrites the edge `property(source, target)` on a named property, so that
+/// `ObjectInverseOf(P)(a, b)` becomes `P(b, a)`. Chains have no single edge.
+fn named_edge(property: &ObjectPropertyExpression, source: &Individual, target: &Individual) -> Option<NamedEdge> {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) => Some((p.clone(), source.clone(), target.clone())),
+        ObjectPropertyExpression::InverseObjectProperty(p) => Some((p.clone(), target.clone(), source.clone())),
+        ObjectPropertyExpression::ObjectPropertyChain(_) => None,
+    }
+}
+
+/// A set of named edges, indexed by property and by either endpoint.
+#[derive(Default)]
+struct EdgeIndex {
+    edges: HashSet<NamedEdge>,
+    forward: HashMap<(ObjectProperty, Individual), Vec<Individual>>,
+    backward: HashMap<(ObjectProperty, Individual), Vec<Individual>>,
+}
+
+impl EdgeIndex {
+    /// Adds an edge, returning `false` if it was already present.
+    fn insert(&mut self, edge: &NamedEdge) -> bool {
+        if !self.edges.insert(edge.clone()) {
+            return false;
+        }
+        let (property, source, target) = edge;
+        self.forward.entry((property.clone(), source.clone())).or_default().push(target.clone());
+        self.backward.entry((property.clone(), target.clone())).or_default().push(source.clone());
+        true
+    }
+
+    /// Returns the individuals reached from `source` over `property`.
+    fn targets(&self, property: &ObjectPropertyExpression, source: &Individual) -> Vec<Individual> {
+        let (map, property) = match property {
+            ObjectPropertyExpression::ObjectProperty(p) => (&self.forward, p),
+            ObjectPropertyExpression::InverseObjectProperty(p) => (&self.backward, p),
+            ObjectPropertyExpression::ObjectPropertyChain(_) => return Vec::new(),
+        };
+        map.get(&(property.clone(), source.clone())).cloned().unwrap_or_default()
+    }
+}
+
+/// Checks whether `size` of the `candidates` are known to be pairwise distinct.
+///
+/// Tries every combination with backtracking, so unlike a greedy choice it finds such
+/// a subset whenever one exists, at a worst-case cost exponential in `size`.
+fn has_distinct_subset(graph: &CompletionGraph, candidates: &[&Individual], size: usize) -> bool {
+    fn extend<'a>(graph: &CompletionGraph, candidates: &[&'a Individual], chosen: &mut Vec<&'a Individual>, size: usize) -> bool {
+        if chosen.len() == size {
+            return true;
+        }
+        for (position, &candidate) in candidates.iter().enumerate() {
+            if candidates.len() - position < size - chosen.len() {
+                return false;
+            }
+            if chosen.iter().all(|other| graph.are_distinct(other, candidate)) {
+                chosen.push(candidate);
+                if extend(graph, &candidates[position + 1..], chosen, size) {
+                    return true;
+                }
+                chosen.pop();
+            }
+        }
+        false
+    }
+
+    extend(graph, candidates, &mut Vec::new(), size)
+}
+
+/// Returns the filler of a cardinality restriction, treating a missing filler as owl:Thing.
+fn filler_or_thing(filler: &Option<Box<ClassExpression>>) -> ClassExpression {
+    match filler {
+        Some(filler) => (**filler).clone(),
+        None => ClassExpression::Class(Class::thing()),
+    }
+}
+
+/// Returns the negation of a class expression in negation normal form: the complement
+/// is pushed through intersections, unions and restrictions down to named classes.
+fn negate(expression: &ClassExpression) -> ClassExpression {
+    match expression {
+        ClassExpression::ObjectComplementOf(inner) => (**inner).clone(),
+        ClassExpression::ObjectIntersectionOf(operands) => ClassExpression::ObjectUnionOf(operands.iter().map(negate).collect()),
+        ClassExpression::ObjectUnionOf(operands) => ClassExpression::ObjectIntersectionOf(operands.iter().map(negate).collect()),
+        ClassExpression::ObjectSomeValuesFrom { property, filler } => ClassExpression::all(property.clone(), negate(filler)),
+        ClassExpression::ObjectAllValuesFrom { property, filler } => ClassExpression::some(property.clone(), negate(filler)),
+        other => ClassExpression::complement(other.clone()),
+    }
+}
+
+/// Checks whether the expansion rules fully handle a class expression, as it appears
+/// in a node's label, so that the absence of a clash proves a model exists.
+fn is_decided_expression(expression: &ClassExpression) -> bool {
+    let is_thing = |filler: &Option<Box<ClassExpression>>| filler.as_deref().is_none_or(|filler| matches!(filler, ClassExpression::Class(class) if class.is_thing()));
+    match expression {
+        ClassExpression::Class(_) => true,
+        ClassExpression::ObjectIntersectionOf(operands) | ClassExpression::ObjectUnionOf(operands) => operands.iter().all(is_decided_expression),
+        ClassExpression::ObjectComplementOf(inner) => match negate(inner) {
+            ClassExpression::ObjectComplementOf(_) => matches!(**inner, ClassExpression::Class(_)),
+            negated => is_decided_expression(&negated),
+        },
+        // Edges to fresh successors are only followed forwards, so restrictions on
+        // inverse properties are not propagated back to the predecessor
+        ClassExpression::ObjectSomeValuesFrom { property, filler } | ClassExpression::ObjectAllValuesFrom { property, filler } => {
+            matches!(property, ObjectPropertyExpression::ObjectProperty(_)) && is_decided_expression(filler)
+        }
+        ClassExpression::ObjectMinCardinality { filler, .. } => filler.as_deref().is_none_or(is_decided_expression),
+        ClassExpression::ObjectMaxCardinality { filler, .. } | ClassExpression::ObjectExactCardinality { filler, .. } => is_thing(filler),
+        ClassExpression::ObjectHasValue { .. } | ClassExpression::ObjectOneOf(_) | ClassExpression::ObjectHasSelf(_) => false,
+        // No expansion rule reasons about data restrictions
+        ClassExpression::DataSomeValuesFrom { .. }
+        | ClassExpression::DataAllValuesFrom { .. }
+        | ClassExpression::DataHasValue { .. }
+        | ClassExpression::DataMinCardinality { .. }
+        | ClassExpression::DataMaxCardinality { .. }
+        | ClassExpression::DataExactCardinality { .. } => false,
+    }
+}
+
+/// Checks whether the expansion rules fully handle an ontology axiom.
+fn is_decided_axiom(axiom: &Axiom) -> bool {
+    match axiom {
+        Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class, super_class }) => {
+            let negated_class = matches!(sub_class, ClassExpression::ObjectComplementOf(inner)
+                if matches!(negate(inner), ClassExpression::ObjectComplementOf(named) if matches!(*named, ClassExpression::Class(_))));
+            (matches!(sub_class, ClassExpression::Class(_)) || negated_class) && is_decided_expression(super_class)
+        }
+        Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes } | crate::ClassAxiom::DisjointClasses { classes }) => {
+            classes.len() < 2 || classes.iter().all(|class| matches!(class, ClassExpression::Class(_)))
+        }
+        Axiom::Class(crate::ClassAxiom::DisjointUnion { disjoint_classes, .. }) => {
+            disjoint_classes.iter().all(|class| matches!(class, ClassExpression::Class(_)))
+        }
+        Axiom::ObjectProperty(property_axiom) => match property_axiom {
+            crate::ObjectPropertyAxiom::SubObjectPropertyOf { sub_property, super_property } => {
+                matches!(sub_property, ObjectPropertyExpression::ObjectProperty(_))
+                    && matches!(super_property, ObjectPropertyExpression::ObjectProperty(_))
+            }
+            crate::ObjectPropertyAxiom::EquivalentObjectProperties { properties }
+            | crate::ObjectPropertyAxiom::DisjointObjectProperties { properties } => {
+                properties.iter().all(|property| matches!(property, ObjectPropertyExpression::ObjectProperty(_)))
+            }
+            crate::ObjectPropertyAxiom::InverseObjectProperties { .. } => false,
+            crate::ObjectPropertyAxiom::ObjectPropertyDomain { domain: expression, .. }
+            | crate::ObjectPropertyAxiom::ObjectPropertyRange { range: expression, .. } => is_decided_expression(expression),
+            _ => false,
+        },
+        Axiom::DataProperty(data_property_axiom) => !matches!(
+            data_property_axiom,
+            crate::DataPropertyAxiom::DataPropertyDomain { .. } | crate::DataPropertyAxiom::DisjointDataProperties { .. }
+        ),
+        Axiom::Assertion(assertion) => match assertion {
+            crate::Assertion::ClassAssertion { class, .. } => is_decided_expression(class),
+            crate::Assertion::NegativeDataPropertyAssertion { .. } => false,
+            _ => true,
+        },
+        Axiom::Declaration(_) | Axiom::Annotation(_) => true,
+    }
+}
+
+/// Returns the inverse of a simple property expression; property chains have none.
+fn inverse_of(property: &ObjectPropertyExpression) -> Option<ObjectPropertyExpression> {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(p) => Some(ObjectPropertyExpression::InverseObjectProperty(p.clone())),
+        ObjectPropertyExpression::InverseObjectProperty(p) => Some(ObjectPropertyExpression::ObjectProperty(p.clone())),
+        ObjectPropertyExpression::ObjectPropertyChain(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Class, Individual};
+
+    #[test]
+    fn test_completion_graph_creation() {
+        let graph = CompletionGraph::new();
+        assert_eq!(graph.nodes.len(), 0);
+        assert_eq!(graph.next_fresh_id, 0);
+    }
+
+    #[test]
+    fn test_add_node() {
+        let mut graph = CompletionGraph::new();
+        let individual = Individual::Named(crate::IRI("http://example.com/individual1".to_string()));
+        let node = graph.add_node(individual.clone());
+        assert_eq!(node.individual, individual);
+        assert_eq!(node.concepts.len(), 0);
+        assert_eq!(node.roles.len(), 0);
+        assert_eq!(graph.nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_create_node() {
+        let mut graph = CompletionGraph::new();
+        let individual = Individual::Named(crate::IRI("http://example.com/individual1".to_string()));
+        
+        // First call should create a new node
+        {
+            let node1 = graph.get_or_create_node(&individual);
+            assert_eq!(node1.individual, individual);
+        }
+        assert_eq!(graph.nodes.len(), 1);
+        
+        // Second call should return the same node
+        {
+            let node2 = graph.get_or_create_node(&individual);
+            assert_eq!(node2.individual, individual);
         }
         assert_eq!(graph.nodes.len(), 1);
     }
@@ -755,6 +3701,32 @@ mod tests {
         assert_eq!(node.roles[0].1, target);
     }
 
+    #[test]
+    fn test_role_targets_follow_mirrored_edges() {
+        let mut graph = CompletionGraph::new();
+        let named = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let has_child = ObjectPropertyExpression::ObjectProperty(
+            crate::ObjectProperty(crate::IRI("http://example.com/hasChild".to_string()))
+        );
+        let has_parent = inverse_of(&has_child).unwrap();
+
+        graph.add_role(&named("alice"), has_child.clone(), named("bob"));
+        graph.get_or_create_node(&named("bob"));
+        let bob = graph.nodes.len() - 1;
+        assert_eq!(graph.role_targets(bob, &has_parent), vec![named("alice")]);
+
+        // The mirrored edge is not stored again, and a merged node's copied edges are found too
+        graph.add_role(&named("bob"), has_parent.clone(), named("alice"));
+        assert!(graph.nodes[bob].roles.is_empty());
+        graph.get_or_create_node(&named("carol"));
+        let (alice, carol) = (0, graph.nodes.len() - 1);
+        graph.merge_nodes(alice, carol);
+        assert_eq!(graph.role_targets(bob, &has_parent), vec![named("alice"), named("carol")]);
+
+        graph.normalize_roles();
+        assert_eq!(graph.role_targets(bob, &has_parent), vec![named("alice"), named("carol")]);
+    }
+
     #[test]
     fn test_fresh_individual() {
         let mut graph = CompletionGraph::new();
@@ -777,6 +3749,1834 @@ mod tests {
         assert_eq!(graph.next_fresh_id, 2);
     }
 
+    #[test]
+    fn test_fresh_id_seed_and_reset() {
+        let mut graph = CompletionGraph::with_fresh_id_seed(10);
+        assert_eq!(graph.fresh_individual(), Individual::Anonymous(crate::NodeID("_:fresh11".to_string())));
+        graph.add_node(Individual::Named(crate::IRI("http://example.com/a".to_string())));
+
+        graph.clear();
+        assert!(graph.nodes.is_empty());
+        assert_eq!(graph.next_fresh_id, 10);
+        assert_eq!(graph.fresh_individual(), Individual::Anonymous(crate::NodeID("_:fresh11".to_string())));
+    }
+
+    #[test]
+    fn test_fresh_prefix() {
+        let mut graph = CompletionGraph::with_fresh_prefix("_:witness");
+        graph.add_node(Individual::Anonymous(crate::NodeID("_:witness1".to_string())));
+        assert_eq!(graph.fresh_individual(), Individual::Anonymous(crate::NodeID("_:witness2".to_string())));
+
+        let ontology = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)) NamedIndividual(<http://example.com/alice>))
+)"#).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        reasoner.graph = CompletionGraph::with_fresh_prefix("_:pipeline-");
+        assert!(reasoner.is_consistent().unwrap());
+        let fresh: Vec<&Individual> = reasoner.graph.nodes.iter()
+            .map(|node| &node.individual)
+            .filter(|individual| matches!(individual, Individual::Anonymous(_)))
+            .collect();
+        assert_eq!(fresh, vec![&Individual::Anonymous(crate::NodeID("_:pipeline-1".to_string()))]);
+    }
+
+    #[test]
+    fn test_equivalent_iri_encodings_share_a_node() {
+        let ontology = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Person>) NamedIndividual(<http://Example.COM/%7Ejohn>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/~john>))
+)"#).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let john = Individual::Named(crate::IRI("http://example.com/~john".to_string()));
+        assert_eq!(reasoner.graph.nodes.len(), 1);
+        assert_eq!(reasoner.graph.nodes[0].individual, john);
+        assert!(reasoner.graph.nodes[0].concepts.contains(&ClassExpression::Class(Class(crate::IRI("http://example.com/Person".to_string())))));
+        assert!(reasoner.graph.nodes[0].concepts.contains(&ClassExpression::Class(Class(crate::IRI("http://example.com/Student".to_string())))));
+
+        // Queries may use any encoding of the IRI
+        let encoded = Individual::Named(crate::IRI("http://Example.COM/%7Ejohn".to_string()));
+        let student = Class(crate::IRI("http://example.com/Student".to_string()));
+        assert!(reasoner.is_instance_of(&encoded, &student));
+        assert!(reasoner.is_instance_of_expression(&encoded, &ClassExpression::Class(student.clone())));
+        assert!(reasoner.entails(&Axiom::Assertion(crate::Assertion::ClassAssertion {
+            class: ClassExpression::Class(student),
+            individual: encoded.clone(),
+        })));
+        assert!(reasoner.realize().unwrap().contains_key(&john));
+    }
+
+    #[test]
+    fn test_fresh_individuals_are_reproducible() {
+        let ontology = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasPet>) Class(<http://example.com/Dog>))) NamedIndividual(<http://example.com/alice>))
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/owns>) Class(<http://example.com/Car>)) NamedIndividual(<http://example.com/bob>))
+)"#).unwrap();
+
+        let fresh_nodes = |reasoner: &TableauReasoner| -> Vec<(Individual, Vec<ClassExpression>)> {
+            reasoner.graph.nodes.iter()
+                .filter(|n| matches!(n.individual, Individual::Anonymous(_)))
+                .map(|n| (n.individual.clone(), n.concepts.clone()))
+                .collect()
+        };
+
+        let mut first = TableauReasoner::new(ontology.clone());
+        assert!(first.is_consistent().unwrap());
+        let first_run = fresh_nodes(&first);
+        assert_eq!(first_run.len(), 3);
+
+        let mut second = TableauReasoner::new(ontology);
+        assert!(second.is_consistent().unwrap());
+        assert_eq!(fresh_nodes(&second), first_run);
+
+        // Resetting and reasoning again on the same reasoner yields the same names
+        first.reset();
+        assert!(first.is_consistent().unwrap());
+        assert_eq!(fresh_nodes(&first), first_run);
+    }
+
+    #[test]
+    fn test_annotation_handler_affects_classification() {
+        use crate::{AnnotationValue, ClassAxiom, IRI};
+
+        let legacy_sub_class_of = IRI("http://example.com/legacy#subClassOf".to_string());
+        let student = Class(IRI("http://example.com/Student".to_string()));
+        let person = Class(IRI("http://example.com/Person".to_string()));
+        let ontology = Ontology {
+            axioms: vec![Axiom::Annotation(AnnotationAxiom::AnnotationAssertion {
+                property: legacy_sub_class_of.clone(),
+                subject: student.0.clone(),
+                value: AnnotationValue::IRI(person.0.clone()),
+            })],
+            ..Default::default()
+        };
+
+        // Without a handler the annotation has no effect
+        let mut plain = TableauReasoner::new(ontology.clone());
+        assert!(plain.classify().unwrap().superclasses.is_empty());
+
+        let config = ReasonerConfig {
+            annotation_handlers: vec![Box::new(move |annotation| match annotation {
+                AnnotationAxiom::AnnotationAssertion { property, subject, value: AnnotationValue::IRI(object) }
+                    if *property == legacy_sub_class_of => Some(Axiom::Class(ClassAxiom::SubClassOf {
+                        sub_class: ClassExpression::Class(Class(subject.clone())),
+                        super_class: ClassExpression::Class(Class(object.clone())),
+                    })),
+                _ => None,
+            })],
+            ..ReasonerConfig::default()
+        };
+        let mut reasoner = TableauReasoner::with_config(ontology, config);
+        let hierarchy = reasoner.classify().unwrap();
+        assert_eq!(hierarchy.superclasses[&student], vec![person]);
+    }
+
+    #[test]
+    fn test_disjoint_object_properties_clash() {
+        let inconsistent = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  DisjointObjectProperties(ObjectProperty(<http://example.com/R>) ObjectProperty(<http://example.com/S>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/S>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+)"#).unwrap();
+        assert!(!TableauReasoner::new(inconsistent).is_consistent().unwrap());
+
+        // The same properties between different pairs are fine
+        let consistent = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  DisjointObjectProperties(ObjectProperty(<http://example.com/R>) ObjectProperty(<http://example.com/S>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/S>) NamedIndividual(<http://example.com/b>) NamedIndividual(<http://example.com/a>))
+)"#).unwrap();
+        assert!(TableauReasoner::new(consistent).is_consistent().unwrap());
+
+        // An edge inherited through the property hierarchy also clashes
+        let inherited = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  DisjointObjectProperties(ObjectProperty(<http://example.com/R>) ObjectProperty(<http://example.com/S>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/R1>) ObjectProperty(<http://example.com/R>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R1>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/S>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+)"#).unwrap();
+        assert!(!TableauReasoner::new(inherited).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_check_consistency_reports_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) ObjectComplementOf(Class(<http://example.com/Employee>)))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ClassAssertion(Class(<http://example.com/Employee>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        assert_eq!(reasoner.check_consistency(), ConsistencyResult::Inconsistent {
+            clash: Clash::Complement {
+                individual: Individual::Named(crate::IRI("http://example.com/john".to_string())),
+                class: ClassExpression::Class(Class(crate::IRI("http://example.com/Employee".to_string()))),
+            },
+        });
+    }
+
+    #[test]
+    fn test_check_consistency_model_summary() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        assert_eq!(reasoner.check_consistency(), ConsistencyResult::Consistent {
+            model_summary: ModelSummary { nodes: 2, edges: 1 },
+        });
+    }
+
+    #[test]
+    fn test_data_property_range_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(TableauReasoner::new(ontology).is_consistent().unwrap());
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "old")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_data_property_range_uses_datatype_hierarchy() {
+        let ontology = |range: &str| crate::parser::OWLParser::parse_ontology(&format!(r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#{}>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) "22"^^<http://www.w3.org/2001/XMLSchema#integer>)
+)"#, range)).unwrap();
+        assert!(TableauReasoner::new(ontology("decimal")).is_consistent().unwrap());
+
+        let mut reasoner = TableauReasoner::new(ontology("string"));
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::DataRange { .. } }));
+    }
+
+    #[test]
+    fn test_data_one_of_range_clash() {
+        let ontology = |value: &str| crate::parser::OWLParser::parse_ontology(&format!(r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasGrade>) DataOneOf("a" "b"))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasGrade>) NamedIndividual(<http://example.com/john>) {})
+)"#, value)).unwrap();
+        assert!(TableauReasoner::new(ontology(r#""b""#)).is_consistent().unwrap());
+
+        let mut reasoner = TableauReasoner::new(ontology(r#""c""#));
+        let ConsistencyResult::Inconsistent { clash } = reasoner.check_consistency() else {
+            panic!("expected an inconsistency");
+        };
+        assert!(matches!(clash, Clash::DataRange { range: DataRange::DataOneOf(_), ref value, .. } if value.value == "c"));
+    }
+
+    #[test]
+    fn test_data_one_of_some_values_from_clash() {
+        let ontology = |axioms: &str| crate::parser::OWLParser::parse_ontology(&format!(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(DataSomeValuesFrom(DataProperty(<http://example.com/hasGrade>) DataOneOf("a" "b")) NamedIndividual(<http://example.com/john>))
+  {}
+)"#, axioms)).unwrap();
+        let functional = "FunctionalDataProperty(DataProperty(<http://example.com/hasGrade>))";
+        let grade = |value: &str| format!(r#"DataPropertyAssertion(DataProperty(<http://example.com/hasGrade>) NamedIndividual(<http://example.com/john>) "{}")"#, value);
+
+        assert!(TableauReasoner::new(ontology(&format!("{}\n  {}", functional, grade("b")))).is_consistent().unwrap());
+        // Without functionality john may have another grade that is "a" or "b"
+        assert!(TableauReasoner::new(ontology(&grade("c"))).is_consistent().unwrap());
+
+        let mut reasoner = TableauReasoner::new(ontology(&format!("{}\n  {}", functional, grade("c"))));
+        let ConsistencyResult::Inconsistent { clash } = reasoner.check_consistency() else {
+            panic!("expected an inconsistency");
+        };
+        assert!(matches!(clash, Clash::DataRange { range: DataRange::DataOneOf(_), ref value, .. } if value.value == "c"));
+
+        // No grade in the enumeration is allowed by the property's range
+        let range = r#"DataPropertyRange(DataProperty(<http://example.com/hasGrade>) DataOneOf("c" "d"))"#;
+        let mut reasoner = TableauReasoner::new(ontology(range));
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::EmptyDataRange { .. } }));
+    }
+
+    #[test]
+    fn test_sub_data_property_value_counts_for_functional_super_property() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubDataPropertyOf(DataProperty(<http://example.com/hasBirthYear>) DataProperty(<http://example.com/hasYear>))
+  FunctionalDataProperty(DataProperty(<http://example.com/hasYear>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasYear>) NamedIndividual(<http://example.com/john>) "1990"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  DataPropertyAssertion(DataProperty(<http://example.com/hasBirthYear>) NamedIndividual(<http://example.com/john>) "1985"^^<http://www.w3.org/2001/XMLSchema#integer>)
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        let ConsistencyResult::Inconsistent { clash } = reasoner.check_consistency() else {
+            panic!("expected an inconsistency");
+        };
+        assert!(matches!(clash, Clash::FunctionalDataProperty { ref property, .. }
+            if property.0.0 == "http://example.com/hasYear"));
+    }
+
+    #[test]
+    fn test_functional_data_property_compares_values() {
+        let ontology = |second: &str| crate::parser::OWLParser::parse_ontology(&format!(r#"Ontology(<http://example.com/ontology>
+  FunctionalDataProperty(DataProperty(<http://example.com/hasQuantity>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasQuantity>) NamedIndividual(<http://example.com/batch>) "1"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  DataPropertyAssertion(DataProperty(<http://example.com/hasQuantity>) NamedIndividual(<http://example.com/batch>) {})
+)"#, second)).unwrap();
+
+        assert!(TableauReasoner::new(ontology(r#""1.0"^^<http://www.w3.org/2001/XMLSchema#decimal>"#)).is_consistent().unwrap());
+        assert!(!TableauReasoner::new(ontology(r#""1.5"^^<http://www.w3.org/2001/XMLSchema#decimal>"#)).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_equivalent_data_properties_share_ranges() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  EquivalentDataProperties(DataProperty(<http://example.com/age>) DataProperty(<http://example.com/hasAge>))
+  DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))
+  DataPropertyAssertion(DataProperty(<http://example.com/age>) NamedIndividual(<http://example.com/john>) "old")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_bottom_data_property_assertion_is_inconsistent() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DataPropertyAssertion(DataProperty(<http://www.w3.org/2002/07/owl#bottomDataProperty>) NamedIndividual(<http://example.com/john>) "42")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::BottomDataProperty { .. } }));
+
+        // A sub-property of owl:bottomDataProperty inherits its emptiness
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubDataPropertyOf(DataProperty(<http://example.com/never>) DataProperty(<http://www.w3.org/2002/07/owl#bottomDataProperty>))
+  DataPropertyAssertion(DataProperty(<http://example.com/never>) NamedIndividual(<http://example.com/john>) "42")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_empty_data_range_clash() {
+        let literal = DataRange::Datatype(crate::Datatype(crate::IRI("http://www.w3.org/2000/01/rdf-schema#Literal".to_string())));
+        let empty = DataRange::DataComplementOf(Box::new(literal));
+        let has_age = DataProperty(crate::IRI("http://example.com/hasAge".to_string()));
+        let john = Individual::Named(crate::IRI("http://example.com/john".to_string()));
+        let value = Literal { value: "42".to_string(), datatype: crate::Datatype(crate::IRI("http://www.w3.org/2001/XMLSchema#integer".to_string())), lang: None };
+
+        let mut ontology = Ontology::default();
+        ontology.axioms.push(Axiom::DataProperty(crate::DataPropertyAxiom::DataPropertyRange { property: has_age.clone(), range: empty.clone() }));
+        assert!(TableauReasoner::new(ontology.clone()).is_consistent().unwrap());
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::DataPropertyAssertion { property: has_age, source: john.clone(), target: value }));
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::EmptyDataRange { .. } }));
+
+        // Every individual has values of owl:topDataProperty
+        let mut ontology = Ontology::default();
+        ontology.axioms.push(Axiom::DataProperty(crate::DataPropertyAxiom::DataPropertyRange { property: DataProperty::top(), range: empty }));
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion { class: ClassExpression::Class(Class::thing()), individual: john }));
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_data_range_membership_by_value() {
+        let consistent = |range: &str, value: &str| {
+            let input = format!(r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/age>) Datatype(<http://www.w3.org/2001/XMLSchema#{range}>))
+  DataPropertyAssertion(DataProperty(<http://example.com/age>) NamedIndividual(<http://example.com/john>) {value})
+)"#);
+            TableauReasoner::new(crate::parser::OWLParser::parse_ontology(&input).unwrap()).is_consistent().unwrap()
+        };
+        assert!(consistent("nonNegativeInteger", r#""22"^^<http://www.w3.org/2001/XMLSchema#integer>"#));
+        assert!(consistent("integer", r#""5"^^<http://www.w3.org/2001/XMLSchema#decimal>"#));
+        assert!(consistent("int", r#""5"^^<http://www.w3.org/2001/XMLSchema#integer>"#));
+
+        assert!(!consistent("nonNegativeInteger", r#""-22"^^<http://www.w3.org/2001/XMLSchema#integer>"#));
+        assert!(!consistent("integer", r#""5.5"^^<http://www.w3.org/2001/XMLSchema#decimal>"#));
+        assert!(!consistent("int", r#""3000000000"^^<http://www.w3.org/2001/XMLSchema#integer>"#));
+    }
+
+    #[test]
+    fn test_custom_datatype_range_is_not_a_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasSKU>) Datatype(<http://example.com/datatypes#SKU>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasSKU>) NamedIndividual(<http://example.com/milk>) "SKU-1")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(TableauReasoner::new(ontology).is_consistent().unwrap());
+
+        // Nor is its complement, since membership in it is unknown too
+        let input = r#"Ontology(<http://example.com/ontology>
+  DataPropertyRange(DataProperty(<http://example.com/hasSKU>) DataComplementOf(Datatype(<http://example.com/datatypes#SKU>)))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasSKU>) NamedIndividual(<http://example.com/milk>) "SKU-1")
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_reasoner_iri_normalizer() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ClassAssertion(Class(<https://example.com/Employee>) NamedIndividual(<https://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let config = ReasonerConfig {
+            iri_normalizer: Some(Box::new(|iri: &crate::IRI| crate::IRI(iri.0.replacen("https://", "http://", 1)))),
+            ..ReasonerConfig::default()
+        };
+        let mut reasoner = TableauReasoner::with_config(ontology, config);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let john = Individual::Named(crate::IRI("http://example.com/john".to_string()));
+        let node = reasoner.graph.nodes.iter().find(|node| node.individual == john).unwrap();
+        assert_eq!(node.concepts, vec![
+            ClassExpression::Class(Class(crate::IRI("http://example.com/Employee".to_string()))),
+            ClassExpression::Class(Class(crate::IRI("http://example.com/Student".to_string()))),
+        ]);
+        assert_eq!(reasoner.graph.nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_reasoner_iri_normalizer_applies_to_queries() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let config = ReasonerConfig {
+            iri_normalizer: Some(Box::new(|iri: &crate::IRI| crate::IRI(iri.0.replacen("https://", "http://", 1)))),
+            ..ReasonerConfig::default()
+        };
+        let mut reasoner = crate::api::Reasoner::with_config(ontology, config);
+        let class = |name: &str| Class(crate::IRI(format!("https://example.com/{}", name)));
+        let john = Individual::Named(crate::IRI("https://example.com/john".to_string()));
+
+        assert!(reasoner.is_subclass_of(&class("Student"), &class("Person")));
+        assert!(reasoner.entails(&Axiom::Assertion(crate::Assertion::ClassAssertion {
+            class: ClassExpression::Class(class("Person")),
+            individual: john,
+        })));
+        assert_eq!(reasoner.instances_of(&class("Person")).unwrap(), vec![
+            Individual::Named(crate::IRI("http://example.com/john".to_string())),
+        ]);
+        assert_eq!(reasoner.least_common_subsumer(&class("Student"), &class("Person")).unwrap(), vec![
+            Class(crate::IRI("http://example.com/Person".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn test_classify_groups_equivalent_classes() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+  SubClassOf(Class(<http://example.com/C>) Class(<http://example.com/A>))
+  SubClassOf(Class(<http://example.com/D>) Class(<http://example.com/A>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let hierarchy = TableauReasoner::new(ontology).classify().unwrap();
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert_eq!(hierarchy.equivalent_classes[&class("A")], vec![class("B"), class("C")]);
+        assert_eq!(hierarchy.equivalent_classes[&class("B")], vec![class("A"), class("C")]);
+        assert!(!hierarchy.equivalent_classes.contains_key(&class("D")));
+        // Equivalent classes are not each other's sub- or superclasses
+        for name in ["A", "B", "C"] {
+            assert_eq!(hierarchy.superclasses[&class(name)], vec![Class::thing()]);
+            assert_eq!(hierarchy.subclasses[&class(name)], vec![class("D")]);
+        }
+        assert_eq!(hierarchy.superclasses[&class("D")], vec![class("A"), class("B"), class("C")]);
+    }
+
+    #[test]
+    fn test_classify_reduces_transitive_subsumptions() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Agent>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let hierarchy = TableauReasoner::new(ontology).classify().unwrap();
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert_eq!(hierarchy.superclasses[&class("Student")], vec![class("Person")]);
+        assert_eq!(hierarchy.superclasses[&class("Person")], vec![class("Agent")]);
+        assert_eq!(hierarchy.superclasses[&class("Agent")], vec![Class::thing()]);
+        assert_eq!(hierarchy.subclasses[&Class::thing()], vec![class("Agent")]);
+        assert_eq!(hierarchy.superclasses[&Class::nothing()], vec![class("Student")]);
+        assert!(!hierarchy.superclasses.contains_key(&Class::thing()));
+
+        assert_eq!(hierarchy.ancestors(&class("Student")), vec![class("Agent"), class("Person"), Class::thing()]);
+        assert_eq!(hierarchy.descendants(&class("Agent")), vec![class("Person"), class("Student"), Class::nothing()]);
+        assert!(hierarchy.descendants(&Class::nothing()).is_empty());
+    }
+
+    #[test]
+    fn test_classify_with_progress() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let mut reports = Vec::new();
+        let hierarchy = reasoner.classify_with_progress(|completed, total| reports.push((completed, total))).unwrap();
+
+        // Student, Person, Agent and owl:Nothing
+        assert_eq!(reports.len(), 4);
+        assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(reports.iter().all(|&(_, total)| total == 12));
+        assert_eq!(reports.last(), Some(&(12, 12)));
+
+        let mut superclasses = hierarchy.superclasses.clone();
+        let mut expected = reasoner.classify().unwrap().superclasses;
+        superclasses.values_mut().for_each(|classes| classes.sort());
+        expected.values_mut().for_each(|classes| classes.sort());
+        assert_eq!(superclasses, expected);
+    }
+
+    #[test]
+    fn test_class_hierarchy_to_tree() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Dog>) Class(<http://example.com/Animal>))
+  SubClassOf(Class(<http://example.com/Cat>) Class(<http://example.com/Animal>))
+  SubClassOf(Class(<http://example.com/Dog>) Class(<http://example.com/Pet>))
+  SubClassOf(Class(<http://example.com/Puppy>) Class(<http://example.com/Dog>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let tree = TableauReasoner::new(ontology).classify().unwrap().to_tree();
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+        let leaf = |name: &str| ClassTreeNode { class: class(name), children: Vec::new() };
+        let dog = ClassTreeNode { class: class("Dog"), children: vec![leaf("Puppy")] };
+
+        assert_eq!(tree, ClassTreeNode {
+            class: Class::thing(),
+            children: vec![
+                ClassTreeNode { class: class("Animal"), children: vec![leaf("Cat"), dog.clone()] },
+                ClassTreeNode { class: class("Pet"), children: vec![dog] },
+            ],
+        });
+    }
+
+    #[test]
+    fn test_cyclic_existential_terminates() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/A>)))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/x>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+        assert!(reasoner.graph.nodes.len() <= 3);
+    }
+
+    #[test]
+    fn test_subset_blocking() {
+        let a = ClassExpression::Class(Class(crate::IRI("http://example.com/A".to_string())));
+        let b = ClassExpression::Class(Class(crate::IRI("http://example.com/B".to_string())));
+        let r = ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/r".to_string())));
+        let some_r_a = ClassExpression::ObjectSomeValuesFrom { property: r.clone(), filler: Box::new(a.clone()) };
+        let x = Individual::Named(crate::IRI("http://example.com/x".to_string()));
+        let anonymous = |id: &str| Individual::Anonymous(crate::NodeID(id.to_string()));
+
+        let mut graph = CompletionGraph::new();
+        graph.add_concept(&x, a.clone());
+        graph.add_concept(&x, some_r_a.clone());
+        graph.add_role(&x, r.clone(), anonymous("_:n1"));
+        graph.add_concept(&anonymous("_:n1"), a.clone());
+        graph.add_concept(&anonymous("_:n1"), b.clone());
+        graph.add_concept(&anonymous("_:n1"), some_r_a.clone());
+        graph.add_role(&anonymous("_:n1"), r.clone(), anonymous("_:n2"));
+        graph.add_concept(&anonymous("_:n2"), a.clone());
+        graph.add_concept(&anonymous("_:n2"), some_r_a.clone());
+
+        let index = |individual: &Individual| graph.nodes.iter().position(|node| node.individual == *individual).unwrap();
+        // Named individuals neither block nor are blocked
+        assert!(!graph.is_blocked(index(&x)));
+        assert!(!graph.is_blocked(index(&anonymous("_:n1"))));
+        // n2's concepts are a subset of its ancestor n1's
+        assert!(graph.is_blocked(index(&anonymous("_:n2"))));
+
+        let nodes = graph.nodes.len();
+        ExistentialRule.apply(&mut graph, &Ontology::default());
+        assert_eq!(graph.nodes.len(), nodes);
+
+        // n3 is a child of x that n1 also points to; n1 is not its tree ancestor
+        graph.add_role(&x, r.clone(), anonymous("_:n3"));
+        graph.add_role(&anonymous("_:n1"), r, anonymous("_:n3"));
+        graph.add_concept(&anonymous("_:n3"), a);
+        graph.add_concept(&anonymous("_:n3"), some_r_a);
+        assert!(!graph.is_blocked(graph.nodes.len() - 1));
+    }
+
+    #[test]
+    fn test_disjunction_backtracks_to_second_disjunct() {
+        let a = ClassExpression::Class(Class(crate::IRI("http://example.com/A".to_string())));
+        let b = ClassExpression::Class(Class(crate::IRI("http://example.com/B".to_string())));
+        let x = Individual::Named(crate::IRI("http://example.com/x".to_string()));
+        let union = ClassExpression::ObjectUnionOf(vec![a.clone(), b.clone()]);
+        let not = |class: &ClassExpression| ClassExpression::ObjectComplementOf(Box::new(class.clone()));
+
+        // (A ⊔ B) ⊓ ¬A forces B
+        let mut ontology = Ontology::default();
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion {
+            class: ClassExpression::ObjectIntersectionOf(vec![union.clone(), not(&a)]),
+            individual: x.clone(),
+        }));
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        assert_eq!(reasoner.stats.backtracks, 1);
+        let node = reasoner.graph.nodes.iter().find(|node| node.individual == x).unwrap();
+        assert!(node.concepts.contains(&b));
+        assert!(!node.concepts.contains(&a));
+
+        // (A ⊔ B) ⊓ ¬A ⊓ ¬B clashes on every branch
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion { class: not(&b), individual: x }));
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::Complement { .. } }));
+    }
+
+    #[test]
+    fn test_single_operand_class_axioms_are_no_ops() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  EquivalentClasses(Class(<http://example.com/A>))
+  DisjointClasses(Class(<http://example.com/B>))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(Class(<http://example.com/B>) NamedIndividual(<http://example.com/x>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert!(reasoner.is_consistent().unwrap());
+        assert!(reasoner.unsatisfiable_classes().is_empty());
+        let hierarchy = reasoner.classify().unwrap();
+        assert!(hierarchy.equivalent_classes.is_empty());
+        assert_eq!(hierarchy.superclasses[&class("A")], vec![Class::thing()]);
+        assert_eq!(hierarchy.superclasses[&class("B")], vec![Class::thing()]);
+
+        // Both axioms are trivially entailed
+        let a = ClassExpression::Class(class("A"));
+        assert!(reasoner.entails(&Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes: vec![a.clone()] })));
+        assert!(reasoner.entails(&Axiom::Class(crate::ClassAxiom::DisjointClasses { classes: vec![a] })));
+    }
+
+    #[test]
+    fn test_disjoint_classes_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DisjointClasses(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(Class(<http://example.com/B>) NamedIndividual(<http://example.com/x>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_disjoint_union_expansion() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DisjointUnion(Class(<http://example.com/Pet>) Class(<http://example.com/Dog>) Class(<http://example.com/Cat>))
+  ClassAssertion(Class(<http://example.com/Dog>) NamedIndividual(<http://example.com/rex>))
+  ClassAssertion(Class(<http://example.com/Cat>) NamedIndividual(<http://example.com/tom>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        let pet = ClassExpression::Class(Class(crate::IRI("http://example.com/Pet".to_string())));
+        assert!(reasoner.graph.nodes.iter().all(|node| node.concepts.contains(&pet)));
+
+        let mut ontology = ontology;
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion {
+            class: ClassExpression::Class(Class(crate::IRI("http://example.com/Cat".to_string()))),
+            individual: Individual::Named(crate::IRI("http://example.com/rex".to_string())),
+        }));
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_min_cardinality_successors_are_distinct() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectMinCardinality(2 ObjectProperty(<http://example.com/hasChild>)))
+  SubClassOf(Class(<http://example.com/Parent>) ObjectMaxCardinality(1 ObjectProperty(<http://example.com/hasChild>)))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::MaxCardinality { .. } }));
+    }
+
+    #[test]
+    fn test_materialize_object_properties() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SymmetricObjectProperty(ObjectProperty(<http://example.com/marriedTo>))
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/partOf>))
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasPart>) ObjectProperty(<http://example.com/partOf>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/marriedTo>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/mary>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/partOf>) NamedIndividual(<http://example.com/wheel>) NamedIndividual(<http://example.com/car>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/partOf>) NamedIndividual(<http://example.com/spoke>) NamedIndividual(<http://example.com/wheel>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let edge = |property: &str, source: &str, target: &str| crate::Assertion::ObjectPropertyAssertion {
+            property: ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI(format!("http://example.com/{}", property)))),
+            source: Individual::Named(crate::IRI(format!("http://example.com/{}", source))),
+            target: Individual::Named(crate::IRI(format!("http://example.com/{}", target))),
+        };
+
+        let inferred = reasoner.materialize_object_properties().unwrap();
+        assert!(inferred.contains(&edge("marriedTo", "mary", "john")));
+        assert!(inferred.contains(&edge("partOf", "spoke", "car")));
+        assert!(inferred.contains(&edge("hasPart", "car", "wheel")));
+        assert!(inferred.contains(&edge("hasPart", "car", "spoke")));
+        // Asserted edges are not repeated
+        assert!(!inferred.contains(&edge("marriedTo", "john", "mary")));
+        assert_eq!(inferred.len(), 5);
+    }
+
+    #[test]
+    fn test_materialize_object_properties_through_chains() {
+        // The second step of the chain only holds once the sub-property edge is derived
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasElderBrother>) ObjectProperty(<http://example.com/hasBrother>))
+  SubObjectPropertyOf(ObjectPropertyChain(ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>)) ObjectProperty(<http://example.com/hasBrother>)) ObjectProperty(<http://example.com/hasUncle>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/bob>) NamedIndividual(<http://example.com/ann>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasElderBrother>) NamedIndividual(<http://example.com/bob>) NamedIndividual(<http://example.com/carl>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let edge = |property: &str, source: &str, target: &str| crate::Assertion::ObjectPropertyAssertion {
+            property: ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI(format!("http://example.com/{}", property)))),
+            source: Individual::Named(crate::IRI(format!("http://example.com/{}", source))),
+            target: Individual::Named(crate::IRI(format!("http://example.com/{}", target))),
+        };
+
+        assert_eq!(reasoner.materialize_object_properties().unwrap(), vec![
+            edge("hasBrother", "bob", "carl"),
+            edge("hasUncle", "ann", "carl"),
+        ]);
+    }
+
+    #[test]
+    fn test_instances_of_includes_inferred_members() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/teaches>) Class(<http://example.com/Person>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/teaches>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/course>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+
+        let persons = reasoner.instances_of(&class("Person")).unwrap();
+        assert!(persons.contains(&individual("john")));
+        assert!(persons.contains(&individual("mary")));
+        assert!(!persons.contains(&individual("course")));
+        assert_eq!(reasoner.instances_of(&class("Student")).unwrap(), vec![individual("john")]);
+    }
+
+    #[test]
+    fn test_domain_and_range_force_membership() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Employee>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/worksFor>) Class(<http://example.com/Organization>))
+  InverseObjectProperties(ObjectProperty(<http://example.com/employs>) ObjectProperty(<http://example.com/worksFor>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/worksFor>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/acme>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/employs>) NamedIndividual(<http://example.com/globex>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        let member = |reasoner: &TableauReasoner, individual: &str, class: &str| {
+            let individual = Individual::Named(crate::IRI(format!("http://example.com/{}", individual)));
+            let class = ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", class))));
+            reasoner.graph.nodes.iter().any(|node| node.individual == individual && node.concepts.contains(&class))
+        };
+        assert!(member(&reasoner, "john", "Employee"));
+        assert!(member(&reasoner, "acme", "Organization"));
+        // employs is the inverse of worksFor, so its source is in the range and its
+        // target in the domain
+        assert!(member(&reasoner, "mary", "Employee"));
+        assert!(member(&reasoner, "globex", "Organization"));
+        assert!(!member(&reasoner, "acme", "Employee"));
+
+        let mut ontology = ontology;
+        ontology.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "DisjointClasses(Class(<http://example.com/Organization>) Class(<http://example.com/Person>))",
+        ).unwrap());
+        ontology.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "ClassAssertion(Class(<http://example.com/Person>) NamedIndividual(<http://example.com/acme>))",
+        ).unwrap());
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_cardinality_restrictions() {
+        let r = ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/r".to_string())));
+        let x = Individual::Named(crate::IRI("http://example.com/x".to_string()));
+        let assert_class = |class: ClassExpression| {
+            let mut ontology = Ontology::default();
+            ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion { class, individual: x.clone() }));
+            TableauReasoner::new(ontology)
+        };
+
+        let mut reasoner = assert_class(ClassExpression::ObjectExactCardinality { cardinality: 2, property: r.clone(), filler: None });
+        assert!(reasoner.is_consistent().unwrap());
+        let node = reasoner.graph.nodes.iter().position(|node| node.individual == x).unwrap();
+        assert_eq!(reasoner.graph.successors_in(node, &r, &ClassExpression::Class(Class::thing())).len(), 2);
+
+        let mut reasoner = assert_class(ClassExpression::ObjectIntersectionOf(vec![
+            ClassExpression::ObjectMinCardinality { min: 3, property: r.clone(), filler: None },
+            ClassExpression::ObjectMaxCardinality { max: 2, property: r, filler: None },
+        ]));
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::MaxCardinality { .. } }));
+    }
+
+    #[test]
+    fn test_max_cardinality_merges_successors() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectMaxCardinality(1 ObjectProperty(<http://example.com/hasMother>)) NamedIndividual(<http://example.com/ann>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasMother>) NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/mary>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasMother>) NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/maria>))
+  ClassAssertion(Class(<http://example.com/Teacher>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        let teacher = ClassExpression::Class(Class(crate::IRI("http://example.com/Teacher".to_string())));
+        let maria = Individual::Named(crate::IRI("http://example.com/maria".to_string()));
+        assert!(reasoner.graph.nodes.iter().any(|node| node.individual == maria && node.concepts.contains(&teacher)));
+
+        // Once merged, contradicting types clash
+        let mut ontology = ontology;
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::ClassAssertion {
+            class: ClassExpression::ObjectComplementOf(Box::new(teacher)),
+            individual: maria,
+        }));
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_max_cardinality_considers_every_set_of_distinct_successors() {
+        // x, asserted first, is not known to differ from anyone, so a greedy choice
+        // starting from it finds neither the merge of c with a nor the clash below
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectMaxCardinality(2 ObjectProperty(<http://example.com/hasChild>)) NamedIndividual(<http://example.com/p>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/p>) NamedIndividual(<http://example.com/x>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/p>) NamedIndividual(<http://example.com/a>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/p>) NamedIndividual(<http://example.com/b>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/p>) NamedIndividual(<http://example.com/c>))
+  DifferentIndividuals(NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+  DifferentIndividuals(NamedIndividual(<http://example.com/c>) NamedIndividual(<http://example.com/b>))
+  ClassAssertion(Class(<http://example.com/Teacher>) NamedIndividual(<http://example.com/c>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        let teacher = ClassExpression::Class(Class(crate::IRI("http://example.com/Teacher".to_string())));
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let has_teacher = |name: &str| reasoner.graph.nodes.iter().any(|node| node.individual == individual(name) && node.concepts.contains(&teacher));
+        assert!(has_teacher("a"));
+        assert!(!has_teacher("x"));
+
+        // Three pairwise distinct children exceed the restriction
+        let mut ontology = ontology;
+        ontology.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "DifferentIndividuals(NamedIndividual(<http://example.com/c>) NamedIndividual(<http://example.com/a>))",
+        ).unwrap());
+        assert!(!TableauReasoner::new(ontology).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_object_property_key_identifies_individuals() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Batch>) NamedIndividual(<http://example.com/b1>))
+  ClassAssertion(Class(<http://example.com/Recalled>) NamedIndividual(<http://example.com/b1>))
+  ClassAssertion(Class(<http://example.com/Batch>) NamedIndividual(<http://example.com/b2>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/producedBy>) NamedIndividual(<http://example.com/b1>) NamedIndividual(<http://example.com/line7>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/producedBy>) NamedIndividual(<http://example.com/b2>) NamedIndividual(<http://example.com/line7>))
+)"#;
+        let mut ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::HasKey {
+            class: Class(crate::IRI("http://example.com/Batch".to_string())),
+            object_property_expression: vec![ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/producedBy".to_string())))],
+            data_property: vec![],
+        }));
+        let mut reasoner = TableauReasoner::new(ontology);
+        let b2 = Individual::Named(crate::IRI("http://example.com/b2".to_string()));
+        assert!(reasoner.is_instance_of(&b2, &Class(crate::IRI("http://example.com/Recalled".to_string()))));
+    }
+
+    #[test]
+    fn test_data_property_key_identifies_individuals() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Product>) NamedIndividual(<http://example.com/p1>))
+  ClassAssertion(Class(<http://example.com/Organic>) NamedIndividual(<http://example.com/p1>))
+  ClassAssertion(Class(<http://example.com/Product>) NamedIndividual(<http://example.com/p2>))
+  ClassAssertion(Class(<http://example.com/Product>) NamedIndividual(<http://example.com/p3>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasSKU>) NamedIndividual(<http://example.com/p1>) "SKU-1")
+  DataPropertyAssertion(DataProperty(<http://example.com/hasSKU>) NamedIndividual(<http://example.com/p2>) "SKU-1")
+  DataPropertyAssertion(DataProperty(<http://example.com/hasSKU>) NamedIndividual(<http://example.com/p3>) "SKU-3")
+  HasKey(Class(<http://example.com/Product>) () (DataProperty(<http://example.com/hasSKU>)))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let organic = Class(crate::IRI("http://example.com/Organic".to_string()));
+
+        assert!(reasoner.is_instance_of(&individual("p2"), &organic));
+        assert!(!reasoner.is_instance_of(&individual("p3"), &organic));
+    }
+
+    #[test]
+    fn test_data_property_key_compares_values_and_respects_inequalities() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Product>) NamedIndividual(<http://example.com/p1>))
+  ClassAssertion(Class(<http://example.com/Product>) NamedIndividual(<http://example.com/p2>))
+  DataPropertyAssertion(DataProperty(<http://example.com/code>) NamedIndividual(<http://example.com/p1>) "1"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  DataPropertyAssertion(DataProperty(<http://example.com/code>) NamedIndividual(<http://example.com/p2>) "01"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  HasKey(Class(<http://example.com/Product>) () (DataProperty(<http://example.com/code>)))
+)"#;
+        let mut ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        assert!(reasoner.is_consistent().unwrap());
+        let p1 = Individual::Named(crate::IRI("http://example.com/p1".to_string()));
+        let p2 = Individual::Named(crate::IRI("http://example.com/p2".to_string()));
+        assert!(reasoner.graph.identities.contains(&(p1.clone(), p2.clone())));
+
+        // The key makes p1 and p2 the same, so they cannot also be different
+        ontology.axioms.push(Axiom::Assertion(crate::Assertion::DifferentIndividuals { individuals: vec![p1, p2] }));
+        assert!(matches!(
+            TableauReasoner::new(ontology).check_consistency(),
+            ConsistencyResult::Inconsistent { clash: Clash::SameAndDifferent { .. } }
+        ));
+    }
+
+    #[test]
+    fn test_equivalent_and_inverse_edges_are_stored_once() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasChild>) ObjectProperty(<http://example.com/hasParent>))
+  EquivalentObjectProperties(ObjectProperty(<http://example.com/hasChild>) ObjectProperty(<http://example.com/hasKid>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/bob>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasKid>) NamedIndividual(<http://example.com/ann>) NamedIndividual(<http://example.com/bob>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/bob>) NamedIndividual(<http://example.com/ann>))
+  ObjectPropertyAssertion(ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>)) NamedIndividual(<http://example.com/bob>) NamedIndividual(<http://example.com/ann>))
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/hasKid>) Class(<http://example.com/Happy>)) NamedIndividual(<http://example.com/ann>))
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Parent>)) NamedIndividual(<http://example.com/bob>))
+  ClassAssertion(ObjectSomeValuesFrom(ObjectInverseOf(ObjectProperty(<http://example.com/hasParent>)) Class(<http://example.com/Person>)) NamedIndividual(<http://example.com/ann>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        // The four assertions state one edge; the existential gets a fresh successor,
+        // since bob is not known to be a Person
+        assert_eq!(
+            reasoner.check_consistency(),
+            ConsistencyResult::Consistent { model_summary: ModelSummary { nodes: 3, edges: 2 } }
+        );
+
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+        assert!(reasoner.is_instance_of(&individual("bob"), &class("Happy")));
+        assert!(!reasoner.is_instance_of(&individual("bob"), &class("Person")));
+        assert!(reasoner.is_instance_of(&individual("ann"), &class("Parent")));
+        assert!(reasoner.entails(&crate::parser::OWLParser::parse_axiom(
+            "ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/bob>) NamedIndividual(<http://example.com/ann>))",
+        ).unwrap()));
+    }
+
+    #[test]
+    fn test_exact_cardinality_bounds_successors() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectExactCardinality(1 ObjectProperty(<http://example.com/R>) Class(<http://example.com/C>)) NamedIndividual(<http://example.com/a>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/c>))
+  ClassAssertion(Class(<http://example.com/C>) NamedIndividual(<http://example.com/b>))
+  ClassAssertion(Class(<http://example.com/C>) NamedIndividual(<http://example.com/c>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        // b and c may be the same individual, which then satisfies the restriction
+        assert!(TableauReasoner::new(ontology.clone()).is_consistent().unwrap());
+
+        let mut different = ontology;
+        different.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "DifferentIndividuals(NamedIndividual(<http://example.com/b>) NamedIndividual(<http://example.com/c>))",
+        ).unwrap());
+        let mut reasoner = TableauReasoner::new(different);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::MaxCardinality { .. } }));
+
+        // The min bound creates two distinct successors, which the max bound accepts
+        let required = crate::parser::OWLParser::parse_ontology(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectExactCardinality(2 ObjectProperty(<http://example.com/R>) Class(<http://example.com/C>)) NamedIndividual(<http://example.com/a>))
+)"#).unwrap();
+        let mut reasoner = TableauReasoner::new(required);
+        assert!(reasoner.is_consistent().unwrap());
+        let a = Individual::Named(crate::IRI("http://example.com/a".to_string()));
+        let a_index = reasoner.graph.nodes.iter().position(|node| node.individual == a).unwrap();
+        assert_eq!(reasoner.graph.nodes[a_index].roles.len(), 2);
+    }
+
+    #[test]
+    fn test_different_individuals_exceed_max_cardinality() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectMaxCardinality(1 ObjectProperty(<http://example.com/hasChild>)) NamedIndividual(<http://example.com/mary>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/tom>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/ann>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        // Without the unique name assumption tom and ann may be the same child
+        assert!(TableauReasoner::new(ontology.clone()).is_consistent().unwrap());
+
+        let mut different = ontology;
+        different.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "DifferentIndividuals(NamedIndividual(<http://example.com/tom>) NamedIndividual(<http://example.com/ann>))",
+        ).unwrap());
+        assert!(!TableauReasoner::new(different).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_universal_rule_constrains_new_successors() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/D>)) NamedIndividual(<http://example.com/a>))
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/C>)) NamedIndividual(<http://example.com/a>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+        let a = Individual::Named(crate::IRI("http://example.com/a".to_string()));
+        let a_node = reasoner.graph.nodes.iter().find(|n| n.individual == a).unwrap();
+        assert_eq!(a_node.roles.len(), 1);
+        let successor = &a_node.roles[0].1;
+        let successor_node = reasoner.graph.nodes.iter().find(|n| &n.individual == successor).unwrap();
+        assert!(successor_node.concepts.contains(&class("D")));
+        assert!(successor_node.concepts.contains(&class("C")));
+    }
+
+    #[test]
+    fn test_existential_restrictions_get_separate_successors() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/X>) ObjectIntersectionOf(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/A>)) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) ObjectComplementOf(Class(<http://example.com/A>)))))
+  ClassAssertion(Class(<http://example.com/X>) NamedIndividual(<http://example.com/a>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        // ∃R.A ⊓ ∃R.¬A needs two R-successors, not one holding both A and ¬A
+        assert!(reasoner.is_consistent().unwrap());
+        let a = Individual::Named(crate::IRI("http://example.com/a".to_string()));
+        let a_node = reasoner.graph.nodes.iter().find(|n| n.individual == a).unwrap();
+        assert_eq!(a_node.roles.len(), 2);
+
+        let x = Class(crate::IRI("http://example.com/X".to_string()));
+        assert!(!reasoner.entails(&Axiom::Class(crate::ClassAxiom::SubClassOf {
+            sub_class: ClassExpression::Class(x),
+            super_class: ClassExpression::Class(Class(crate::IRI(
+                "http://www.w3.org/2002/07/owl#Nothing".to_string()
+            ))),
+        })));
+    }
+
+    #[test]
+    fn test_universal_rule_reaches_asserted_role_target() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/R>) ObjectComplementOf(Class(<http://example.com/C>))) NamedIndividual(<http://example.com/a>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/a>) NamedIndividual(<http://example.com/b>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let b = Individual::Named(crate::IRI("http://example.com/b".to_string()));
+        let c = Class(crate::IRI("http://example.com/C".to_string()));
+        assert!(!reasoner.is_instance_of(&b, &c));
+        assert!(reasoner.is_instance_of_expression(&b, &ClassExpression::ObjectComplementOf(Box::new(ClassExpression::Class(c)))));
+    }
+
+    #[test]
+    fn test_custom_expansion_rule() {
+        /// Adds `Tagged` to every node that does not have it yet.
+        #[derive(Debug)]
+        struct TagEveryNode;
+
+        impl ExpansionRule for TagEveryNode {
+            fn apply(&self, graph: &mut CompletionGraph, _ontology: &Ontology) -> bool {
+                let tagged = ClassExpression::Class(Class(crate::IRI("http://example.com/Tagged".to_string())));
+                let mut changed = false;
+                for node in &mut graph.nodes {
+                    if !node.concepts.contains(&tagged) {
+                        node.concepts.push(tagged.clone());
+                        changed = true;
+                    }
+                }
+                changed
+            }
+        }
+
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        reasoner.add_rule(TagEveryNode);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let tagged = Class(crate::IRI("http://example.com/Tagged".to_string()));
+        let mary = Individual::Named(crate::IRI("http://example.com/mary".to_string()));
+        assert!(reasoner.is_instance_of(&mary, &tagged));
+        // The fresh successor created by the existential rule was tagged as well
+        assert_eq!(reasoner.graph.nodes.len(), 2);
+        assert!(reasoner.graph.nodes.iter().all(|node| node.concepts.contains(&ClassExpression::Class(tagged.clone()))));
+    }
+
+    #[test]
+    fn test_inferred_property_equivalence() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasParent>) ObjectProperty(<http://example.com/childOf>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/childOf>) ObjectProperty(<http://example.com/hasParent>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasMother>) ObjectProperty(<http://example.com/hasParent>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let reasoner = TableauReasoner::new(ontology);
+        let property = |name: &str| ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI(format!("http://example.com/{}", name))));
+
+        assert!(reasoner.are_properties_equivalent(&property("hasParent"), &property("childOf")));
+        assert!(!reasoner.are_properties_equivalent(&property("hasMother"), &property("hasParent")));
+    }
+
+    #[test]
+    fn test_property_disjointness() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DisjointObjectProperties(ObjectProperty(<http://example.com/likes>) ObjectProperty(<http://example.com/dislikes>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/loves>) ObjectProperty(<http://example.com/likes>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hates>) ObjectProperty(<http://example.com/dislikes>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let reasoner = TableauReasoner::new(ontology);
+        let property = |name: &str| ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI(format!("http://example.com/{}", name))));
+
+        assert!(reasoner.are_properties_disjoint(&property("likes"), &property("dislikes")));
+        assert!(reasoner.are_properties_disjoint(&property("hates"), &property("loves")));
+        assert!(!reasoner.are_properties_disjoint(&property("loves"), &property("likes")));
+    }
+
+    #[test]
+    fn test_redundant_sub_class_axiom() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/C>))
+  SubClassOf(Class(<http://example.com/D>) Class(<http://example.com/D>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let reasoner = TableauReasoner::new(ontology.clone());
+
+        assert_eq!(reasoner.redundant_axioms(), vec![ontology.axioms[2].clone(), ontology.axioms[3].clone()]);
+    }
+
+    #[test]
+    fn test_range_applies_to_fresh_successors() {
+        // Successors of A-instances must be C, but the range makes them B, and B and C
+        // are disjoint
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://www.w3.org/2002/07/owl#Thing>)))
+  SubClassOf(Class(<http://example.com/A>) ObjectAllValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/C>)))
+  SubClassOf(Class(<http://example.com/B>) ObjectComplementOf(Class(<http://example.com/C>)))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/a>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        assert!(TableauReasoner::new(ontology.clone()).is_consistent().unwrap());
+
+        let mut ranged = ontology;
+        ranged.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "ObjectPropertyRange(ObjectProperty(<http://example.com/R>) Class(<http://example.com/B>))",
+        ).unwrap());
+        assert!(!TableauReasoner::new(ranged).is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_range_inherited_from_super_property() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasSon>) ObjectProperty(<http://example.com/hasChild>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasSon>) NamedIndividual(<http://example.com/mary>) NamedIndividual(<http://example.com/tom>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        let tom = Individual::Named(crate::IRI("http://example.com/tom".to_string()));
+        assert!(reasoner.is_instance_of(&tom, &Class(crate::IRI("http://example.com/Person".to_string()))));
+    }
+
+    #[test]
+    fn test_realization_separates_asserted_and_inferred_types() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let types = reasoner.realize().unwrap();
+
+        let john = Individual::Named(crate::IRI("http://example.com/john".to_string()));
+        let student = Class(crate::IRI("http://example.com/Student".to_string()));
+        let person = Class(crate::IRI("http://example.com/Person".to_string()));
+        assert!(types[&john].all.contains(&student));
+        assert!(types[&john].all.contains(&person));
+        assert_eq!(types[&john].asserted, vec![student]);
+        assert_eq!(types[&john].inferred(), vec![person]);
+    }
+
+    #[test]
+    fn test_existential_witnesses_are_shared_between_sources() {
+        let mut axioms = Vec::new();
+        for i in 0..10 {
+            axioms.push(format!(
+                "SubClassOf(Class(<http://example.com/A{i}>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/C>)))\n  ClassAssertion(Class(<http://example.com/A{i}>) NamedIndividual(<http://example.com/a{i}>))"
+            ));
+        }
+        let input = format!("Ontology(<http://example.com/ontology>\n  {}\n)", axioms.join("\n  "));
+        let ontology = crate::parser::OWLParser::parse_ontology(&input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        // Ten named individuals share a single anonymous witness
+        assert_eq!(
+            reasoner.check_consistency(),
+            ConsistencyResult::Consistent { model_summary: ModelSummary { nodes: 11, edges: 10 } }
+        );
+    }
+
+    #[test]
+    fn test_existential_witnesses_not_shared_under_different_universals() {
+        // Sharing the C-successor would wrongly force it into both D and not D
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectIntersectionOf(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/C>)) ObjectAllValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/D>))) NamedIndividual(<http://example.com/a>))
+  ClassAssertion(ObjectIntersectionOf(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/r>) Class(<http://example.com/C>)) ObjectAllValuesFrom(ObjectProperty(<http://example.com/r>) ObjectComplementOf(Class(<http://example.com/D>)))) NamedIndividual(<http://example.com/b>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+        assert_eq!(reasoner.graph.nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_existential_witness_sharing_does_not_cause_false_clash() {
+        // p and q share their s-successor before the universals reach them from x and
+        // y, after which the shared successor would have to be both D and not D
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/s>) Class(<http://example.com/C>)) NamedIndividual(<http://example.com/p>))
+  ClassAssertion(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/s>) Class(<http://example.com/C>)) NamedIndividual(<http://example.com/q>))
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/r>) ObjectAllValuesFrom(ObjectProperty(<http://example.com/s>) Class(<http://example.com/D>))) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectAllValuesFrom(ObjectProperty(<http://example.com/r>) ObjectAllValuesFrom(ObjectProperty(<http://example.com/s>) ObjectComplementOf(Class(<http://example.com/D>)))) NamedIndividual(<http://example.com/y>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/r>) NamedIndividual(<http://example.com/x>) NamedIndividual(<http://example.com/p>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/r>) NamedIndividual(<http://example.com/y>) NamedIndividual(<http://example.com/q>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+        assert!(reasoner.stats.unshared_retry);
+        assert!(!reasoner.graph.has_shared_witness());
+    }
+
+    #[test]
+    fn test_entails_inferred_equivalence() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/C>))
+  SubClassOf(Class(<http://example.com/C>) Class(<http://example.com/A>))
+  SubClassOf(Class(<http://example.com/D>) Class(<http://example.com/A>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+
+        assert!(reasoner.entails(&Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes: vec![class("A"), class("C")] })));
+        assert!(!reasoner.entails(&Axiom::Class(crate::ClassAxiom::EquivalentClasses { classes: vec![class("A"), class("D")] })));
+    }
+
+    #[test]
+    fn test_entails_inferred_disjointness() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) ObjectComplementOf(Class(<http://example.com/B>)))
+  SubClassOf(Class(<http://example.com/C>) Class(<http://example.com/A>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+
+        assert!(reasoner.entails(&Axiom::Class(crate::ClassAxiom::DisjointClasses { classes: vec![class("C"), class("B")] })));
+        assert!(!reasoner.entails(&Axiom::Class(crate::ClassAxiom::DisjointClasses { classes: vec![class("C"), class("A")] })));
+    }
+
+    #[test]
+    fn test_entails_subsumption_and_assertions() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+  SymmetricObjectProperty(ObjectProperty(<http://example.com/knows>))
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/marriedTo>) ObjectProperty(<http://example.com/knows>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/marriedTo>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/mary>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+        let subclass_of = |sub: &str, sup: &str| Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class: class(sub), super_class: class(sup) });
+        let edge = |property: &str, source: &str, target: &str| Axiom::Assertion(crate::Assertion::ObjectPropertyAssertion {
+            property: ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI(format!("http://example.com/{}", property)))),
+            source: Individual::Named(crate::IRI(format!("http://example.com/{}", source))),
+            target: Individual::Named(crate::IRI(format!("http://example.com/{}", target))),
+        });
+
+        assert!(reasoner.entails(&subclass_of("Student", "Agent")));
+        assert!(!reasoner.entails(&subclass_of("Agent", "Student")));
+        assert!(reasoner.entails(&edge("knows", "mary", "john")));
+        assert!(!reasoner.entails(&edge("marriedTo", "mary", "john")));
+        // Unsupported axioms are never entailed
+        assert!(!reasoner.entails(&Axiom::ObjectProperty(crate::ObjectPropertyAxiom::TransitiveObjectProperty {
+            property: ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/knows".to_string()))),
+        })));
+    }
+
+    #[test]
+    fn test_check_entailment_is_three_valued() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Couple>) ObjectMaxCardinality(1 ObjectProperty(<http://example.com/hasChild>)))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+        let subclass_of = |sub: ClassExpression, sup: ClassExpression| Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class: sub, super_class: sup });
+        let has_child = ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/hasChild".to_string())));
+
+        assert_eq!(reasoner.check_entailment(&subclass_of(class("Student"), class("Person"))), Entailment::True);
+        assert_eq!(reasoner.check_entailment(&subclass_of(class("Person"), class("Student"))), Entailment::False);
+
+        // At most one child implies at most two, but refuting that needs a rule for
+        // negated maximum cardinalities, which does not exist yet
+        let at_most_two = ClassExpression::ObjectMaxCardinality { max: 2, property: has_child, filler: None };
+        assert!(!reasoner.entails(&subclass_of(class("Couple"), at_most_two.clone())));
+        assert_eq!(reasoner.check_entailment(&subclass_of(class("Couple"), at_most_two)), Entailment::Unknown);
+
+        // Unsupported axiom types are never answered
+        assert_eq!(reasoner.check_entailment(&Axiom::ObjectProperty(crate::ObjectPropertyAxiom::TransitiveObjectProperty {
+            property: ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/hasChild".to_string()))),
+        })), Entailment::Unknown);
+    }
+
+    #[test]
+    fn test_check_entailment_inverse_restriction_is_unknown() {
+        // A ⊑ C holds, but it needs ∀R⁻.C to reach back from the fresh R-successor
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/B>)))
+  SubClassOf(Class(<http://example.com/B>) ObjectAllValuesFrom(ObjectInverseOf(ObjectProperty(<http://example.com/R>)) Class(<http://example.com/C>)))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+        let axiom = Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class: class("A"), super_class: class("C") });
+        assert_eq!(reasoner.check_entailment(&axiom), Entailment::Unknown);
+    }
+
+    #[test]
+    fn test_negative_property_assertion_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasFather>) ObjectProperty(<http://example.com/hasParent>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasFather>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/bob>))
+  NegativeObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/bob>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::NegativePropertyAssertion { .. } }));
+    }
+
+    #[test]
+    fn test_negative_property_assertion_clash_after_edges_change() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubObjectPropertyOf(ObjectProperty(<http://example.com/hasFather>) ObjectProperty(<http://example.com/hasParent>))
+  NegativeObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/bob>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        reasoner.initialize();
+        assert_eq!(reasoner.find_clash(), None);
+
+        // The closure kept from the first check is not reused once the edges change
+        let named = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let has_father = ObjectPropertyExpression::ObjectProperty(ObjectProperty(crate::IRI("http://example.com/hasFather".to_string())));
+        reasoner.graph.add_role(&named("john"), has_father, named("bob"));
+        assert!(matches!(reasoner.find_clash(), Some(Clash::NegativePropertyAssertion { .. })));
+    }
+
+    #[test]
+    fn test_incremental_consistency_reuses_saturated_graph() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+  SubClassOf(Class(<http://example.com/Person>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Agent>)))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+
+        assert!(reasoner.is_consistent_incremental().unwrap());
+        let full = reasoner.stats;
+        assert!(!full.reused_graph);
+        assert!(full.rule_applications > 0);
+
+        // An assertion about an unrelated individual only needs a little more work
+        let added = crate::parser::OWLParser::parse_axiom(
+            "ClassAssertion(Class(<http://example.com/Building>) NamedIndividual(<http://example.com/library>))",
+        ).unwrap();
+        reasoner.ontology.axioms.push(added);
+        reasoner.ontology.change_tracker.revision += 1;
+        assert!(reasoner.is_consistent_incremental().unwrap());
+        assert!(reasoner.stats.reused_graph);
+        assert!(reasoner.stats.rule_applications < full.rule_applications);
+        let library = Individual::Named(crate::IRI("http://example.com/library".to_string()));
+        assert!(reasoner.graph.nodes.iter().any(|node| node.individual == library));
+
+        // Removing an axiom falls back to reasoning from scratch
+        reasoner.ontology.remove_axiom(3);
+        reasoner.ontology.change_tracker.revision += 1;
+        assert!(reasoner.is_consistent_incremental().unwrap());
+        assert!(!reasoner.stats.reused_graph);
+        let john = Individual::Named(crate::IRI("http://example.com/john".to_string()));
+        assert!(!reasoner.graph.nodes.iter().any(|node| node.individual == john));
+    }
+
+    #[test]
+    fn test_incremental_consistency_does_not_resume_from_a_choice() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectUnionOf(Class(<http://example.com/A>) Class(<http://example.com/B>)) NamedIndividual(<http://example.com/x>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent_incremental().unwrap());
+
+        // The first check chose A for x; the new assertion must lead to B instead
+        let added = crate::parser::OWLParser::parse_axiom(
+            "ClassAssertion(ObjectComplementOf(Class(<http://example.com/A>)) NamedIndividual(<http://example.com/x>))",
+        ).unwrap();
+        reasoner.ontology.axioms.push(added);
+        reasoner.ontology.change_tracker.revision += 1;
+        assert!(reasoner.is_consistent_incremental().unwrap());
+        assert!(!reasoner.stats.reused_graph);
+    }
+
+    #[test]
+    fn test_direct_subclasses_of_expression() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Employee>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/GradStudent>) Class(<http://example.com/Student>))
+  SubClassOf(Class(<http://example.com/Manager>) Class(<http://example.com/Employee>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert_eq!(
+            reasoner.direct_subclasses_of_expression(&ClassExpression::Class(class("Person"))).unwrap(),
+            vec![class("Employee"), class("Student")]
+        );
+        assert_eq!(
+            reasoner.direct_subclasses_of_expression(&ClassExpression::Class(class("Agent"))).unwrap(),
+            vec![class("Person")]
+        );
+    }
+
+    #[test]
+    fn test_least_common_subsumer() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/P>))
+  SubClassOf(Class(<http://example.com/B>) Class(<http://example.com/P>))
+  SubClassOf(Class(<http://example.com/P>) Class(<http://example.com/Q>))
+  SubClassOf(Class(<http://example.com/C>) Class(<http://example.com/Q>))
+  SubClassOf(Class(<http://example.com/A1>) Class(<http://example.com/A>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert_eq!(reasoner.least_common_subsumer(&class("A"), &class("B")).unwrap(), vec![class("P")]);
+        assert_eq!(reasoner.least_common_subsumer(&class("A1"), &class("B")).unwrap(), vec![class("P")]);
+        assert_eq!(reasoner.least_common_subsumer(&class("A"), &class("C")).unwrap(), vec![class("Q")]);
+        assert_eq!(reasoner.least_common_subsumer(&class("A1"), &class("A")).unwrap(), vec![class("A")]);
+
+        // Incomparable minimal subsumers are all returned
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Singer>) Class(<http://example.com/Artist>))
+  SubClassOf(Class(<http://example.com/Singer>) Class(<http://example.com/Employee>))
+  SubClassOf(Class(<http://example.com/Dancer>) Class(<http://example.com/Artist>))
+  SubClassOf(Class(<http://example.com/Dancer>) Class(<http://example.com/Employee>))
+)"#;
+        let mut reasoner = TableauReasoner::new(crate::parser::OWLParser::parse_ontology(input).unwrap());
+        assert_eq!(reasoner.least_common_subsumer(&class("Singer"), &class("Dancer")).unwrap(), vec![class("Artist"), class("Employee")]);
+    }
+
+    #[test]
+    fn test_check_abox_only() {
+        let parse = |axioms: &str| crate::parser::OWLParser::parse_ontology(&format!("Ontology(<http://example.com/ontology>\n{}\n)", axioms)).unwrap();
+
+        // A contradiction in the data itself
+        let mut reasoner = TableauReasoner::new(parse(r#"
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/A>)) NamedIndividual(<http://example.com/x>))"#));
+        assert!(!reasoner.check_abox_only());
+        assert!(!reasoner.is_consistent().unwrap());
+
+        let mut reasoner = TableauReasoner::new(parse(r#"
+  SameIndividual(NamedIndividual(<http://example.com/x>) NamedIndividual(<http://example.com/y>))
+  DifferentIndividuals(NamedIndividual(<http://example.com/y>) NamedIndividual(<http://example.com/x>))"#));
+        assert!(!reasoner.check_abox_only());
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Inconsistent { clash: Clash::SameAndDifferent { .. } }));
+
+        // A contradiction that only follows from the TBox
+        let mut reasoner = TableauReasoner::new(parse(r#"
+  SubClassOf(Class(<http://example.com/A>) ObjectComplementOf(Class(<http://example.com/B>)))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(Class(<http://example.com/B>) NamedIndividual(<http://example.com/x>))"#));
+        assert!(reasoner.check_abox_only());
+        assert!(!reasoner.is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_batch_satisfiable() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Dog>) ObjectComplementOf(Class(<http://example.com/Cat>)))
+  SubClassOf(Class(<http://example.com/Kitten>) Class(<http://example.com/Cat>))
+  SubClassOf(Class(<http://example.com/CatOwner>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/owns>) Class(<http://example.com/Cat>)))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+        let both = |a: &str, b: &str| ClassExpression::ObjectIntersectionOf(vec![class(a), class(b)]);
+
+        let expressions = vec![
+            class("Cat"),
+            both("Cat", "Dog"),
+            both("Kitten", "Dog"),
+            both("CatOwner", "Dog"),
+            ClassExpression::ObjectComplementOf(Box::new(class("Cat"))),
+            both("Cat", "Cat"),
+        ];
+        assert_eq!(reasoner.batch_satisfiable(&expressions), vec![true, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn test_all_disjoint_pairs() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  DisjointClasses(Class(<http://example.com/Animal>) Class(<http://example.com/Plant>))
+  SubClassOf(Class(<http://example.com/Dog>) Class(<http://example.com/Animal>))
+  SubClassOf(Class(<http://example.com/Pet>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/livesWith>) Class(<http://example.com/Animal>)))
+  SubClassOf(Class(<http://example.com/Impossible>) Class(<http://example.com/Dog>))
+  SubClassOf(Class(<http://example.com/Impossible>) Class(<http://example.com/Plant>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        // The asserted pair and the pair inherited by Dog; Impossible is left out
+        assert_eq!(reasoner.all_disjoint_pairs(), vec![(class("Animal"), class("Plant")), (class("Dog"), class("Plant"))]);
+    }
+
+    #[test]
+    fn test_find_models_enumerates_disjuncts() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectUnionOf(Class(<http://example.com/A>) Class(<http://example.com/B>)) NamedIndividual(<http://example.com/x>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology.clone());
+        let class = |name: &str| ClassExpression::Class(Class(crate::IRI(format!("http://example.com/{}", name))));
+
+        let models = reasoner.find_models(10);
+        assert_eq!(models.len(), 2);
+        assert!(models[0].nodes[0].concepts.contains(&class("A")) && !models[0].nodes[0].concepts.contains(&class("B")));
+        assert!(models[1].nodes[0].concepts.contains(&class("B")) && !models[1].nodes[0].concepts.contains(&class("A")));
+        assert_eq!(reasoner.find_models(1).len(), 1);
+
+        // Only the second disjunct is clash-free once x is known not to be an A
+        let mut restricted = ontology;
+        restricted.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "ClassAssertion(ObjectComplementOf(Class(<http://example.com/A>)) NamedIndividual(<http://example.com/x>))",
+        ).unwrap());
+        let models = TableauReasoner::new(restricted).find_models(10);
+        assert_eq!(models.len(), 1);
+        assert!(models[0].nodes[0].concepts.contains(&class("B")));
+    }
+
+    #[test]
+    fn test_gci_with_complex_left_hand_side() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(ObjectSomeValuesFrom(ObjectProperty(<http://example.com/R>) Class(<http://example.com/A>)) Class(<http://example.com/B>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/x>) NamedIndividual(<http://example.com/y>))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/y>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/R>) NamedIndividual(<http://example.com/z>) NamedIndividual(<http://example.com/w>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let b = Class(crate::IRI("http://example.com/B".to_string()));
+
+        assert!(reasoner.is_consistent().unwrap());
+        let x = reasoner.graph.nodes.iter().find(|node| node.individual == individual("x")).unwrap();
+        assert!(x.concepts.contains(&ClassExpression::Class(b.clone())));
+        assert!(reasoner.is_instance_of(&individual("x"), &b));
+        assert!(!reasoner.is_instance_of(&individual("z"), &b));
+
+        // The GCI also takes part in subsumption: ∃R.A ⊓ C ⊑ B
+        let expression = ClassExpression::ObjectIntersectionOf(vec![
+            ClassExpression::some(
+                ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/R".to_string()))),
+                ClassExpression::Class(Class(crate::IRI("http://example.com/A".to_string()))),
+            ),
+            ClassExpression::Class(Class(crate::IRI("http://example.com/C".to_string()))),
+        ]);
+        assert!(reasoner.entails(&Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class: expression, super_class: ClassExpression::Class(b.clone()) })));
+
+        // Outside a reasoner the rule collects the inclusions from the ontology itself
+        let mut graph = CompletionGraph::new();
+        let r = ObjectPropertyExpression::ObjectProperty(crate::ObjectProperty(crate::IRI("http://example.com/R".to_string())));
+        graph.add_role(&individual("x"), r, individual("y"));
+        graph.add_concept(&individual("y"), ClassExpression::Class(Class(crate::IRI("http://example.com/A".to_string()))));
+        assert!(GciRule.apply(&mut graph, &reasoner.ontology));
+        assert!(graph.nodes[0].concepts.contains(&ClassExpression::Class(b)));
+    }
+
+    #[test]
+    fn test_gci_with_negated_left_hand_side() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(ObjectComplementOf(Class(<http://example.com/A>)) Class(<http://example.com/B>))
+  DisjointClasses(Class(<http://example.com/A>) Class(<http://example.com/C>))
+  DisjointClasses(Class(<http://example.com/B>) Class(<http://example.com/D>))
+  ClassAssertion(Class(<http://example.com/C>) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(Class(<http://example.com/D>) NamedIndividual(<http://example.com/y>))
+  ClassAssertion(Class(<http://example.com/E>) NamedIndividual(<http://example.com/z>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let individual = |name: &str| Individual::Named(crate::IRI(format!("http://example.com/{}", name)));
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+
+        assert!(reasoner.is_consistent().unwrap());
+        // x is disjoint from A, so ¬A ⊑ B makes it a B
+        assert!(reasoner.is_instance_of(&individual("x"), &class("B")));
+        // y cannot be a B, so it must be an A
+        assert!(reasoner.is_instance_of(&individual("y"), &class("A")));
+        // Nothing is known about z, so it is in A ⊔ B but in neither on its own
+        assert!(!reasoner.is_instance_of(&individual("z"), &class("A")));
+        assert!(!reasoner.is_instance_of(&individual("z"), &class("B")));
+
+        // ¬A ⊑ B also means ¬B ⊑ A, which is fully decided
+        let subclass_of = |sub: ClassExpression, sup: ClassExpression| Axiom::Class(crate::ClassAxiom::SubClassOf { sub_class: sub, super_class: sup });
+        let not_b = ClassExpression::complement(ClassExpression::Class(class("B")));
+        assert_eq!(reasoner.check_entailment(&subclass_of(not_b, ClassExpression::Class(class("A")))), Entailment::True);
+        assert_eq!(reasoner.check_entailment(&subclass_of(ClassExpression::Class(class("E")), ClassExpression::Class(class("B")))), Entailment::False);
+
+        // An individual in neither A nor B contradicts the axiom
+        let mut reasoner = TableauReasoner::new(crate::parser::OWLParser::parse_ontology(r#"Ontology(<http://example.com/ontology>
+  SubClassOf(ObjectComplementOf(Class(<http://example.com/A>)) Class(<http://example.com/B>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/A>)) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/B>)) NamedIndividual(<http://example.com/x>))
+)"#).unwrap());
+        assert!(!reasoner.is_consistent().unwrap());
+    }
+
+    #[test]
+    fn test_prepare_answers_queries_without_reasoning_again() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  EquivalentClasses(Class(<http://example.com/Person>) Class(<http://example.com/Human>))
+  SubClassOf(Class(<http://example.com/Person>) Class(<http://example.com/Agent>))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+        let john = Individual::Named(crate::IRI("http://example.com/john".to_string()));
+
+        reasoner.prepare().unwrap();
+        let stats = reasoner.stats;
+        let hierarchy = reasoner.previous_results.as_ref().unwrap().class_hierarchy.clone();
+        let inclusions = reasoner.preprocessed.as_ref().unwrap().inclusions.clone();
+        assert!(Arc::ptr_eq(reasoner.graph.inclusions.as_ref().unwrap(), &inclusions));
+        // Scratch graphs start from the same preprocessing instead of redoing it
+        let mut scratch = reasoner.scratch_reasoner();
+        scratch.initialize();
+        assert!(Arc::ptr_eq(scratch.graph.inclusions.as_ref().unwrap(), &inclusions));
+
+        assert!(reasoner.is_subclass_of(&class("Student"), &class("Agent")));
+        assert!(reasoner.is_subclass_of(&class("Human"), &class("Person")));
+        assert!(!reasoner.is_subclass_of(&class("Agent"), &class("Person")));
+        assert!(reasoner.is_instance_of(&john, &class("Human")));
+        assert!(!reasoner.is_instance_of(&john, &Class::nothing()));
+        assert!(reasoner.is_instance_of_expression(&john, &ClassExpression::Class(class("Agent"))));
+        // Classes outside the ontology fall back to a satisfiability test
+        assert!(reasoner.is_subclass_of(&Class::nothing(), &class("Unknown")));
+
+        // No saturation ran on the reasoner's own graph, and the hierarchy was kept
+        assert!(stats.saturation_rounds > 0);
+        assert_eq!(reasoner.stats, stats);
+        reasoner.prepare().unwrap();
+        assert_eq!(reasoner.stats, stats);
+        assert_eq!(reasoner.classify_incremental().unwrap().ancestors(&class("Student")), hierarchy.ancestors(&class("Student")));
+
+        // After an addition the incremental check is the only saturation of the graph
+        reasoner.ontology.axioms.push(crate::parser::OWLParser::parse_axiom(
+            "ClassAssertion(Class(<http://example.com/Person>) NamedIndividual(<http://example.com/mary>))",
+        ).unwrap());
+        reasoner.ontology.change_tracker.revision += 1;
+        reasoner.prepare().unwrap();
+        assert!(reasoner.stats.reused_graph);
+        // The new revision gets its own preprocessing
+        assert!(!Arc::ptr_eq(&reasoner.preprocessed.as_ref().unwrap().inclusions, &inclusions));
+        let mary = Individual::Named(crate::IRI("http://example.com/mary".to_string()));
+        assert!(reasoner.realize_incremental().unwrap()[&mary].all.contains(&class("Person")));
+
+        // Without prepare the same answers are computed from scratch
+        let unprepared = TableauReasoner::new(reasoner.ontology.clone());
+        assert!(unprepared.is_subclass_of(&class("Student"), &class("Agent")));
+        assert!(!unprepared.is_subclass_of(&class("Agent"), &class("Person")));
+        assert!(unprepared.previous_results.is_none());
+    }
+
+    #[test]
+    fn test_max_backtracks_gives_up_with_unknown() {
+        // Six independent choices followed by a disjunction whose disjuncts both clash,
+        // so every combination of the earlier choices is tried
+        let mut input = String::from("Ontology(<http://example.com/ontology>\n");
+        for i in 0..6 {
+            input.push_str(&format!(
+                "  ClassAssertion(ObjectUnionOf(Class(<http://example.com/A{i}>) Class(<http://example.com/B{i}>)) NamedIndividual(<http://example.com/x>))\n"
+            ));
+        }
+        input.push_str("  ClassAssertion(ObjectUnionOf(Class(<http://example.com/Z1>) Class(<http://example.com/Z2>)) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/Z1>)) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/Z2>)) NamedIndividual(<http://example.com/x>))
+)");
+        let ontology = crate::parser::OWLParser::parse_ontology(&input).unwrap();
+
+        let mut unlimited = TableauReasoner::new(ontology.clone());
+        assert!(matches!(unlimited.check_consistency(), ConsistencyResult::Inconsistent { .. }));
+        assert!(unlimited.stats.backtracks > 10);
+        assert!(!unlimited.stats.backtrack_limit_reached);
+
+        let config = ReasonerConfig { max_backtracks: Some(10), ..ReasonerConfig::default() };
+        let mut limited = TableauReasoner::with_config(ontology, config);
+        assert_eq!(limited.check_consistency(), ConsistencyResult::Unknown { backtracks: 10 });
+        assert!(limited.stats.backtrack_limit_reached);
+        assert_eq!(limited.is_consistent(), Err(ConsistencyUnknown { backtracks: 10 }));
+        assert!(matches!(limited.realize(), Err(ConsistencyUnknown { backtracks: 10 })));
+        assert_eq!(limited.materialize_object_properties(), Err(ConsistencyUnknown { backtracks: 10 }));
+        // Refutations that give up prove nothing
+        assert!(!limited.is_instance_of(&Individual::Named(crate::IRI("http://example.com/x".to_string())), &crate::Class(crate::IRI("http://example.com/Z1".to_string()))));
+    }
+
+    #[test]
+    fn test_clash_beyond_branch_depth_is_unknown() {
+        // Each of the nested choices takes its first disjunct (the second one clashes
+        // at once), so the last disjunction lies beyond the depth limit and only its
+        // clashing first disjunct is tried
+        let mut input = String::from("Ontology(<http://example.com/ontology>\n");
+        for i in 0..MAX_BRANCH_DEPTH {
+            input.push_str(&format!(
+                "  ClassAssertion(ObjectUnionOf(Class(<http://example.com/A{i}>) Class(<http://example.com/B{i}>)) NamedIndividual(<http://example.com/x>))\n  ClassAssertion(ObjectComplementOf(Class(<http://example.com/B{i}>)) NamedIndividual(<http://example.com/x>))\n"
+            ));
+        }
+        input.push_str("  ClassAssertion(ObjectUnionOf(Class(<http://example.com/Z1>) Class(<http://example.com/Z2>)) NamedIndividual(<http://example.com/x>))
+  ClassAssertion(ObjectComplementOf(Class(<http://example.com/Z1>)) NamedIndividual(<http://example.com/x>))
+)");
+        let ontology = crate::parser::OWLParser::parse_ontology(&input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(matches!(reasoner.check_consistency(), ConsistencyResult::Unknown { .. }));
+        assert!(reasoner.stats.branch_depth_limit_reached);
+        assert!(!reasoner.stats.backtrack_limit_reached);
+    }
+
+    #[test]
+    fn test_completion_graph_to_dot() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/ann>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let dot = completion_graph_to_dot(&reasoner.graph);
+        assert!(dot.starts_with("digraph completion_graph {\n"));
+        assert!(dot.contains(r#"  n0 [label="<http://example.com/ann>\nClass(<http://example.com/Parent>)\n"#));
+        assert!(dot.contains(r#"  n1 [label="_:fresh1\nClass(<http://example.com/Person>)"];"#));
+        assert!(dot.contains(r#"  n0 -> n1 [label="ObjectProperty(<http://example.com/hasChild>)"];"#));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_classify_places_nothing_at_the_bottom() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>))
+  SubClassOf(Class(<http://example.com/U>) ObjectIntersectionOf(Class(<http://example.com/C>) ObjectComplementOf(Class(<http://example.com/C>))))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        let hierarchy = reasoner.classify().unwrap();
+        let class = |name: &str| Class(crate::IRI(format!("http://example.com/{}", name)));
+        let nothing = Class::nothing();
+
+        // owl:Nothing is beneath every satisfiable class, directly below the leaves
+        for name in ["A", "B", "C"] {
+            assert!(hierarchy.ancestors(&nothing).contains(&class(name)));
+            assert!(hierarchy.descendants(&class(name)).contains(&nothing));
+        }
+        assert_eq!(hierarchy.superclasses[&nothing], vec![class("A"), class("C")]);
+
+        // The unsatisfiable class is equivalent to owl:Nothing; A is not
+        assert_eq!(hierarchy.equivalent_classes[&class("U")], vec![nothing.clone()]);
+        assert_eq!(hierarchy.equivalent_classes[&nothing], vec![class("U")]);
+        assert!(!hierarchy.equivalent_classes.contains_key(&class("A")));
+    }
+
+    #[test]
+    fn test_nothing_membership_is_a_clash() {
+        let input = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/A>) Class(<http://www.w3.org/2002/07/owl#Nothing>))
+  ClassAssertion(Class(<http://example.com/A>) NamedIndividual(<http://example.com/a>))
+)"#;
+        let ontology = crate::parser::OWLParser::parse_ontology(input).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert_eq!(
+            reasoner.check_consistency(),
+            ConsistencyResult::Inconsistent { clash: Clash::Nothing { individual: Individual::Named(crate::IRI("http://example.com/a".to_string())) } }
+        );
+    }
+
+    #[test]
+    fn test_unqualified_min_cardinality_uses_thing() {
+        let ontology = crate::api::load_ontology(r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(ObjectMinCardinality(2 ObjectProperty(<http://example.com/R>)) NamedIndividual(<http://example.com/a>))
+)"#).unwrap();
+        let mut reasoner = TableauReasoner::new(ontology);
+        assert!(reasoner.is_consistent().unwrap());
+
+        let a = Individual::Named(crate::IRI("http://example.com/a".to_string()));
+        let node = reasoner.graph.nodes.iter().find(|n| n.individual == a).unwrap();
+        assert_eq!(node.roles.len(), 2);
+        assert_ne!(node.roles[0].1, node.roles[1].1);
+
+        let thing = ClassExpression::Class(Class::thing());
+        for (_, successor) in &node.roles {
+            let successor_node = reasoner.graph.nodes.iter().find(|n| &n.individual == successor).unwrap();
+            assert_eq!(successor_node.concepts, vec![thing.clone()]);
+        }
+    }
+
     #[test]
     fn test_tableau_reasoner_creation() {
         let ontology = Ontology::default();
@@ -791,7 +5591,7 @@ mod tests {
         let mut reasoner = TableauReasoner::new(Ontology::default());
         
         // Test with an empty ontology - should be consistent
-        assert!(reasoner.is_consistent());
+        assert!(reasoner.is_consistent().unwrap());
     }
     
     #[test]
@@ -804,7 +5604,7 @@ mod tests {
     #[test]
     fn test_classify_empty_ontology() {
         let mut reasoner = TableauReasoner::new(Ontology::default());
-        let hierarchy = reasoner.classify();
+        let hierarchy = reasoner.classify().unwrap();
         assert!(hierarchy.subclasses.is_empty());
         assert!(hierarchy.superclasses.is_empty());
     }
@@ -824,9 +5624,14 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let reasoner = TableauReasoner::new(ontology);
@@ -857,9 +5662,14 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let reasoner = TableauReasoner::new(ontology);
@@ -884,25 +5694,34 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let mut reasoner = TableauReasoner::new(ontology);
-        let hierarchy = reasoner.classify();
+        let hierarchy = reasoner.classify().unwrap();
         
-        // Check that the hierarchy structure is created correctly
-        // Note: Our current implementation might not detect explicit subsumptions
-        // but it should at least create the structure correctly
-        assert_eq!(hierarchy.superclasses.len(), 0);
-        assert_eq!(hierarchy.subclasses.len(), 0);
+        // The told subsumption A ⊑ B is detected, between owl:Thing and owl:Nothing
+        assert_eq!(hierarchy.superclasses.len(), 3);
+        assert_eq!(hierarchy.superclasses[&class_a], vec![class_b.clone()]);
+        assert_eq!(hierarchy.superclasses[&class_b], vec![Class::thing()]);
+        assert_eq!(hierarchy.superclasses[&Class::nothing()], vec![class_a.clone()]);
+        assert_eq!(hierarchy.subclasses.len(), 3);
+        assert_eq!(hierarchy.subclasses[&class_b], vec![class_a.clone()]);
+        assert_eq!(hierarchy.subclasses[&class_a], vec![Class::nothing()]);
+        assert!(hierarchy.equivalent_classes.is_empty());
     }
     
     #[test]
     fn test_realization_empty_ontology() {
         let mut reasoner = TableauReasoner::new(Ontology::default());
-        let individual_types = reasoner.realize();
+        let individual_types = reasoner.realize().unwrap();
         assert!(individual_types.is_empty());
     }
     
@@ -920,13 +5739,18 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let mut reasoner = TableauReasoner::new(ontology);
-        let individual_types = reasoner.realize();
+        let individual_types = reasoner.realize().unwrap();
         
         // Check that we found the individual
         assert_eq!(individual_types.len(), 1);
@@ -952,9 +5776,14 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let mut reasoner = TableauReasoner::new(ontology);
@@ -980,7 +5809,7 @@ mod tests {
         reasoner.graph.add_concept(&individual, complement);
         
         // Check for clash directly
-        assert!(reasoner.has_clash());
+        assert!(reasoner.find_clash().is_some());
     }
     
     #[test]
@@ -1128,9 +5957,14 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let reasoner = TableauReasoner::new(ontology);
@@ -1161,9 +5995,14 @@ mod tests {
         });
         
         let ontology = Ontology {
+            ontology_iri: None,
+            version_iri: None,
             direct_imports: vec![],
+            annotations: vec![],
             axioms: vec![axiom],
             change_tracker: crate::ChangeTracker::default(),
+            axiom_sources: vec![],
+            prefixes: None,
         };
         
         let reasoner = TableauReasoner::new(ontology);
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 /// A SPARQL endpoint for querying OWL 2 ontologies
 pub struct SparqlEndpoint {
+    #[allow(dead_code)]
     ontology: Ontology,
 }
 
@@ -35,7 +36,7 @@ impl SparqlEndpoint {
     /// 
     /// * `Ok(SparqlResults)` - The query results
     /// * `Err(Owl2RsError)` - An error if the query fails
-    pub fn query(&self, query: &str) -> Result<SparqlResults, Owl2RsError> {
+    pub fn query(&self, _query: &str) -> Result<SparqlResults, Owl2RsError> {
         // For now, we'll return an error indicating this is not yet implemented
         // In a full implementation, we would:
         // 1. Parse the SPARQL query
@@ -56,7 +57,7 @@ impl SparqlEndpoint {
     /// 
     /// * `Ok(SparqlResults)` - The query results
     /// * `Err(Owl2RsError)` - An error if the query fails
-    pub async fn query_async(&self, query: &str) -> Result<SparqlResults, Owl2RsError> {
+    pub async fn query_async(&self, _query: &str) -> Result<SparqlResults, Owl2RsError> {
         // For now, we'll return an error indicating this is not yet implemented
         // In a full implementation, we would:
         // 1. Parse the SPARQL query
@@ -69,7 +70,7 @@ impl SparqlEndpoint {
 }
 
 /// Results from a SPARQL query
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SparqlResults {
     /// The variables in the query
     pub variables: Vec<String>,
@@ -24,7 +24,7 @@ pub fn run_owl2_test_case(test_file_path: &Path) -> Result<(), String> {
     let mut reasoner = Reasoner::new(ontology);
     
     // Check consistency
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().map_err(|e| format!("Reasoning failed: {}", e))?;
     
     // For now, we'll just print the result
     println!("Test case {:?} is consistent: {}", test_file_path, is_consistent);
@@ -56,7 +56,7 @@ pub fn run_owl2_test_suite(test_dir_path: &Path) -> Result<usize, String> {
         let path = entry.path();
         
         // Check if it's an RDF file
-        if path.extension().map_or(false, |ext| ext == "rdf") {
+        if path.extension().is_some_and(|ext| ext == "rdf") {
             total_count += 1;
             match run_owl2_test_case(&path) {
                 Ok(()) => {
@@ -31,18 +31,18 @@ fn test_comprehensive_reasoning() {
     let mut reasoner = TableauReasoner::new(ontology);
     
     // Check consistency
-    assert!(reasoner.is_consistent(), "Ontology should be consistent");
+    assert!(reasoner.is_consistent().unwrap(), "Ontology should be consistent");
     
     // Classify the ontology
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     
     // Check that we have the expected number of classes
     // Note: This is a simplified check - in a real implementation we would check the actual hierarchy
-    assert!(hierarchy.superclasses.is_empty() || hierarchy.superclasses.len() > 0);
-    assert!(hierarchy.subclasses.is_empty() || hierarchy.subclasses.len() > 0);
+    assert!(hierarchy.superclasses.is_empty() || !hierarchy.superclasses.is_empty());
+    assert!(hierarchy.subclasses.is_empty() || !hierarchy.subclasses.is_empty());
     
     // Realize individuals
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     
     // Check that we found our individuals
     let john = Individual::Named(IRI("http://example.com/john".to_string()));
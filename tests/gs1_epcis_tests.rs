@@ -8,7 +8,7 @@ use std::path::Path;
 #[test]
 fn test_gs1_ontology_parsing() {
     let path = Path::new("test_cases/gs1_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load GS1 ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load GS1 ontology");
     
     // Check that we have the expected number of axioms
     assert!(ontology.axioms.len() > 10);
@@ -19,11 +19,11 @@ fn test_gs1_ontology_parsing() {
 #[test]
 fn test_gs1_ontology_consistency() {
     let path = Path::new("test_cases/gs1_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load GS1 ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load GS1 ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("GS1 ontology is consistent");
 }
@@ -31,11 +31,11 @@ fn test_gs1_ontology_consistency() {
 #[test]
 fn test_gs1_ontology_classification() {
     let path = Path::new("test_cases/gs1_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load GS1 ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load GS1 ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Compute the class hierarchy
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     
     // We can at least check that the function runs without error
     println!("Computed class hierarchy for GS1 ontology:");
@@ -46,7 +46,7 @@ fn test_gs1_ontology_classification() {
 #[test]
 fn test_epcis_ontology_parsing() {
     let path = Path::new("test_cases/epcis_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load EPCIS ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load EPCIS ontology");
     
     // Check that we have the expected number of axioms
     assert!(ontology.axioms.len() > 10);
@@ -57,11 +57,11 @@ fn test_epcis_ontology_parsing() {
 #[test]
 fn test_epcis_ontology_consistency() {
     let path = Path::new("test_cases/epcis_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load EPCIS ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load EPCIS ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("EPCIS ontology is consistent");
 }
@@ -69,11 +69,11 @@ fn test_epcis_ontology_consistency() {
 #[test]
 fn test_epcis_ontology_classification() {
     let path = Path::new("test_cases/epcis_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load EPCIS ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load EPCIS ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Compute the class hierarchy
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     
     // We can at least check that the function runs without error
     println!("Computed class hierarchy for EPCIS ontology:");
@@ -84,11 +84,11 @@ fn test_epcis_ontology_classification() {
 #[test]
 fn test_gs1_reasoning() {
     let path = Path::new("test_cases/gs1_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load GS1 ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load GS1 ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Realize individuals
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     
     // Check that we have realized types for our individuals
     assert!(!individual_types.is_empty());
@@ -104,11 +104,11 @@ fn test_gs1_reasoning() {
 #[test]
 fn test_epcis_reasoning() {
     let path = Path::new("test_cases/epcis_test.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load EPCIS ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load EPCIS ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Realize individuals
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     
     // Check that we have realized types for our individuals
     assert!(!individual_types.is_empty());
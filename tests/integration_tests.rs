@@ -4,9 +4,11 @@
 //! of the parser and reasoner.
 
 use owl2_rs::{
-    parser::OWLParser,
+    api::load_ontology,
+    parser::{write_ontology, OWLParser},
     reasoner::TableauReasoner,
 };
+use std::collections::HashSet;
 use std::path::Path;
 
 /// A test case for OWL2 reasoning.
@@ -26,13 +28,13 @@ fn run_owl2_test_case(test_case: OWL2TestCase) {
     
     // Parse the ontology
     let ontology = OWLParser::parse_ontology(&test_case.ontology_str)
-        .expect(&format!("Failed to parse ontology for test case: {}", test_case.name));
+        .unwrap_or_else(|_| panic!("Failed to parse ontology for test case: {}", test_case.name));
     
     // Create a reasoner
     let mut reasoner = TableauReasoner::new(ontology);
     
     // Check consistency
-    let is_consistent = reasoner.is_consistent();
+    let is_consistent = reasoner.is_consistent().unwrap();
     assert_eq!(is_consistent, test_case.expected_consistent,
         "Consistency check failed for test case: {}. Expected: {}, Got: {}",
         test_case.name, test_case.expected_consistent, is_consistent);
@@ -118,7 +120,7 @@ fn test_gs1_ontology_consistency() {
     let mut reasoner = TableauReasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("GS1 ontology is consistent");
 }
@@ -143,7 +145,7 @@ fn test_epcis_ontology_consistency() {
     let mut reasoner = TableauReasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("EPCIS ontology is consistent");
 }
@@ -168,7 +170,118 @@ fn test_uht_milk_supplychain_consistency() {
     let mut reasoner = TableauReasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("UHT milk supply chain ontology is consistent");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_subsumption_provenance_from_imported_file() {
+    use owl2_rs::{api::{load_ontology_from_file, Reasoner}, Class, IRI};
+
+    let mut ontology = load_ontology_from_file(Path::new("test_cases/imports/university.ofn"))
+        .expect("Failed to load university ontology");
+    let upper = load_ontology_from_file(Path::new("test_cases/imports/upper.ofn"))
+        .expect("Failed to load upper ontology");
+    let upper_iri = IRI("http://example.com/upper".to_string());
+    ontology.merge_import(upper_iri.clone(), upper);
+
+    let reasoner = Reasoner::new(ontology);
+    let student = Class(IRI("http://example.com/Student".to_string()));
+    let person = Class(IRI("http://example.com/Person".to_string()));
+    let agent = Class(IRI("http://example.com/Agent".to_string()));
+
+    // Student ⊑ Person is asserted locally
+    let local = reasoner.subsumption_provenance(&student, &person).unwrap();
+    assert_eq!(local.len(), 1);
+    assert_eq!(local[0].1, None);
+
+    // Student ⊑ Agent relies on Person ⊑ Agent from the imported ontology
+    let provenance = reasoner.subsumption_provenance(&student, &agent).unwrap();
+    assert_eq!(provenance.len(), 2);
+    assert_eq!(provenance[0].1, None);
+    assert_eq!(provenance[1].1, Some(upper_iri));
+
+    assert!(reasoner.subsumption_provenance(&agent, &student).is_none());
+}
+
+#[test]
+fn test_imports_resolved_from_base_directory() {
+    use owl2_rs::{api::{load_ontology_with_imports, FileResolver, Reasoner}, Class, IRI};
+
+    let main = std::fs::read_to_string("test_cases/imports/university.ofn")
+        .expect("Failed to read university ontology");
+    let ontology = load_ontology_with_imports(&main, FileResolver::new("test_cases/imports"))
+        .expect("Failed to load university ontology with imports");
+
+    let upper_iri = IRI("http://example.com/upper".to_string());
+    assert_eq!(ontology.direct_imports, vec![upper_iri.clone()]);
+    assert_eq!(ontology.axioms.len(), 3);
+    assert_eq!(ontology.axiom_source(2), Some(&upper_iri));
+
+    let mut reasoner = Reasoner::new(ontology);
+    let hierarchy = reasoner.classify().unwrap();
+    let student = Class(IRI("http://example.com/Student".to_string()));
+    let agent = Class(IRI("http://example.com/Agent".to_string()));
+    assert!(hierarchy.ancestors(&student).contains(&agent));
+
+    let missing = "Ontology(<http://example.com/main>\n  Import(<http://example.com/nowhere>)\n)";
+    assert!(load_ontology_with_imports(missing, FileResolver::new("test_cases/imports")).is_err());
+}
+
+#[test]
+fn test_write_ontology_round_trip() {
+    for path in ["test_cases/uht_milk_supplychain.ofn", "test_cases/gs1_test.ofn", "test_cases/epcis_test.ofn"] {
+        let ontology_str = std::fs::read_to_string(path).expect("Failed to read test file");
+        let ontology = load_ontology(&ontology_str).expect("Failed to parse ontology");
+
+        let written = write_ontology(&ontology);
+        assert_eq!(written, ontology.to_string());
+        let reloaded = load_ontology(&written).expect("Failed to parse written ontology");
+
+        assert_eq!(reloaded.ontology_iri, ontology.ontology_iri);
+        assert_eq!(reloaded.direct_imports, ontology.direct_imports);
+        assert_eq!(reloaded.axioms.iter().collect::<HashSet<_>>(), ontology.axioms.iter().collect::<HashSet<_>>(), "{}", path);
+    }
+}
+
+#[test]
+fn test_write_ontology_round_trip_escapes_literals() {
+    use owl2_rs::{Assertion, Axiom, DataProperty, Datatype, Individual, Literal, Ontology, IRI};
+
+    let assertion = |value: &str| Axiom::Assertion(Assertion::DataPropertyAssertion {
+        property: DataProperty(IRI("http://example.com/note".to_string())),
+        source: Individual::Named(IRI("http://example.com/file".to_string())),
+        target: Literal {
+            value: value.to_string(),
+            datatype: Datatype(IRI("http://www.w3.org/2001/XMLSchema#string".to_string())),
+            lang: None,
+        },
+    });
+    let ontology = Ontology {
+        axioms: vec![assertion(r#"say "hi""#), assertion(r"C:\temp\"), assertion(r#"\""#)],
+        ..Default::default()
+    };
+
+    let written = write_ontology(&ontology);
+    let reloaded = load_ontology(&written).expect("Failed to parse written ontology");
+    assert_eq!(reloaded.axioms, ontology.axioms);
+}
+
+#[test]
+fn test_write_ontology_round_trip_anonymous_individuals() {
+    let input = r#"Ontology(<http://example.com/ontology>
+  ClassAssertion(Class(<http://example.com/Person>) _:someone)
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/knows>) NamedIndividual(<http://example.com/ann>) _:someone)
+  DataPropertyAssertion(DataProperty(<http://example.com/age>) _:someone "42"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  SameIndividual(_:someone _:somebody)
+  DifferentIndividuals(_:someone NamedIndividual(<http://example.com/ann>))
+  SubClassOf(Class(<http://example.com/Friend>) ObjectHasValue(ObjectProperty(<http://example.com/knows>) _:someone))
+  EquivalentClasses(Class(<http://example.com/Pair>) ObjectOneOf(_:someone NamedIndividual(<http://example.com/ann>)))
+)"#;
+    let ontology = load_ontology(input).expect("Failed to parse anonymous individuals");
+    assert_eq!(ontology.axioms.len(), 7);
+
+    let reloaded = load_ontology(&write_ontology(&ontology)).expect("Failed to parse written ontology");
+    assert_eq!(reloaded.axioms, ontology.axioms);
+}
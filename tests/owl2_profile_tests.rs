@@ -41,7 +41,7 @@ mod tests {
         
         // This ontology should be parseable and consistent
         let mut reasoner = owl2_rs::api::Reasoner::new(ontology);
-        assert!(reasoner.is_consistent());
+        assert!(reasoner.is_consistent().unwrap());
     }
 
     /// Test that checks if an ontology conforms to OWL 2 RL profile
@@ -67,7 +67,7 @@ mod tests {
         
         // This ontology should be parseable and consistent
         let mut reasoner = owl2_rs::api::Reasoner::new(ontology);
-        assert!(reasoner.is_consistent());
+        assert!(reasoner.is_consistent().unwrap());
     }
 
     /// Test that shows a full OWL 2 ontology with constructs not allowed in profiles
@@ -86,7 +86,7 @@ mod tests {
         
         // This ontology should be parseable and consistent
         let mut reasoner = owl2_rs::api::Reasoner::new(ontology);
-        assert!(reasoner.is_consistent());
+        assert!(reasoner.is_consistent().unwrap());
     }
 
     /// Test profile checking functionality
@@ -4,9 +4,22 @@
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use owl2_rs::{Axiom, Class, ClassAxiom, ClassExpression, Entity, IRI, Individual, ObjectProperty, ObjectPropertyAxiom, ObjectPropertyExpression};
+    use std::collections::HashSet;
     use std::fs;
     use std::path::Path;
+
+    fn iri(name: &str) -> IRI {
+        IRI(format!("http://example.com/{}", name))
+    }
+
+    fn class(name: &str) -> ClassExpression {
+        ClassExpression::Class(Class(iri(name)))
+    }
+
+    fn property(name: &str) -> ObjectPropertyExpression {
+        ObjectPropertyExpression::ObjectProperty(ObjectProperty(iri(name)))
+    }
     
     /// Test loading ontology from Turtle file
     #[test]
@@ -16,8 +29,10 @@ mod tests {
 @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
 @prefix owl: <http://www.w3.org/2002/07/owl#> .
 @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix dc: <http://purl.org/dc/elements/1.1/> .
 
-<http://example.com/ontology> rdf:type owl:Ontology .
+<http://example.com/ontology> rdf:type owl:Ontology ;
+    dc:title "University" .
 <http://example.com/Student> rdf:type owl:Class .
 <http://example.com/Person> rdf:type owl:Class .
 <http://example.com/Student> rdfs:subClassOf <http://example.com/Person> .
@@ -28,13 +43,23 @@ mod tests {
         fs::write(temp_file, turtle_content).expect("Failed to write test file");
         
         // Try to load the ontology
-        let result = crate::rdf::load_ontology_from_turtle(temp_file);
+        let result = owl2_rs::rdf::load_ontology_from_turtle(temp_file);
         
         // Clean up
         fs::remove_file(temp_file).expect("Failed to remove test file");
         
-        // For now, we expect an error since the conversion is not fully implemented
-        assert!(result.is_err());
+        let ontology = result.expect("Failed to load Turtle");
+        assert_eq!(ontology.ontology_iri, Some(IRI("http://example.com/ontology".to_string())));
+        assert!(ontology.axioms.contains(&Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: class("Student"),
+            super_class: class("Person"),
+        })));
+        assert!(ontology.axioms.contains(&Axiom::Declaration(Entity::Class(Class(iri("Person"))))));
+        // An undeclared property on the header annotates the ontology
+        assert_eq!(ontology.annotations.len(), 1);
+        assert_eq!(ontology.annotations[0].property, IRI("http://purl.org/dc/elements/1.1/title".to_string()));
+        assert!(matches!(&ontology.annotations[0].value,
+            owl2_rs::AnnotationValue::Literal(literal) if literal.value == "University"));
     }
     
     /// Test loading ontology from JSON-LD file
@@ -74,13 +99,18 @@ mod tests {
         fs::write(temp_file, jsonld_content).expect("Failed to write test file");
         
         // Try to load the ontology
-        let result = crate::rdf::load_ontology_from_jsonld(temp_file);
+        let result = owl2_rs::rdf::load_ontology_from_jsonld(temp_file);
         
         // Clean up
         fs::remove_file(temp_file).expect("Failed to remove test file");
         
-        // For now, we expect an error since the conversion is not fully implemented
-        assert!(result.is_err());
+        let ontology = result.expect("Failed to load JSON-LD");
+        assert_eq!(ontology.ontology_iri, Some(IRI("http://example.com/ontology".to_string())));
+        assert!(ontology.axioms.contains(&Axiom::Class(ClassAxiom::SubClassOf {
+            sub_class: class("Student"),
+            super_class: class("Person"),
+        })));
+        assert!(ontology.axioms.contains(&Axiom::Declaration(Entity::Class(Class(iri("Student"))))));
     }
     
     /// Test RDF format conversion
@@ -104,7 +134,7 @@ mod tests {
         fs::write(input_file, turtle_content).expect("Failed to write test file");
         
         // Try to convert the format
-        let result = crate::rdf::convert_rdf_format(
+        let result = owl2_rs::rdf::convert_rdf_format(
             input_file, 
             output_file, 
             oxrdfio::RdfFormat::Turtle, 
@@ -121,4 +151,302 @@ mod tests {
         // In a full implementation, this should succeed
         assert!(result.is_err() || result.is_ok());
     }
-}
\ No newline at end of file
+
+    /// Test streaming a Functional-Style Syntax document to Turtle
+    #[test]
+    fn test_convert_fss_to_turtle() {
+        let fss = r#"Prefix(ex:=<http://example.com/>)
+Ontology(<http://example.com/ontology>
+  Import(<http://example.com/upper>)
+  # Students are people with an enrolment
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Student>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/enrolledIn>) Class(<http://example.com/Course>)))
+  ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))
+  DataPropertyAssertion(DataProperty(<http://example.com/hasName>) NamedIndividual(<http://example.com/john>) "John (Jr.)"^^<http://www.w3.org/2001/XMLSchema#string>)
+)"#;
+
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::convert_fss_to_turtle(fss.as_bytes(), &mut turtle).expect("Failed to convert");
+
+        let triples: Vec<String> = oxrdfio::RdfParser::from_format(oxrdfio::RdfFormat::Turtle)
+            .for_reader(turtle.as_slice())
+            .map(|quad| {
+                let quad = quad.expect("Output should be valid Turtle");
+                format!("{} {} {}", quad.subject, quad.predicate, quad.object)
+            })
+            .collect();
+
+        let expected = [
+            "<http://example.com/ontology> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Ontology>",
+            "<http://example.com/ontology> <http://www.w3.org/2002/07/owl#imports> <http://example.com/upper>",
+            "<http://example.com/Student> <http://www.w3.org/2000/01/rdf-schema#subClassOf> <http://example.com/Person>",
+            "<http://example.com/john> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.com/Student>",
+            "<http://example.com/john> <http://example.com/hasName> \"John (Jr.)\"",
+        ];
+        for triple in expected {
+            assert!(triples.iter().any(|t| t == triple), "missing triple: {}", triple);
+        }
+
+        // The existential restriction is written as an owl:Restriction blank node
+        assert!(triples.iter().any(|t| t.ends_with("<http://www.w3.org/2002/07/owl#onProperty> <http://example.com/enrolledIn>")));
+        assert!(triples.iter().any(|t| t.ends_with("<http://www.w3.org/2002/07/owl#someValuesFrom> <http://example.com/Course>")));
+    }
+
+    /// Test that string literals are scanned with the grammar's escapes
+    #[test]
+    fn test_convert_fss_to_turtle_literal_escapes() {
+        // The literal ends in an escaped backslash, so the next quote closes it
+        let fss = r#"Ontology(<http://example.com/ontology>
+  DataPropertyAssertion(DataProperty(<http://example.com/path>) NamedIndividual(<http://example.com/drive>) "C:\\")
+  DataPropertyAssertion(DataProperty(<http://example.com/label>) NamedIndividual(<http://example.com/drive>) "say \"C\"")
+  ClassAssertion(Class(<http://example.com/Drive>) NamedIndividual(<http://example.com/drive>))
+)"#;
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::convert_fss_to_turtle(fss.as_bytes(), &mut turtle).expect("Failed to convert");
+
+        let objects: Vec<String> = oxrdfio::RdfParser::from_format(oxrdfio::RdfFormat::Turtle)
+            .for_reader(turtle.as_slice())
+            .map(|quad| match quad.expect("Output should be valid Turtle").object {
+                oxrdf::Term::Literal(literal) => literal.value().to_string(),
+                other => other.to_string(),
+            })
+            .collect();
+        assert!(objects.iter().any(|object| object == "C:\\"));
+        assert!(objects.iter().any(|object| object == "say \"C\""));
+        assert!(objects.iter().any(|object| object == "<http://example.com/Drive>"));
+
+        // A bare trailing backslash escapes the closing quote, as in the grammar
+        let unterminated = "Ontology(<http://example.com/ontology>\n  DataPropertyAssertion(DataProperty(<http://example.com/path>) NamedIndividual(<http://example.com/drive>) \"C:\\\")\n)";
+        assert!(owl2_rs::rdf::convert_fss_to_turtle(unterminated.as_bytes(), &mut Vec::new()).is_err());
+    }
+
+    /// Test that malformed Functional-Style Syntax is reported
+    #[test]
+    fn test_convert_fss_to_turtle_rejects_unbalanced_input() {
+        let fss = "Ontology(<http://example.com/ontology>\n  SubClassOf(Class(<http://example.com/A>) Class(<http://example.com/B>)\n";
+        let mut turtle = Vec::new();
+        assert!(owl2_rs::rdf::convert_fss_to_turtle(fss.as_bytes(), &mut turtle).is_err());
+    }
+
+    /// Test reading class expressions, lists and assertions from Turtle
+    #[test]
+    fn test_load_ontology_from_turtle_structures() {
+        let turtle_content = r#"
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.com/> .
+
+ex:ontology rdf:type owl:Ontology ;
+    owl:imports ex:upper .
+ex:hasParent rdf:type owl:ObjectProperty ;
+    rdfs:domain ex:Person ;
+    rdfs:range ex:Person .
+ex:hasAge rdf:type owl:DatatypeProperty ;
+    rdfs:range xsd:integer .
+ex:StudentParent rdfs:subClassOf _:and .
+_:and rdf:type owl:Class ;
+    owl:intersectionOf _:l1 .
+_:l1 rdf:first ex:Person ;
+    rdf:rest _:l2 .
+_:l2 rdf:first _:some ;
+    rdf:rest rdf:nil .
+_:some rdf:type owl:Restriction ;
+    owl:onProperty ex:hasChild ;
+    owl:someValuesFrom ex:Student .
+ex:hasGrandparent owl:propertyChainAxiom _:c1 .
+_:c1 rdf:first ex:hasParent ;
+    rdf:rest _:c2 .
+_:c2 rdf:first ex:hasParent ;
+    rdf:rest rdf:nil .
+ex:john rdf:type ex:Student ;
+    ex:hasParent ex:mary ;
+    ex:hasAge "20"^^xsd:integer ;
+    rdfs:label "John"@en .
+_:someone rdf:type ex:Student ;
+    ex:hasParent ex:mary .
+ex:Adult rdf:type rdfs:Datatype ;
+    owl:equivalentClass _:adult .
+_:adult rdf:type rdfs:Datatype ;
+    owl:onDatatype xsd:integer ;
+    owl:withRestrictions _:f1 .
+_:f1 rdf:first _:min ;
+    rdf:rest rdf:nil .
+_:min xsd:minInclusive "18"^^xsd:integer .
+"#;
+        let temp_file = "test_ontology_structures.ttl";
+        fs::write(temp_file, turtle_content).expect("Failed to write test file");
+        let result = owl2_rs::rdf::load_ontology_from_turtle(temp_file);
+        fs::remove_file(temp_file).expect("Failed to remove test file");
+        let ontology = result.expect("Failed to load Turtle");
+
+        assert_eq!(ontology.direct_imports, vec![iri("upper")]);
+        let expected = [
+            "SubClassOf(Class(<http://example.com/StudentParent>) ObjectIntersectionOf(Class(<http://example.com/Person>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Student>))))",
+            "ObjectPropertyDomain(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>))",
+            "ObjectPropertyRange(ObjectProperty(<http://example.com/hasParent>) Class(<http://example.com/Person>))",
+            "DataPropertyRange(DataProperty(<http://example.com/hasAge>) Datatype(<http://www.w3.org/2001/XMLSchema#integer>))",
+            "ClassAssertion(Class(<http://example.com/Student>) NamedIndividual(<http://example.com/john>))",
+            "ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasParent>) NamedIndividual(<http://example.com/john>) NamedIndividual(<http://example.com/mary>))",
+            "DataPropertyAssertion(DataProperty(<http://example.com/hasAge>) NamedIndividual(<http://example.com/john>) \"20\"^^<http://www.w3.org/2001/XMLSchema#integer>)",
+            "AnnotationAssertion(<http://www.w3.org/2000/01/rdf-schema#label> <http://example.com/john> \"John\"@en)",
+        ];
+        for axiom in expected {
+            let axiom = owl2_rs::parser::OWLParser::parse_axiom(axiom).expect("Failed to parse expected axiom");
+            assert!(ontology.axioms.contains(&axiom), "missing axiom: {:?}", axiom);
+        }
+        assert!(ontology.axioms.contains(&Axiom::ObjectProperty(ObjectPropertyAxiom::SubObjectPropertyOf {
+            sub_property: ObjectPropertyExpression::ObjectPropertyChain(vec![property("hasParent"), property("hasParent")]),
+            super_property: property("hasGrandparent"),
+        })));
+
+        // A typed blank node is an anonymous individual, but the blank nodes of the
+        // structures are not read as individuals
+        let someone = Individual::Anonymous(owl2_rs::NodeID("_:someone".to_string()));
+        let anonymous_types: Vec<_> = ontology.axioms.iter()
+            .filter(|axiom| matches!(axiom, Axiom::Assertion(owl2_rs::Assertion::ClassAssertion { individual: Individual::Anonymous(_), .. })))
+            .collect();
+        assert_eq!(anonymous_types, vec![&Axiom::Assertion(owl2_rs::Assertion::ClassAssertion {
+            class: class("Student"),
+            individual: someone.clone(),
+        })]);
+        assert!(ontology.axioms.contains(&Axiom::Assertion(owl2_rs::Assertion::ObjectPropertyAssertion {
+            property: property("hasParent"),
+            source: someone,
+            target: Individual::Named(iri("mary")),
+        })));
+
+        // Datatype definitions cannot be represented and are skipped
+        assert!(!ontology.axioms.iter().any(|axiom| matches!(axiom, Axiom::Class(ClassAxiom::EquivalentClasses { .. }))));
+    }
+
+    /// Test that an ontology streamed to Turtle reads back as the same axioms
+    #[test]
+    fn test_turtle_round_trip() {
+        let fss = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.com/ontology>
+  Annotation(dc:title "People and their families")
+  Declaration(Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Student>) Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Parent>) ObjectMinCardinality(1 ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  EquivalentClasses(Class(<http://example.com/Orphan>) ObjectAllValuesFrom(ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>)) ObjectComplementOf(Class(<http://example.com/Alive>))))
+  DisjointClasses(Class(<http://example.com/A>) Class(<http://example.com/B>) Class(<http://example.com/C>))
+  InverseObjectProperties(ObjectProperty(<http://example.com/hasChild>) ObjectProperty(<http://example.com/hasParent>))
+  TransitiveObjectProperty(ObjectProperty(<http://example.com/hasAncestor>))
+  ClassAssertion(ObjectUnionOf(Class(<http://example.com/A>) Class(<http://example.com/B>)) NamedIndividual(<http://example.com/x>))
+  DifferentIndividuals(NamedIndividual(<http://example.com/x>) NamedIndividual(<http://example.com/y>))
+)"#;
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::convert_fss_to_turtle(fss.as_bytes(), &mut turtle).expect("Failed to convert");
+        let temp_file = "test_ontology_round_trip.ttl";
+        fs::write(temp_file, &turtle).expect("Failed to write test file");
+        let result = owl2_rs::rdf::load_ontology_from_turtle(temp_file);
+        fs::remove_file(temp_file).expect("Failed to remove test file");
+
+        let expected = owl2_rs::parser::OWLParser::parse_ontology(fss).expect("Failed to parse ontology");
+        let loaded = result.expect("Failed to load Turtle");
+        assert_eq!(loaded.ontology_iri, expected.ontology_iri);
+        assert_eq!(loaded.annotations, expected.annotations);
+        assert_eq!(loaded.axioms.iter().collect::<HashSet<_>>(), expected.axioms.iter().collect::<HashSet<_>>());
+    }
+
+    /// Test serializing an in-memory ontology to Turtle and loading it back
+    #[test]
+    fn test_write_ontology_to_turtle() {
+        let fss = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.com/ontology> <http://example.com/ontology/1.0>
+  Annotation(dc:title "Family")
+  Declaration(Class(<http://example.com/Person>))
+  Declaration(ObjectProperty(<http://example.com/hasChild>))
+  SubClassOf(Class(<http://example.com/Parent>) Class(<http://example.com/Person>))
+  ObjectPropertyDomain(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Parent>))
+  ObjectPropertyRange(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/alice>))
+  ObjectPropertyAssertion(ObjectProperty(<http://example.com/hasChild>) NamedIndividual(<http://example.com/alice>) NamedIndividual(<http://example.com/bob>))
+  DataPropertyDomain(DataProperty(<http://example.com/age>) Class(<http://example.com/Person>))
+)"#;
+        let ontology = owl2_rs::parser::OWLParser::parse_ontology(fss).expect("Failed to parse ontology");
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::write_ontology_to_turtle(&ontology, &mut turtle).expect("Failed to serialize");
+        let temp_file = "test_ontology_written.ttl";
+        fs::write(temp_file, &turtle).expect("Failed to write test file");
+        let result = owl2_rs::rdf::load_ontology_from_turtle(temp_file);
+        fs::remove_file(temp_file).expect("Failed to remove test file");
+
+        let loaded = result.expect("Failed to load Turtle");
+        assert_eq!(loaded.ontology_iri, ontology.ontology_iri);
+        assert_eq!(loaded.version_iri, ontology.version_iri);
+        assert_eq!(loaded.annotations, ontology.annotations);
+        // The undeclared data property and the title annotation property gain declarations
+        let mut expected: HashSet<_> = ontology.axioms.iter().cloned().collect();
+        expected.insert(Axiom::Declaration(Entity::DataProperty(owl2_rs::DataProperty(iri("age")))));
+        expected.insert(Axiom::Declaration(Entity::AnnotationProperty(IRI("http://purl.org/dc/elements/1.1/title".to_string()))));
+        assert_eq!(loaded.axioms.into_iter().collect::<HashSet<_>>(), expected);
+    }
+
+    /// Test writing and reading back an ontology that has no IRI
+    #[test]
+    fn test_write_ontology_to_turtle_without_iri() {
+        let fss = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(
+  Annotation(dc:title "Untitled")
+  Declaration(AnnotationProperty(dc:title))
+  SubClassOf(Class(<http://example.com/Parent>) Class(<http://example.com/Person>))
+)"#;
+        let ontology = owl2_rs::parser::OWLParser::parse_ontology(fss).expect("Failed to parse ontology");
+        assert_eq!(ontology.ontology_iri, None);
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::write_ontology_to_turtle(&ontology, &mut turtle).expect("Failed to serialize");
+        let temp_file = "test_ontology_without_iri.ttl";
+        fs::write(temp_file, &turtle).expect("Failed to write test file");
+        let result = owl2_rs::rdf::load_ontology_from_turtle(temp_file);
+        fs::remove_file(temp_file).expect("Failed to remove test file");
+
+        // The blank-node header is read back as the header, not as an assertion
+        let loaded = result.expect("Failed to load Turtle");
+        assert_eq!(loaded.ontology_iri, None);
+        assert_eq!(loaded.annotations, ontology.annotations);
+        assert_eq!(loaded.axioms.into_iter().collect::<HashSet<_>>(), ontology.axioms.into_iter().collect::<HashSet<_>>());
+    }
+
+    /// Test that anonymous individuals do not share labels with generated blank nodes
+    #[test]
+    fn test_write_ontology_to_turtle_relabels_anonymous_individuals() {
+        use oxrdf::{NamedOrBlankNode, Term};
+        use oxrdfio::{RdfFormat, RdfParser};
+
+        let fss = r#"Ontology(<http://example.com/ontology>
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+)"#;
+        let mut ontology = owl2_rs::parser::OWLParser::parse_ontology(fss).expect("Failed to parse ontology");
+        for parent in ["alice", "bob"] {
+            ontology.axioms.push(Axiom::Assertion(owl2_rs::Assertion::ObjectPropertyAssertion {
+                property: property("hasChild"),
+                source: Individual::Named(iri(parent)),
+                target: Individual::Anonymous(owl2_rs::NodeID("_:b1".to_string())),
+            }));
+        }
+        let mut turtle = Vec::new();
+        owl2_rs::rdf::write_ontology_to_turtle(&ontology, &mut turtle).expect("Failed to serialize");
+        let triples: Vec<_> = RdfParser::from_format(RdfFormat::Turtle)
+            .for_reader(&turtle[..])
+            .map(|quad| quad.expect("Failed to parse Turtle"))
+            .collect();
+
+        let child_of = |parent: &str| triples.iter()
+            .find(|quad| quad.subject == NamedOrBlankNode::from(oxrdf::NamedNode::new_unchecked(format!("http://example.com/{}", parent))))
+            .map(|quad| quad.object.clone())
+            .expect("Missing hasChild triple");
+        let child = child_of("alice");
+        assert!(matches!(child, Term::BlankNode(_)));
+        // Both assertions keep referring to the same individual
+        assert_eq!(child_of("bob"), child);
+        // ... which is not the blank node written for the restriction
+        let restriction = triples.iter()
+            .find(|quad| quad.object == Term::from(oxrdf::NamedNode::new_unchecked("http://www.w3.org/2002/07/owl#Restriction")))
+            .map(|quad| Term::from(quad.subject.clone()))
+            .expect("Missing restriction");
+        assert_ne!(restriction, child);
+    }
+}
@@ -0,0 +1,42 @@
+//! # Tests for the `serde` Feature
+//! 
+//! These tests only run with `--features serde`.
+
+#![cfg(feature = "serde")]
+
+use owl2_rs::parser::OWLParser;
+use owl2_rs::{ClassExpression, Class, IRI, Ontology};
+
+/// Test that a parsed ontology survives a round trip through JSON
+#[test]
+fn test_ontology_json_round_trip() {
+    let input = r#"Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.com/ontology>
+  Annotation(dc:title "Family")
+  Declaration(Class(<http://example.com/Person>))
+  SubClassOf(Class(<http://example.com/Parent>) ObjectSomeValuesFrom(ObjectProperty(<http://example.com/hasChild>) Class(<http://example.com/Person>)))
+  EquivalentClasses(Class(<http://example.com/Orphan>) ObjectAllValuesFrom(ObjectInverseOf(ObjectProperty(<http://example.com/hasChild>)) ObjectComplementOf(Class(<http://example.com/Alive>))))
+  DataPropertyRange(DataProperty(<http://example.com/age>) DatatypeRestriction(Datatype(<http://www.w3.org/2001/XMLSchema#integer>) <http://www.w3.org/2001/XMLSchema#minInclusive> "0"^^<http://www.w3.org/2001/XMLSchema#integer>))
+  ClassAssertion(Class(<http://example.com/Parent>) NamedIndividual(<http://example.com/alice>))
+  DataPropertyAssertion(DataProperty(<http://example.com/age>) NamedIndividual(<http://example.com/alice>) "42"^^<http://www.w3.org/2001/XMLSchema#integer>)
+  AnnotationAssertion(rdfs:label <http://example.com/alice> "Alice"@en)
+)"#;
+    let ontology = OWLParser::parse_ontology(input).expect("Failed to parse ontology");
+
+    let json = serde_json::to_string(&ontology).expect("Failed to serialize");
+    let restored: Ontology = serde_json::from_str(&json).expect("Failed to deserialize");
+
+    assert_eq!(restored.ontology_iri, ontology.ontology_iri);
+    assert_eq!(restored.annotations, ontology.annotations);
+    assert_eq!(restored.axioms, ontology.axioms);
+    assert_eq!(restored.prefixes, ontology.prefixes);
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+}
+
+/// Test the documented JSON shape of enum variants
+#[test]
+fn test_class_expression_json_representation() {
+    let class = ClassExpression::Class(Class(IRI("http://example.com/Person".to_string())));
+    let json = serde_json::to_value(&class).unwrap();
+    assert_eq!(json, serde_json::json!({"type": "Class", "value": "http://example.com/Person"}));
+}
@@ -9,7 +9,7 @@ use std::path::Path;
 #[test]
 fn test_uht_milk_supplychain_ontology_parsing() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     
     // Check that we have the expected number of axioms
     assert!(ontology.axioms.len() > 20);
@@ -20,11 +20,11 @@ fn test_uht_milk_supplychain_ontology_parsing() {
 #[test]
 fn test_uht_milk_supplychain_consistency() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Check that the ontology is consistent
-    assert!(reasoner.is_consistent());
+    assert!(reasoner.is_consistent().unwrap());
     
     println!("UHT milk supply chain ontology is consistent");
 }
@@ -32,11 +32,11 @@ fn test_uht_milk_supplychain_consistency() {
 #[test]
 fn test_uht_milk_supplychain_classification() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Compute the class hierarchy
-    let hierarchy = reasoner.classify();
+    let hierarchy = reasoner.classify().unwrap();
     
     // We can at least check that the function runs without error
     println!("Computed class hierarchy for UHT milk supply chain ontology:");
@@ -47,11 +47,11 @@ fn test_uht_milk_supplychain_classification() {
 #[test]
 fn test_uht_milk_supplychain_reasoning() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     let mut reasoner = Reasoner::new(ontology);
     
     // Realize individuals
-    let individual_types = reasoner.realize();
+    let individual_types = reasoner.realize().unwrap();
     
     // Check that we have realized types for our individuals
     assert!(!individual_types.is_empty());
@@ -63,7 +63,7 @@ fn test_uht_milk_supplychain_reasoning() {
 #[test]
 fn test_supply_chain_relationships() {
     let path = Path::new("test_cases/uht_milk_supplychain.ofn");
-    let ontology = load_ontology_from_file(&path).expect("Failed to load UHT milk supply chain ontology");
+    let ontology = load_ontology_from_file(path).expect("Failed to load UHT milk supply chain ontology");
     
     // This test just verifies that the ontology can be parsed and is consistent
     // In a more complete implementation, we would test specific relationships